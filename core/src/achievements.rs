@@ -0,0 +1,291 @@
+use crate::constants::AchievementConstants;
+use crate::pickup::PickupType;
+use crate::run::RunEvent;
+use crate::storage::{Storage, StorageResult};
+
+/// What has to happen within a single run to unlock an achievement.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AchievementCondition {
+    /// Reach the given level (1-based)
+    ReachLevel(u32),
+    /// Catch this many pickups of a type with the tractor beam
+    BeamCatches {
+        /// Kind of pickup that counts
+        pickup_type: PickupType,
+        /// Catches needed
+        count: u32,
+    },
+    /// Fly this far in a row without firing a thruster, in pixels (shown as m)
+    DistanceWithoutThrust(f32),
+    /// Finish a level with less than this fuel ratio left
+    ClearLevelWithFuelBelow(f32),
+    /// Pull off this many near-misses
+    NearMisses(u32),
+    /// Chain this many pickups into one combo
+    Combo(u32),
+}
+
+impl AchievementCondition {
+    /// Checks the condition against the run so far.
+    pub fn is_met(&self, progress: &AchievementProgress) -> bool {
+        match *self {
+            AchievementCondition::ReachLevel(level_number) => progress.level_number >= level_number,
+            AchievementCondition::BeamCatches { pickup_type, count } => {
+                progress.beam_catches(pickup_type) >= count
+            }
+            AchievementCondition::DistanceWithoutThrust(distance) => {
+                progress.longest_coast >= distance
+            }
+            AchievementCondition::ClearLevelWithFuelBelow(ratio) => {
+                progress.lowest_clear_fuel.is_some_and(|fuel_ratio| fuel_ratio < ratio)
+            }
+            AchievementCondition::NearMisses(count) => progress.near_misses >= count,
+            AchievementCondition::Combo(count) => progress.best_combo >= count,
+        }
+    }
+}
+
+/// An achievement definition.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Achievement {
+    /// Stable identifier used when saving unlocks
+    pub id: &'static str,
+    /// Name shown to the player
+    pub title: &'static str,
+    /// What the player has to do
+    pub description: &'static str,
+    /// Condition that unlocks it
+    pub condition: AchievementCondition,
+}
+
+/// The built-in achievements, in display order.
+pub const ACHIEVEMENTS: &[Achievement] = &[
+    Achievement {
+        id: "deep_diver",
+        title: "Deep Diver",
+        description: "Reach level 4",
+        condition: AchievementCondition::ReachLevel(4),
+    },
+    Achievement {
+        id: "beam_harvester",
+        title: "Beam Harvester",
+        description: "Collect 10 fuel with the beam in one run",
+        condition: AchievementCondition::BeamCatches {
+            pickup_type: PickupType::Fuel,
+            count: 10,
+        },
+    },
+    Achievement {
+        id: "glider",
+        title: "Glider",
+        description: "Fly 2000 m without thrusting",
+        condition: AchievementCondition::DistanceWithoutThrust(2000.0),
+    },
+    Achievement {
+        id: "running_on_fumes",
+        title: "Running on Fumes",
+        description: "Finish a level with <5% fuel",
+        condition: AchievementCondition::ClearLevelWithFuelBelow(0.05),
+    },
+    Achievement {
+        id: "daredevil",
+        title: "Daredevil",
+        description: "Pull off 25 near-misses in one run",
+        condition: AchievementCondition::NearMisses(25),
+    },
+    Achievement {
+        id: "chain_reaction",
+        title: "Chain Reaction",
+        description: "Chain 10 pickups into one combo",
+        condition: AchievementCondition::Combo(10),
+    },
+];
+
+/// Progress within the current run, gathered from run events.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AchievementProgress {
+    /// Level being flown (1-based)
+    pub level_number: u32,
+    /// Fuel, shield and cargo pickups caught with the beam
+    beam_catches: [u32; 3],
+    /// Pixels flown since the last thrust
+    pub coast: f32,
+    /// Longest stretch flown without thrust, in pixels
+    pub longest_coast: f32,
+    /// Lowest fuel ratio a level was finished with
+    pub lowest_clear_fuel: Option<f32>,
+    /// Near-misses pulled off
+    pub near_misses: u32,
+    /// Longest combo chained
+    pub best_combo: u32,
+}
+
+impl Default for AchievementProgress {
+    fn default() -> Self {
+        Self {
+            level_number: 1,
+            beam_catches: [0; 3],
+            coast: 0.0,
+            longest_coast: 0.0,
+            lowest_clear_fuel: None,
+            near_misses: 0,
+            best_combo: 0,
+        }
+    }
+}
+
+impl AchievementProgress {
+    /// Creates progress for a fresh run.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies one run event.
+    pub fn apply(&mut self, event: &RunEvent) {
+        match *event {
+            RunEvent::PickupCollected {
+                pickup_type,
+                beam_catch: true,
+            } => self.beam_catches[Self::pickup_index(pickup_type)] += 1,
+            RunEvent::NearMiss { .. } => self.near_misses += 1,
+            RunEvent::Combo { count, .. } => self.best_combo = self.best_combo.max(count),
+            RunEvent::LevelCleared {
+                level_number,
+                fuel_ratio,
+            } => {
+                self.level_number = self.level_number.max(level_number + 1);
+                let lowest = self.lowest_clear_fuel.map_or(fuel_ratio, |lowest| lowest.min(fuel_ratio));
+                self.lowest_clear_fuel = Some(lowest);
+            }
+            RunEvent::Flight {
+                distance,
+                thrusting,
+                ..
+            } => {
+                self.coast = if thrusting { 0.0 } else { self.coast + distance };
+                self.longest_coast = self.longest_coast.max(self.coast);
+            }
+            // A lost ship breaks the glide
+            RunEvent::ShipLost { .. } => self.coast = 0.0,
+            _ => {}
+        }
+    }
+
+    /// Gets the number of pickups of a type caught with the beam.
+    pub fn beam_catches(&self, pickup_type: PickupType) -> u32 {
+        self.beam_catches[Self::pickup_index(pickup_type)]
+    }
+
+    /// Gets the slot of a pickup type in the catch counters.
+    fn pickup_index(pickup_type: PickupType) -> usize {
+        match pickup_type {
+            PickupType::Fuel => 0,
+            PickupType::Shield => 1,
+            PickupType::Cargo => 2,
+        }
+    }
+}
+
+/// Tracks achievements across runs.
+///
+/// Conditions are checked after every run event; unlocks are kept for good.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AchievementTracker {
+    registry: Vec<Achievement>,
+    unlocked: Vec<&'static str>,
+    progress: AchievementProgress,
+    newly_unlocked: Vec<Achievement>,
+}
+
+impl Default for AchievementTracker {
+    fn default() -> Self {
+        Self::new(ACHIEVEMENTS.to_vec())
+    }
+}
+
+impl AchievementTracker {
+    /// Creates a tracker for the given achievements with nothing unlocked.
+    pub fn new(registry: Vec<Achievement>) -> Self {
+        Self {
+            registry,
+            unlocked: Vec::new(),
+            progress: AchievementProgress::new(),
+            newly_unlocked: Vec::new(),
+        }
+    }
+
+    /// Resets the run progress for a new run; unlocks are kept.
+    pub fn start_run(&mut self) {
+        self.progress = AchievementProgress::new();
+    }
+
+    /// Applies one run event and unlocks any achievements it completes.
+    pub fn apply(&mut self, event: &RunEvent) {
+        self.progress.apply(event);
+
+        for achievement in &self.registry {
+            if !self.unlocked.contains(&achievement.id) && achievement.condition.is_met(&self.progress) {
+                self.unlocked.push(achievement.id);
+                self.newly_unlocked.push(*achievement);
+            }
+        }
+    }
+
+    /// Takes the achievements unlocked since the last call.
+    pub fn drain_unlocked(&mut self) -> Vec<Achievement> {
+        std::mem::take(&mut self.newly_unlocked)
+    }
+
+    /// Gets all achievements, in display order.
+    pub fn achievements(&self) -> &[Achievement] {
+        &self.registry
+    }
+
+    /// Checks if the achievement with the given id is unlocked.
+    pub fn is_unlocked(&self, id: &str) -> bool {
+        self.unlocked.contains(&id)
+    }
+
+    /// Gets the number of unlocked achievements.
+    pub fn unlocked_count(&self) -> usize {
+        self.unlocked.len()
+    }
+
+    /// Gets the progress of the current run.
+    pub fn progress(&self) -> &AchievementProgress {
+        &self.progress
+    }
+
+    /// Writes the unlocked ids as text, one per line.
+    pub fn to_text(&self) -> String {
+        self.unlocked.iter().map(|id| format!("{}\n", id)).collect()
+    }
+
+    /// Marks the achievements listed by `to_text` as unlocked.
+    ///
+    /// Ids not in the registry are skipped.
+    pub fn unlock_from_text(&mut self, text: &str) {
+        for id in text.lines().map(str::trim) {
+            let known = self.registry.iter().find(|achievement| achievement.id == id);
+            if let Some(achievement) = known {
+                if !self.unlocked.contains(&achievement.id) {
+                    self.unlocked.push(achievement.id);
+                }
+            }
+        }
+    }
+
+    /// Loads the built-in achievements with the unlocks saved in storage.
+    pub fn load(storage: &dyn Storage) -> StorageResult<Self> {
+        let mut tracker = Self::default();
+        if let Some(text) = storage.load(AchievementConstants::STORAGE_KEY)? {
+            tracker.unlock_from_text(&text);
+        }
+        Ok(tracker)
+    }
+
+    /// Saves the unlocks to storage.
+    pub fn save(&self, storage: &mut dyn Storage) -> StorageResult<()> {
+        storage.save(AchievementConstants::STORAGE_KEY, &self.to_text())
+    }
+}
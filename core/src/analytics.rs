@@ -0,0 +1,280 @@
+use crate::constants::AnalyticsConstants;
+use crate::difficulty::Difficulty;
+use crate::game_state::DeathCause;
+use crate::mode::GameMode;
+use crate::storage::{Storage, StorageError, StorageResult};
+
+/// Where and how a ship was lost.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeathRecord {
+    /// World x-coordinate of the ship
+    pub world_x: f32,
+    /// Level being flown (1-based)
+    pub level_number: u32,
+    /// Cave seed the run was played on
+    pub seed: u32,
+    /// What destroyed the ship
+    pub cause: DeathCause,
+    /// Game mode the run was played in
+    pub mode: GameMode,
+    /// Difficulty preset the run was played on
+    pub difficulty: Difficulty,
+}
+
+impl DeathRecord {
+    /// Creates a death record for a run in the default mode and difficulty.
+    pub fn new(world_x: f32, level_number: u32, seed: u32, cause: DeathCause) -> Self {
+        Self {
+            world_x,
+            level_number,
+            seed,
+            cause,
+            mode: GameMode::default(),
+            difficulty: Difficulty::default(),
+        }
+    }
+
+    /// Sets the mode and difficulty the run was played under.
+    pub fn with_run(mut self, mode: GameMode, difficulty: Difficulty) -> Self {
+        self.mode = mode;
+        self.difficulty = difficulty;
+        self
+    }
+
+    /// Writes the record as one CSV row.
+    fn format_csv(&self) -> String {
+        format!(
+            "{:.1},{},{},{},{},{}",
+            self.world_x, self.level_number, self.seed, self.cause, self.mode, self.difficulty
+        )
+    }
+
+    /// Writes the record as one JSON object.
+    fn format_json(&self) -> String {
+        format!(
+            "{{\"world_x\":{:.1},\"level\":{},\"seed\":{},\"cause\":\"{}\",\"mode\":\"{}\",\"difficulty\":\"{}\"}}",
+            self.world_x, self.level_number, self.seed, self.cause, self.mode, self.difficulty
+        )
+    }
+
+    /// Reads a record written by `format_csv`.
+    fn parse_csv(line: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = line.split(',').collect();
+        let [world_x, level_number, seed, cause, mode, difficulty] = fields[..] else {
+            return Err(format!("expected 6 fields, found {}", fields.len()));
+        };
+
+        let world_x = world_x.parse::<f32>().map_err(|e| format!("'{}': {}", world_x, e))?;
+        let number = |field: &str| field.parse::<u32>().map_err(|e| format!("'{}': {}", field, e));
+        let cause = DeathCause::from_name(cause).ok_or_else(|| format!("unknown death cause '{}'", cause))?;
+        let mode = GameMode::from_name(mode).ok_or_else(|| format!("unknown game mode '{}'", mode))?;
+        let difficulty = Difficulty::from_name(difficulty)
+            .ok_or_else(|| format!("unknown difficulty '{}'", difficulty))?;
+
+        Ok(Self::new(world_x, number(level_number)?, number(seed)?, cause).with_run(mode, difficulty))
+    }
+}
+
+/// Log of ship losses kept for balancing.
+///
+/// Holds at most `capacity` records; the oldest are dropped first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeathLog {
+    records: Vec<DeathRecord>,
+    capacity: usize,
+}
+
+impl Default for DeathLog {
+    fn default() -> Self {
+        Self::new(AnalyticsConstants::MAX_RECORDS)
+    }
+}
+
+impl DeathLog {
+    /// Creates an empty log keeping at most `capacity` records.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            records: Vec::new(),
+            capacity,
+        }
+    }
+
+    /// Adds a record, dropping the oldest if the log is full.
+    pub fn record(&mut self, record: DeathRecord) {
+        self.records.push(record);
+        if self.records.len() > self.capacity {
+            let excess = self.records.len() - self.capacity;
+            self.records.drain(..excess);
+        }
+    }
+
+    /// Gets the records, oldest first.
+    pub fn records(&self) -> &[DeathRecord] {
+        &self.records
+    }
+
+    /// Gets the records from runs on the given seed.
+    pub fn records_for_seed(&self, seed: u32) -> impl Iterator<Item = &DeathRecord> {
+        self.records.iter().filter(move |record| record.seed == seed)
+    }
+
+    /// Gets the records from runs on the given seed, mode and difficulty.
+    pub fn records_for_run(
+        &self,
+        seed: u32,
+        mode: GameMode,
+        difficulty: Difficulty,
+    ) -> impl Iterator<Item = &DeathRecord> {
+        self.records_for_seed(seed)
+            .filter(move |record| record.mode == mode && record.difficulty == difficulty)
+    }
+
+    /// Gets the number of records.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Checks if the log is empty.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Writes the log as CSV with a header row.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(AnalyticsConstants::CSV_HEADER);
+        csv.push('\n');
+        for record in &self.records {
+            csv.push_str(&record.format_csv());
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// Writes the log as a JSON array of objects.
+    pub fn to_json(&self) -> String {
+        let objects: Vec<String> = self
+            .records
+            .iter()
+            .map(|record| format!("  {}", record.format_json()))
+            .collect();
+        if objects.is_empty() {
+            "[]\n".to_string()
+        } else {
+            format!("[\n{}\n]\n", objects.join(",\n"))
+        }
+    }
+
+    /// Reads a log written by `to_csv`.
+    ///
+    /// The header row is optional. Only the newest `capacity` records are kept.
+    pub fn from_csv(text: &str, capacity: usize) -> StorageResult<Self> {
+        let mut log = Self::new(capacity);
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line == AnalyticsConstants::CSV_HEADER {
+                continue;
+            }
+
+            let record = DeathRecord::parse_csv(line).map_err(|reason| StorageError::Corrupt {
+                key: AnalyticsConstants::STORAGE_KEY.to_string(),
+                reason: format!("line {}: {}", index + 1, reason),
+            })?;
+            log.record(record);
+        }
+        Ok(log)
+    }
+
+    /// Loads the log from storage, or an empty log if none was saved.
+    pub fn load(storage: &dyn Storage) -> StorageResult<Self> {
+        match storage.load(AnalyticsConstants::STORAGE_KEY)? {
+            Some(text) => Self::from_csv(&text, AnalyticsConstants::MAX_RECORDS),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Saves the log to storage.
+    pub fn save(&self, storage: &mut dyn Storage) -> StorageResult<()> {
+        storage.save(AnalyticsConstants::STORAGE_KEY, &self.to_csv())
+    }
+}
+
+/// Death counts binned along the cave.
+///
+/// Bucket `i` covers world x from `i * bucket_width` to `(i + 1) * bucket_width`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeathHeatStrip {
+    bucket_width: f32,
+    counts: Vec<u32>,
+    max_count: u32,
+}
+
+impl DeathHeatStrip {
+    /// Creates an empty strip with the given bucket width in pixels.
+    pub fn new(bucket_width: f32) -> Self {
+        Self {
+            bucket_width,
+            counts: Vec::new(),
+            max_count: 0,
+        }
+    }
+
+    /// Builds a strip from death records.
+    pub fn from_records<'a>(records: impl IntoIterator<Item = &'a DeathRecord>, bucket_width: f32) -> Self {
+        let mut strip = Self::new(bucket_width);
+        for record in records {
+            strip.add(record.world_x);
+        }
+        strip
+    }
+
+    /// Counts a death at the given world x-coordinate.
+    ///
+    /// Deaths left of the cave start count towards the first bucket.
+    pub fn add(&mut self, world_x: f32) {
+        let index = self.bucket_index(world_x);
+        if index >= self.counts.len() {
+            self.counts.resize(index + 1, 0);
+        }
+        self.counts[index] += 1;
+        self.max_count = self.max_count.max(self.counts[index]);
+    }
+
+    /// Gets the bucket width in pixels.
+    pub fn bucket_width(&self) -> f32 {
+        self.bucket_width
+    }
+
+    /// Gets the number of deaths in the bucket containing `world_x`.
+    pub fn count_at(&self, world_x: f32) -> u32 {
+        self.counts.get(self.bucket_index(world_x)).copied().unwrap_or(0)
+    }
+
+    /// Gets the highest bucket count.
+    pub fn max_count(&self) -> u32 {
+        self.max_count
+    }
+
+    /// Gets the bucket's count relative to the deadliest bucket (0.0 to 1.0).
+    pub fn intensity_at(&self, world_x: f32) -> f32 {
+        if self.max_count == 0 {
+            return 0.0;
+        }
+        self.count_at(world_x) as f32 / self.max_count as f32
+    }
+
+    /// Gets the non-empty buckets as (x start, count) pairs within a range.
+    pub fn buckets_in_range(&self, x_min: f32, x_max: f32) -> Vec<(f32, u32)> {
+        self.counts
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count > 0)
+            .map(|(index, count)| (index as f32 * self.bucket_width, *count))
+            .filter(|(x_start, _)| *x_start + self.bucket_width > x_min && *x_start < x_max)
+            .collect()
+    }
+
+    /// Gets the bucket index for a world x-coordinate.
+    fn bucket_index(&self, world_x: f32) -> usize {
+        (world_x.max(0.0) / self.bucket_width) as usize
+    }
+}
@@ -19,6 +19,38 @@ pub enum AudioEvent {
     Death,
     /// UI button click sound
     ButtonClick,
+    /// Hull scraping a wall under the damage model
+    HullHit,
+    /// Shield cell pickup sound
+    ShieldPickup,
+    /// Tractor beam switched between pull and push
+    BeamPolarityToggle,
+    /// Tractor beam drained its energy and locked out
+    BeamOverheat,
+    /// Tractor beam latched onto an anchor crystal
+    GrappleLatch,
+    /// Grapple rope snapped
+    GrappleBreak,
+    /// Cargo pod tethered to the ship
+    CargoAttach,
+    /// Cargo pod delivered to a drop-off platform
+    CargoDelivered,
+    /// Cargo pod smashed against a wall
+    CargoLost,
+    /// Ship touched down on a landing pad
+    PadTouchdown,
+    /// Fuel endurance dropped to the low warning level
+    LowFuelBeep,
+    /// Fuel endurance dropped to the critical level
+    CriticalFuelAlarm,
+    /// Ship passed close to a wall or hazard without touching it
+    NearMiss,
+    /// Pickup combo reached a milestone
+    ComboMilestone,
+    /// An achievement was unlocked
+    AchievementUnlocked,
+    /// A run reached its goal
+    RunComplete,
 }
 
 /// Audio state tracker for managing looping sounds.
@@ -0,0 +1,355 @@
+use crate::cave::SimpleRng;
+use crate::collision::Aabb;
+use crate::constants::CargoConstants;
+use crate::player::Player;
+
+/// Result of one tether update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TetherStatus {
+    /// The pod is hanging on the tether
+    Holding,
+    /// The pod touched down on a drop-off platform
+    Delivered,
+    /// The pod hit a cave wall and was lost
+    Lost,
+}
+
+/// A cargo pod hanging on a tether below the ship.
+///
+/// The tether is a rope of fixed length; when it goes taut the pod and the
+/// ship share the correction by mass, so a heavy pod drags the ship around.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tether {
+    /// Pod centre position in world coordinates (x, y)
+    pub pod_position: (f32, f32),
+    /// Pod velocity in pixels per second
+    pub pod_velocity: (f32, f32),
+    /// Tether length in pixels
+    pub length: f32,
+}
+
+impl Tether {
+    /// Attaches a pod at the given position to the ship.
+    ///
+    /// # Arguments
+    /// * `pod_position` - Pod centre position (x, y)
+    /// * `pod_velocity` - Pod velocity when grabbed
+    pub fn new(pod_position: (f32, f32), pod_velocity: (f32, f32)) -> Self {
+        Self {
+            pod_position,
+            pod_velocity,
+            length: CargoConstants::TETHER_LENGTH,
+        }
+    }
+
+    /// Moves the pod under gravity and applies the tether to pod and ship.
+    ///
+    /// # Arguments
+    /// * `player` - The ship carrying the pod
+    /// * `dt` - Delta time in seconds
+    pub fn update(&mut self, player: &mut Player, dt: f32) {
+        let damping = (1.0 - CargoConstants::DRAG * dt).max(0.0);
+        self.pod_velocity.0 *= damping;
+        self.pod_velocity.1 = self.pod_velocity.1 * damping + CargoConstants::GRAVITY * dt;
+        self.pod_position.0 += self.pod_velocity.0 * dt;
+        self.pod_position.1 += self.pod_velocity.1 * dt;
+
+        let dx = self.pod_position.0 - player.pos.x;
+        let dy = self.pod_position.1 - player.pos.y;
+        let distance = (dx * dx + dy * dy).sqrt();
+        if distance <= self.length || distance <= 0.0 {
+            return;
+        }
+
+        // Share the correction by mass: the lighter body moves further
+        let (nx, ny) = (dx / distance, dy / distance);
+        let total_mass = 1.0 + CargoConstants::POD_MASS;
+        let pod_share = 1.0 / total_mass;
+        let ship_share = CargoConstants::POD_MASS / total_mass;
+
+        let excess = distance - self.length;
+        self.pod_position.0 -= nx * excess * pod_share;
+        self.pod_position.1 -= ny * excess * pod_share;
+        player.pos.x += nx * excess * ship_share;
+        player.pos.y += ny * excess * ship_share;
+
+        // Cancel the separating velocity along the tether
+        let separating = (self.pod_velocity.0 - player.vel.x) * nx + (self.pod_velocity.1 - player.vel.y) * ny;
+        if separating > 0.0 {
+            self.pod_velocity.0 -= nx * separating * pod_share;
+            self.pod_velocity.1 -= ny * separating * pod_share;
+            player.vel.x += nx * separating * ship_share;
+            player.vel.y += ny * separating * ship_share;
+        }
+    }
+
+    /// Gets the bounding box of the pod.
+    pub fn pod_bounds(&self) -> Aabb {
+        let size = CargoConstants::POD_SIZE;
+        Aabb::new(
+            self.pod_position.0 - size / 2.0,
+            self.pod_position.1 - size / 2.0,
+            size,
+            size,
+        )
+    }
+
+    /// Checks the pod against the cave walls and drop-off platforms.
+    ///
+    /// Touching a platform delivers the pod; touching a wall loses it.
+    ///
+    /// # Arguments
+    /// * `ceiling_y` - Y-coordinate of the ceiling at the pod
+    /// * `floor_y` - Y-coordinate of the floor at the pod
+    /// * `drop_offs` - Drop-off platforms to test against
+    pub fn check_contact(&self, ceiling_y: f32, floor_y: f32, drop_offs: &[DropOff]) -> TetherStatus {
+        let bounds = self.pod_bounds();
+
+        if drop_offs.iter().any(|platform| platform.accepts(&bounds)) {
+            TetherStatus::Delivered
+        } else if bounds.top() <= ceiling_y || bounds.bottom() >= floor_y {
+            TetherStatus::Lost
+        } else {
+            TetherStatus::Holding
+        }
+    }
+}
+
+/// The ship's single carry slot for a cargo pod.
+///
+/// A pod stays tethered independently of beam bursts until it is
+/// delivered or lost.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct CargoHold {
+    /// Tether holding the carried pod, if any
+    tether: Option<Tether>,
+}
+
+impl CargoHold {
+    /// Creates an empty hold.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tethers a cargo pod to the ship.
+    ///
+    /// Returns false if the slot is already taken.
+    ///
+    /// # Arguments
+    /// * `pod_position` - Pod centre position (x, y)
+    /// * `pod_velocity` - Pod velocity when grabbed
+    pub fn attach(&mut self, pod_position: (f32, f32), pod_velocity: (f32, f32)) -> bool {
+        if self.tether.is_some() {
+            return false;
+        }
+        self.tether = Some(Tether::new(pod_position, pod_velocity));
+        true
+    }
+
+    /// Gets the tether holding the carried pod, if any.
+    pub fn tether(&self) -> Option<&Tether> {
+        self.tether.as_ref()
+    }
+
+    /// Checks if the slot is taken.
+    pub fn is_carrying(&self) -> bool {
+        self.tether.is_some()
+    }
+
+    /// Empties the slot, returning the tether that held the pod.
+    pub fn drop_pod(&mut self) -> Option<Tether> {
+        self.tether.take()
+    }
+
+    /// Moves the carried pod and adds its mass to the ship.
+    ///
+    /// Clears the ship's payload when nothing is carried.
+    ///
+    /// # Arguments
+    /// * `player` - The ship carrying the pod
+    /// * `dt` - Delta time in seconds
+    pub fn update(&mut self, player: &mut Player, dt: f32) {
+        match self.tether.as_mut() {
+            Some(tether) => {
+                player.payload_mass = CargoConstants::POD_MASS;
+                tether.update(player, dt);
+            }
+            None => player.payload_mass = 0.0,
+        }
+    }
+}
+
+/// A landing platform on the cave floor where cargo is delivered.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DropOff {
+    /// Left edge in world coordinates
+    pub x: f32,
+    /// Top of the platform in world coordinates
+    pub y: f32,
+    /// Platform width in pixels
+    pub width: f32,
+}
+
+impl DropOff {
+    /// Gets the bounding box of the platform.
+    pub fn bounds(&self) -> Aabb {
+        Aabb::new(self.x, self.y, self.width, CargoConstants::PLATFORM_HEIGHT)
+    }
+
+    /// Checks if a pod resting on this platform counts as delivered.
+    ///
+    /// The pod must be fully over the platform and touching its top.
+    pub fn accepts(&self, pod: &Aabb) -> bool {
+        pod.left() >= self.x && pod.right() <= self.x + self.width && pod.bottom() >= self.y
+    }
+}
+
+/// Manages spawning of drop-off platforms along the cave.
+#[derive(Debug)]
+pub struct DropOffManager {
+    /// Platforms in the world
+    drop_offs: Vec<DropOff>,
+    /// Random number generator for spawn decisions
+    rng: SimpleRng,
+    /// X-coordinate where the next platform may spawn
+    next_spawn_x: f32,
+    /// Whether platforms spawn at all
+    enabled: bool,
+}
+
+impl DropOffManager {
+    /// Creates a new drop-off manager with spawning disabled.
+    pub fn new(seed: u32) -> Self {
+        Self {
+            drop_offs: Vec::new(),
+            rng: SimpleRng::new(seed.wrapping_add(CargoConstants::RNG_SEED_OFFSET)),
+            next_spawn_x: CargoConstants::INITIAL_SPAWN_DELAY,
+            enabled: false,
+        }
+    }
+
+    /// Enables or disables platform spawning.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Checks if platforms are spawning.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Checks if a platform should spawn at the given x-coordinate.
+    ///
+    /// # Arguments
+    /// * `x` - Current x-coordinate to check
+    pub fn should_spawn_drop_off(&mut self, x: f32) -> bool {
+        if !self.enabled || x < self.next_spawn_x {
+            return false;
+        }
+
+        let average_distance = CargoConstants::PLATFORM_SPAWN_DISTANCE;
+        let variation = average_distance * CargoConstants::SPAWN_DISTANCE_VARIATION;
+        self.next_spawn_x = x + self.rng.range(average_distance - variation, average_distance + variation);
+        true
+    }
+
+    /// Spawns a platform on the floor starting at the given x-coordinate.
+    ///
+    /// # Arguments
+    /// * `x` - Left edge of the platform
+    /// * `floor_y` - Y-coordinate of the floor at this x position
+    pub fn spawn_drop_off(&mut self, x: f32, floor_y: f32) {
+        self.drop_offs.push(DropOff {
+            x,
+            y: floor_y - CargoConstants::PLATFORM_HEIGHT,
+            width: CargoConstants::PLATFORM_WIDTH,
+        });
+    }
+
+    /// Gets all platforms.
+    pub fn drop_offs(&self) -> &[DropOff] {
+        &self.drop_offs
+    }
+
+    /// Gets platforms overlapping the specified x-range.
+    ///
+    /// # Arguments
+    /// * `x_min` - Minimum x-coordinate
+    /// * `x_max` - Maximum x-coordinate
+    pub fn get_drop_offs_in_range(&self, x_min: f32, x_max: f32) -> Vec<&DropOff> {
+        self.drop_offs
+            .iter()
+            .filter(|d| d.x + d.width >= x_min && d.x <= x_max)
+            .collect()
+    }
+
+    /// Removes platforms far behind the camera.
+    ///
+    /// # Arguments
+    /// * `camera_x` - Current camera x-position
+    pub fn cleanup_old_drop_offs(&mut self, camera_x: f32) {
+        self.drop_offs
+            .retain(|d| d.x + d.width > camera_x - CargoConstants::CLEANUP_DISTANCE);
+    }
+
+    /// Clears all platforms (used when transitioning to a new level).
+    pub fn clear_all_drop_offs(&mut self) {
+        self.drop_offs.clear();
+        self.next_spawn_x = CargoConstants::INITIAL_SPAWN_DELAY;
+    }
+}
+
+/// Delivery goal and tally for the current level.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct CargoMission {
+    /// Pods to deliver this level
+    pub goal: u32,
+    /// Pods delivered this level
+    pub delivered: u32,
+    /// Pods lost this level
+    pub lost: u32,
+    /// Points earned from deliveries over the whole run
+    pub points: u32,
+}
+
+impl CargoMission {
+    /// Creates a mission with the given delivery goal.
+    pub fn new(goal: u32) -> Self {
+        Self {
+            goal,
+            ..Default::default()
+        }
+    }
+
+    /// Starts a new level's goal, keeping the points earned so far.
+    pub fn start_level(&mut self, goal: u32) {
+        self.goal = goal;
+        self.delivered = 0;
+        self.lost = 0;
+    }
+
+    /// Records a delivered pod and awards points.
+    ///
+    /// The delivery that meets the level's goal also earns the goal bonus.
+    /// Returns true for that delivery.
+    pub fn deliver(&mut self) -> bool {
+        self.delivered += 1;
+        self.points += CargoConstants::DELIVERY_POINTS;
+
+        let goal_met = self.delivered == self.goal;
+        if goal_met {
+            self.points += CargoConstants::GOAL_BONUS;
+        }
+        goal_met
+    }
+
+    /// Records a lost pod.
+    pub fn lose(&mut self) {
+        self.lost += 1;
+    }
+
+    /// Checks if the level's delivery goal has been met.
+    pub fn is_complete(&self) -> bool {
+        self.delivered >= self.goal
+    }
+}
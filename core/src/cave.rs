@@ -1,4 +1,10 @@
 use std::collections::VecDeque;
+use crate::cargo::DropOffManager;
+use crate::collision::Aabb;
+use crate::constants::{LandingPadConstants, PassabilityConstants};
+use crate::grapple::AnchorManager;
+use crate::hazard::HazardManager;
+use crate::passability::PassabilityValidator;
 use crate::pickup::PickupManager;
 
 /// A single segment of the cave with ceiling and floor heights.
@@ -10,6 +16,8 @@ pub struct CaveSegment {
     pub floor: f32,
     pub x_start: f32,
     pub width: f32,
+    /// Whether the floor of this segment is part of a landing pad
+    pub landing_pad: bool,
 }
 
 impl CaveSegment {
@@ -20,6 +28,7 @@ impl CaveSegment {
             floor,
             x_start,
             width,
+            landing_pad: false,
         }
     }
 
@@ -80,8 +89,18 @@ pub struct Cave {
     rng: SimpleRng,
     next_x: f32,
     pickup_manager: PickupManager,
+    hazard_manager: HazardManager,
+    anchor_manager: AnchorManager,
+    drop_off_manager: DropOffManager,
+    pad_rng: SimpleRng,
+    next_pad_x: f32,
+    pad_segments_left: u32,
     base_ceiling: f32,
     base_floor: f32,
+    /// Multiplier on the level gap and the minimum gap
+    gap_scale: f32,
+    validator: Option<PassabilityValidator>,
+    repair_count: u32,
 }
 
 impl Cave {
@@ -92,8 +111,17 @@ impl Cave {
             rng: SimpleRng::new(seed),
             next_x: 0.0,
             pickup_manager: PickupManager::new(seed),
+            hazard_manager: HazardManager::new(seed),
+            anchor_manager: AnchorManager::new(seed),
+            drop_off_manager: DropOffManager::new(seed),
+            pad_rng: SimpleRng::new(seed.wrapping_add(LandingPadConstants::RNG_SEED_OFFSET)),
+            next_pad_x: LandingPadConstants::INITIAL_SPAWN_DELAY,
+            pad_segments_left: 0,
             base_ceiling: CaveConstants::INITIAL_CEILING,
             base_floor: CaveConstants::INITIAL_FLOOR,
+            gap_scale: 1.0,
+            validator: Some(PassabilityValidator::default()),
+            repair_count: 0,
         };
 
         // Generate initial segment
@@ -107,16 +135,21 @@ impl Cave {
         // Level 1: 400px, Level 2: 350px, ..., Level 6+: MIN_GAP (140px)
         let initial_gap = CaveConstants::INITIAL_FLOOR - CaveConstants::INITIAL_CEILING;
         let level_reduction = (level_number - 1).min(5) as f32 * 50.0;
-        let gap = (initial_gap - level_reduction).max(CaveConstants::MIN_GAP);
+        let gap = (initial_gap - level_reduction).max(CaveConstants::MIN_GAP) * self.gap_scale;
         
         // Center the cave vertically
         let center_y = 300.0; // Center of 600px high window
         self.base_ceiling = center_y - gap / 2.0;
         self.base_floor = center_y + gap / 2.0;
         
-        // Clear existing segments and pickups, then regenerate initial segment
+        // Clear existing segments and everything placed in them, then regenerate initial segment
         self.segments.clear();
         self.pickup_manager.clear_all_pickups();
+        self.hazard_manager.clear_all_hazards();
+        self.anchor_manager.clear_all_anchors();
+        self.drop_off_manager.clear_all_drop_offs();
+        self.next_pad_x = LandingPadConstants::INITIAL_SPAWN_DELAY;
+        self.pad_segments_left = 0;
         self.next_x = 0.0;
         self.generate_initial_segment();
     }
@@ -138,7 +171,7 @@ impl Cave {
     ///
     /// Ensures minimum gap is maintained and segments are contiguous.
     pub fn generate_next(&mut self, fuel_spawn_distance: f32) {
-        let _prev_segment = self
+        let prev_segment = *self
             .segments
             .back()
             .expect("Cave should always have at least one segment");
@@ -157,28 +190,61 @@ impl Cave {
         let mut new_floor = self.base_floor + floor_variation;
 
         // Ensure minimum gap is maintained
-        if new_floor - new_ceiling < CaveConstants::MIN_GAP {
+        let min_gap = self.min_gap();
+        if new_floor - new_ceiling < min_gap {
             let gap_center = (new_ceiling + new_floor) / 2.0;
-            new_ceiling = gap_center - CaveConstants::MIN_GAP / 2.0;
-            new_floor = gap_center + CaveConstants::MIN_GAP / 2.0;
+            new_ceiling = gap_center - min_gap / 2.0;
+            new_floor = gap_center + min_gap / 2.0;
         }
 
-        let segment = CaveSegment::new(
+        // Landing pads keep the floor flat for a short run
+        let landing_pad = self.should_generate_pad(self.next_x);
+        if landing_pad {
+            new_floor = prev_segment.floor;
+            new_ceiling = new_ceiling.min(new_floor - min_gap);
+        }
+
+        let mut segment = self.repair_if_impassable(CaveSegment::new(
             new_ceiling,
             new_floor,
             self.next_x,
             CaveConstants::SEGMENT_WIDTH,
-        );
+        ));
+        // Repair may reshape the segment; only a floor that stayed flat counts as a pad
+        segment.landing_pad = landing_pad && segment.floor == prev_segment.floor;
 
         // Check if we should spawn a pickup in this segment
         if self.pickup_manager.should_spawn_pickup(segment.x_start + segment.width / 2.0, fuel_spawn_distance) {
-            self.pickup_manager.spawn_fuel_pickup(
+            self.pickup_manager.spawn_random_pickup(
                 segment.x_start + segment.width / 2.0,
                 segment.ceiling,
                 segment.floor,
             );
         }
 
+        // Check if we should spawn a hazard in this segment
+        if self.hazard_manager.should_spawn_hazard(segment.x_start + segment.width / 2.0) {
+            self.hazard_manager.spawn_random_hazard(
+                segment.x_start + segment.width / 2.0,
+                segment.ceiling,
+                segment.floor,
+            );
+        }
+
+        // Check if we should spawn an anchor crystal in this segment
+        if self.anchor_manager.should_spawn_anchor(segment.x_start + segment.width / 2.0) {
+            self.anchor_manager.spawn_anchor(
+                segment.x_start + segment.width / 2.0,
+                segment.ceiling,
+                segment.floor,
+            );
+        }
+
+        // Check if we should spawn a drop-off platform in this segment
+        if self.drop_off_manager.should_spawn_drop_off(segment.x_start) {
+            self.drop_off_manager.spawn_drop_off(segment.x_start, segment.floor);
+        }
+
         self.segments.push_back(segment);
         self.next_x = segment.x_end();
 
@@ -189,6 +255,85 @@ impl Cave {
         }
     }
 
+    /// Checks if the segment starting at the given x-coordinate belongs to a landing pad.
+    ///
+    /// Starts a new pad once the spawn distance has been covered and keeps
+    /// returning true until the pad's segments are used up.
+    fn should_generate_pad(&mut self, x: f32) -> bool {
+        if self.pad_segments_left == 0 && x >= self.next_pad_x {
+            let average_distance = LandingPadConstants::PAD_SPAWN_DISTANCE;
+            let variation = average_distance * LandingPadConstants::SPAWN_DISTANCE_VARIATION;
+            self.next_pad_x = x + self.pad_rng.range(average_distance - variation, average_distance + variation);
+            self.pad_segments_left = LandingPadConstants::PAD_SEGMENTS;
+        }
+
+        if self.pad_segments_left == 0 {
+            return false;
+        }
+        self.pad_segments_left -= 1;
+        true
+    }
+
+    /// Repairs a candidate segment that would make the recent stretch impassable.
+    ///
+    /// Blends the candidate toward the previous segment until the validator
+    /// finds a path, falling back to a copy of the previous segment.
+    fn repair_if_impassable(&mut self, candidate: CaveSegment) -> CaveSegment {
+        let validator = match self.validator {
+            Some(validator) => validator,
+            None => return candidate,
+        };
+
+        let window_start = self.segments.len().saturating_sub(PassabilityConstants::REPAIR_WINDOW);
+        let mut window: Vec<CaveSegment> = self.segments.range(window_start..).copied().collect();
+        let previous = *window.last().expect("Cave should always have at least one segment");
+
+        let mut repaired = candidate;
+        for _ in 0..PassabilityConstants::MAX_REPAIR_STEPS {
+            window.push(repaired);
+            let passable = validator.is_passable(&window, &[]);
+            window.pop();
+
+            if passable {
+                if repaired != candidate {
+                    self.repair_count += 1;
+                }
+                return repaired;
+            }
+
+            repaired.ceiling = (repaired.ceiling + previous.ceiling) / 2.0;
+            repaired.floor = (repaired.floor + previous.floor) / 2.0;
+        }
+
+        self.repair_count += 1;
+        CaveSegment::new(previous.ceiling, previous.floor, candidate.x_start, candidate.width)
+    }
+
+    /// Scales the gap between ceiling and floor.
+    ///
+    /// Takes effect at the next `configure_for_level`; the minimum gap
+    /// scales too, so the narrowest levels still differ.
+    pub fn set_gap_scale(&mut self, scale: f32) {
+        self.gap_scale = scale;
+    }
+
+    /// Gets the smallest gap generated segments keep.
+    pub fn min_gap(&self) -> f32 {
+        CaveConstants::MIN_GAP * self.gap_scale
+    }
+
+    /// Sets the validator used to repair impassable sections.
+    ///
+    /// Passing `None` disables repair and keeps raw generator output.
+    pub fn set_validator(&mut self, validator: Option<PassabilityValidator>) {
+        self.validator = validator;
+    }
+
+    /// Gets the number of generated segments that needed repair.
+    pub fn repair_count(&self) -> u32 {
+        self.repair_count
+    }
+
     /// Returns segments visible in the given x range.
     ///
     /// Generates new segments as needed to fill the view.
@@ -206,6 +351,88 @@ impl Cave {
             .collect()
     }
 
+    /// Gets the stored segment covering an x-coordinate.
+    ///
+    /// Returns `None` for positions outside the generated part of the cave.
+    pub fn segment_at(&self, x: f32) -> Option<&CaveSegment> {
+        find_segment(&self.segments, x)
+    }
+
+    /// Gets the clearance between a box and the cave walls.
+    ///
+    /// Returns the distances (ceiling, floor) to the closest wall among the
+    /// segments under the box; negative values mean the box is in the wall.
+    /// Returns `None` if no generated segment lies under the box.
+    ///
+    /// # Arguments
+    /// * `bounds` - Box to measure from (usually the ship)
+    pub fn wall_clearance(&self, bounds: &Aabb) -> Option<(f32, f32)> {
+        self.segments
+            .iter()
+            .filter(|segment| segment.x_start < bounds.right() && segment.x_end() > bounds.left())
+            .map(|segment| (bounds.top() - segment.ceiling, segment.floor - bounds.bottom()))
+            .reduce(|a, b| (a.0.min(b.0), a.1.min(b.1)))
+    }
+
+    /// Moves released pickups and keeps moving pickups inside the cave walls.
+    pub fn update_pickups(&mut self, dt: f32) {
+        let segments = &self.segments;
+        self.pickup_manager.update_physics(dt, |x| {
+            find_segment(segments, x).map(|segment| (segment.ceiling, segment.floor))
+        });
+    }
+
+    /// Casts a ray through the cave and returns the distance to the first wall.
+    ///
+    /// Walls are flat within a segment, so the ray is walked one segment at
+    /// a time. Returns `None` if no wall is hit within `max_distance` or the
+    /// ray leaves the generated part of the cave.
+    ///
+    /// # Arguments
+    /// * `origin` - Start of the ray (x, y)
+    /// * `direction` - Unit vector the ray travels along
+    /// * `max_distance` - Maximum distance to search
+    pub fn raycast(&self, origin: (f32, f32), direction: (f32, f32), max_distance: f32) -> Option<f32> {
+        const SEGMENT_NUDGE: f32 = 1e-3; // Steps over the boundary into the next segment
+        let (dir_x, dir_y) = direction;
+        let mut travelled = 0.0;
+
+        while travelled <= max_distance {
+            let x = origin.0 + dir_x * travelled;
+            let y = origin.1 + dir_y * travelled;
+            let segment = self.segment_at(x)?;
+
+            // The ray ran into the side of a step in the wall
+            if y <= segment.ceiling || y >= segment.floor {
+                return Some(travelled);
+            }
+
+            let to_wall = if dir_y < 0.0 {
+                (segment.ceiling - y) / dir_y
+            } else if dir_y > 0.0 {
+                (segment.floor - y) / dir_y
+            } else {
+                f32::INFINITY
+            };
+            let to_edge = if dir_x > 0.0 {
+                (segment.x_end() - x) / dir_x
+            } else if dir_x < 0.0 {
+                (segment.x_start - x) / dir_x
+            } else {
+                f32::INFINITY
+            };
+
+            if to_wall <= to_edge {
+                let hit = travelled + to_wall;
+                return (hit <= max_distance).then_some(hit);
+            }
+
+            travelled += to_edge + SEGMENT_NUDGE;
+        }
+
+        None
+    }
+
     /// Gets all current segments (for testing).
     pub fn segments(&self) -> &VecDeque<CaveSegment> {
         &self.segments
@@ -220,4 +447,47 @@ impl Cave {
     pub fn pickup_manager_mut(&mut self) -> &mut PickupManager {
         &mut self.pickup_manager
     }
+
+    /// Gets a reference to the hazard manager.
+    pub fn hazard_manager(&self) -> &HazardManager {
+        &self.hazard_manager
+    }
+
+    /// Gets a mutable reference to the hazard manager.
+    pub fn hazard_manager_mut(&mut self) -> &mut HazardManager {
+        &mut self.hazard_manager
+    }
+
+    /// Gets a reference to the anchor manager.
+    pub fn anchor_manager(&self) -> &AnchorManager {
+        &self.anchor_manager
+    }
+
+    /// Gets a mutable reference to the anchor manager.
+    pub fn anchor_manager_mut(&mut self) -> &mut AnchorManager {
+        &mut self.anchor_manager
+    }
+
+    /// Gets a reference to the drop-off platform manager.
+    pub fn drop_off_manager(&self) -> &DropOffManager {
+        &self.drop_off_manager
+    }
+
+    /// Gets a mutable reference to the drop-off platform manager.
+    pub fn drop_off_manager_mut(&mut self) -> &mut DropOffManager {
+        &mut self.drop_off_manager
+    }
+}
+
+/// Finds the segment covering an x-coordinate.
+///
+/// Segments are contiguous and equally wide, so the index is computed directly.
+fn find_segment(segments: &VecDeque<CaveSegment>, x: f32) -> Option<&CaveSegment> {
+    let first = segments.front()?;
+    if x < first.x_start {
+        return None;
+    }
+
+    let index = ((x - first.x_start) / CaveConstants::SEGMENT_WIDTH) as usize;
+    segments.get(index).filter(|segment| x < segment.x_end())
 }
@@ -74,6 +74,25 @@ pub fn aabb_overlap(
     check_aabb_overlap(&a, &b)
 }
 
+/// Gets the shortest distance between two axis-aligned bounding boxes.
+///
+/// Returns 0 when the boxes touch or overlap.
+///
+/// # Examples
+/// ```
+/// use core::collision::{aabb_gap, Aabb};
+///
+/// let a = Aabb::new(0.0, 0.0, 10.0, 10.0);
+/// let b = Aabb::new(13.0, 14.0, 10.0, 10.0);
+/// assert_eq!(aabb_gap(&a, &b), 5.0);
+/// ```
+pub fn aabb_gap(a: &Aabb, b: &Aabb) -> f32 {
+    let dx = (b.left() - a.right()).max(a.left() - b.right()).max(0.0);
+    let dy = (b.top() - a.bottom()).max(a.top() - b.bottom()).max(0.0);
+
+    (dx * dx + dy * dy).sqrt()
+}
+
 /// Checks if two AABB structs overlap.
 ///
 /// Internal helper function with low cyclomatic complexity.
@@ -92,3 +111,65 @@ fn check_aabb_overlap(a: &Aabb, b: &Aabb) -> bool {
     // No separation found, boxes must overlap
     true
 }
+
+/// Contact information for two overlapping rectangles.
+///
+/// The normal points from the second rectangle toward the first,
+/// i.e. the direction the first rectangle must move to separate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Contact {
+    pub normal: (f32, f32),
+    pub depth: f32,
+}
+
+/// Computes the contact between two axis-aligned bounding boxes.
+///
+/// Returns `None` if the rectangles do not overlap. Otherwise returns the
+/// axis of least penetration as the contact normal.
+///
+/// # Arguments
+/// * `a_pos` - Position (x, y) of the moving rectangle
+/// * `a_size` - Size (width, height) of the moving rectangle
+/// * `b_pos` - Position (x, y) of the obstacle
+/// * `b_size` - Size (width, height) of the obstacle
+///
+/// # Examples
+/// ```
+/// use core::collision::aabb_contact;
+///
+/// // Rectangle sinking 2px into the top of a floor
+/// let contact = aabb_contact((0.0, 0.0), (10.0, 10.0), (0.0, 8.0), (10.0, 10.0)).unwrap();
+/// assert_eq!(contact.normal, (0.0, -1.0));
+/// assert_eq!(contact.depth, 2.0);
+/// ```
+pub fn aabb_contact(
+    a_pos: (f32, f32),
+    a_size: (f32, f32),
+    b_pos: (f32, f32),
+    b_size: (f32, f32),
+) -> Option<Contact> {
+    let a = Aabb::new(a_pos.0, a_pos.1, a_size.0, a_size.1);
+    let b = Aabb::new(b_pos.0, b_pos.1, b_size.0, b_size.1);
+
+    if !check_aabb_overlap(&a, &b) {
+        return None;
+    }
+
+    // Penetration depth when pushing a out of b in each direction
+    let push_left = a.right() - b.left();
+    let push_right = b.right() - a.left();
+    let push_up = a.bottom() - b.top();
+    let push_down = b.bottom() - a.top();
+
+    let candidates = [
+        ((-1.0, 0.0), push_left),
+        ((1.0, 0.0), push_right),
+        ((0.0, -1.0), push_up),
+        ((0.0, 1.0), push_down),
+    ];
+
+    candidates
+        .iter()
+        .min_by(|x, y| x.1.partial_cmp(&y.1).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|&(normal, depth)| Contact { normal, depth })
+}
@@ -0,0 +1,127 @@
+use crate::constants::ComboConstants;
+use crate::pickup::{PickupEvent, PickupType};
+use crate::run::RunEvent;
+
+/// Reward handed out when a combo reaches a milestone.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ComboReward {
+    /// Fuel added to the tank
+    Fuel(f32),
+    /// Points added to the score
+    Points(u32),
+}
+
+impl ComboReward {
+    /// Gets the reward for a combo of the given length, if it is a milestone.
+    ///
+    /// Milestones alternate between fuel and points, starting with fuel.
+    ///
+    /// # Arguments
+    /// * `count` - Pickups chained so far
+    pub fn for_count(count: u32) -> Option<Self> {
+        if count == 0 || !count.is_multiple_of(ComboConstants::MILESTONE_STEP) {
+            return None;
+        }
+
+        let milestone = count / ComboConstants::MILESTONE_STEP;
+        if milestone % 2 == 1 {
+            Some(ComboReward::Fuel(ComboConstants::MILESTONE_FUEL))
+        } else {
+            Some(ComboReward::Points(ComboConstants::MILESTONE_POINTS))
+        }
+    }
+}
+
+/// Chains consecutive pickups into a combo.
+///
+/// Every collection restarts the combo timer; the combo ends when the timer
+/// runs out or a pickup scrolls past uncollected. Cargo pods are delivered
+/// rather than collected, so they neither extend nor break a combo.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ComboTracker {
+    /// Pickups chained in the current combo
+    count: u32,
+    /// Time left before the combo ends in seconds
+    time_left: f32,
+}
+
+impl ComboTracker {
+    /// Creates a tracker with no combo.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds one pickup event into the tracker.
+    ///
+    /// Returns a `Combo` run event when a collection extends a combo to two
+    /// or more pickups.
+    ///
+    /// # Arguments
+    /// * `event` - Collection or miss reported by the `PickupManager`
+    pub fn record(&mut self, event: PickupEvent) -> Option<RunEvent> {
+        match event {
+            PickupEvent::Collected(PickupType::Cargo) | PickupEvent::Missed(PickupType::Cargo) => None,
+            PickupEvent::Collected(_) => {
+                self.count = if self.time_left > 0.0 { self.count + 1 } else { 1 };
+                self.time_left = ComboConstants::WINDOW;
+                if self.count < 2 {
+                    return None;
+                }
+                Some(RunEvent::Combo {
+                    count: self.count,
+                    multiplier: self.multiplier(),
+                    reward: ComboReward::for_count(self.count),
+                })
+            }
+            PickupEvent::Missed(_) => {
+                self.reset();
+                None
+            }
+        }
+    }
+
+    /// Runs the combo timer down.
+    ///
+    /// # Arguments
+    /// * `dt` - Delta time in seconds
+    pub fn update(&mut self, dt: f32) {
+        if self.time_left <= 0.0 {
+            return;
+        }
+
+        self.time_left -= dt;
+        if self.time_left <= 0.0 {
+            self.reset();
+        }
+    }
+
+    /// Gets the number of pickups in the current combo.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Gets the current multiplier (1 when there is no combo).
+    pub fn multiplier(&self) -> u32 {
+        self.count.clamp(1, ComboConstants::MAX_MULTIPLIER)
+    }
+
+    /// Checks if a combo of two or more pickups is running.
+    pub fn is_active(&self) -> bool {
+        self.count >= 2
+    }
+
+    /// Gets the time left before the combo ends in seconds.
+    pub fn time_left(&self) -> f32 {
+        self.time_left
+    }
+
+    /// Gets the time left as a ratio of the combo window (0.0 to 1.0).
+    pub fn time_ratio(&self) -> f32 {
+        (self.time_left / ComboConstants::WINDOW).clamp(0.0, 1.0)
+    }
+
+    /// Ends the current combo.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
@@ -23,14 +23,56 @@ impl PickupConstants {
     
     /// Default fuel spawn distance for collision detection and fallbacks
     pub const DEFAULT_FUEL_SPAWN_DISTANCE: f32 = 300.0;
+    
+    /// Downward acceleration on released pickups (pixels/sec²)
+    pub const GRAVITY: f32 = 300.0;
+    
+    /// Fraction of velocity lost per second while drifting
+    pub const DRAG: f32 = 0.5;
+    
+    /// Fraction of speed kept when a drifting pickup bounces off the ceiling
+    pub const RESTITUTION: f32 = 0.3;
+    
+    /// Mass of a fuel depot (relative units)
+    pub const FUEL_MASS: f32 = 1.0;
+    
+    /// Mass of a shield cell (relative units)
+    pub const SHIELD_MASS: f32 = 0.6;
 }
 
-/// Fuel refill constants
+/// Fuel refill and consumption constants
 pub struct FuelConstants;
 
 impl FuelConstants {
     /// Percentage of max fuel restored when collecting a fuel pickup
     pub const REFILL_PERCENTAGE: f32 = 0.275; // 27.5% (average of 25-30%)
+    
+    /// Burn rate multiplier for full upward thrust
+    pub const MAIN_THRUST_FACTOR: f32 = 1.0;
+    
+    /// Burn rate multiplier for full downward thrust
+    pub const DOWN_THRUST_FACTOR: f32 = 0.5;
+    
+    /// Burn rate multiplier for a full forward boost
+    pub const FORWARD_BOOST_FACTOR: f32 = 0.75;
+    
+    /// Burn rate multiplier while the tractor beam is active
+    pub const BEAM_FACTOR: f32 = 0.25;
+    
+    /// Burn rate multiplier drained every frame regardless of input
+    pub const IDLE_FACTOR: f32 = 0.05;
+    
+    /// Mass of a full tank relative to the ship; an empty tank weighs nothing
+    pub const FULL_TANK_MASS: f32 = 0.5;
+    
+    /// Seconds of burn history used for endurance estimates
+    pub const BURN_HISTORY_WINDOW: f32 = 3.0;
+    
+    /// Estimated seconds of burn left that trigger the low-fuel warning
+    pub const LOW_WARNING_SECONDS: f32 = 10.0;
+    
+    /// Estimated seconds of burn left that trigger the critical alarm
+    pub const CRITICAL_WARNING_SECONDS: f32 = 4.0;
 }
 
 /// Cave generation constants
@@ -54,9 +96,470 @@ impl TractorBeamConstants {
     /// Wider area for maintaining attraction once started (prevents oscillation)
     pub const ATTRACTION_HOLD_WIDTH: f32 = 48.0;
     
-    /// Speed at which pickups are attracted toward the player (pixels per second)
+    /// Top speed at which pickups are attracted toward the player (pixels per second)
     pub const ATTRACTION_SPEED: f32 = 200.0;
     
+    /// Beam acceleration on a pickup of unit mass at full strength (pixels/sec²)
+    pub const BEAM_ACCELERATION: f32 = 1500.0;
+    
     /// Maximum duration the beam can remain active in seconds
     pub const MAX_DURATION: f32 = 2.0;
+    
+    /// Recoil acceleration in push mode when the beam touches a wall at point-blank range (pixels/sec²)
+    pub const PUSH_RECOIL: f32 = 150.0;
+    
+    /// Size of the beam energy pool
+    pub const MAX_ENERGY: f32 = 100.0;
+    
+    /// Energy drained per second while the beam is active
+    pub const ENERGY_DRAIN_RATE: f32 = 40.0;
+    
+    /// Energy recharged per second while the beam is off
+    pub const ENERGY_RECHARGE_RATE: f32 = 20.0;
+    
+    /// Minimum energy needed to switch the beam on
+    pub const MIN_ACTIVATION_ENERGY: f32 = 10.0;
+    
+    /// Lockout after the energy pool is drained completely in seconds
+    pub const OVERHEAT_DURATION: f32 = 3.0;
+    
+    /// Fuel spent per unit of beam energy when running on the fuel fallback
+    pub const FUEL_PER_ENERGY: f32 = 0.25;
+    
+    /// Fraction of the beam strength left at maximum range
+    pub const FALLOFF_MIN_STRENGTH: f32 = 0.5;
+}
+
+/// Grapple constants
+pub struct GrappleConstants;
+
+impl GrappleConstants {
+    /// RNG seed offset to differentiate from cave, pickup and hazard generation
+    pub const RNG_SEED_OFFSET: u32 = 7919;
+    
+    /// Distance before the first anchor crystal spawns (pixels)
+    pub const INITIAL_SPAWN_DELAY: f32 = 600.0;
+    
+    /// Average distance between anchor crystals (pixels)
+    pub const ANCHOR_SPAWN_DISTANCE: f32 = 700.0;
+    
+    /// Variation percentage for anchor spacing (±30%)
+    pub const SPAWN_DISTANCE_VARIATION: f32 = 0.3;
+    
+    /// Distance behind the camera after which anchors are removed (pixels)
+    pub const CLEANUP_DISTANCE: f32 = 400.0;
+    
+    /// Size of an anchor crystal in pixels
+    pub const ANCHOR_SIZE: f32 = 14.0;
+    
+    /// Speed at which the rope reels in (pixels per second)
+    pub const REEL_SPEED: f32 = 120.0;
+    
+    /// Acceleration pulling the ship toward the anchor (pixels/sec²)
+    pub const PULL_ACCELERATION: f32 = 500.0;
+    
+    /// Stretch beyond the rope length at which the rope snaps (pixels)
+    pub const BREAK_STRETCH: f32 = 40.0;
+    
+    /// Distance to the anchor at which the rope lets go (pixels)
+    pub const ARRIVAL_DISTANCE: f32 = 40.0;
+    
+    /// Beam time and energy drain multiplier while grappling
+    pub const BEAM_COST_MULTIPLIER: f32 = 2.0;
+}
+
+/// Cargo delivery constants
+pub struct CargoConstants;
+
+impl CargoConstants {
+    /// RNG seed offset to differentiate from the other spawners
+    pub const RNG_SEED_OFFSET: u32 = 4099;
+    
+    /// Probability that a spawned pickup is a cargo pod in cargo mode
+    pub const POD_SPAWN_CHANCE: f32 = 0.35;
+    
+    /// Size of a cargo pod in pixels
+    pub const POD_SIZE: f32 = 18.0;
+    
+    /// Mass of a cargo pod relative to the ship
+    pub const POD_MASS: f32 = 0.6;
+    
+    /// Length of the tether between ship and pod (pixels)
+    pub const TETHER_LENGTH: f32 = 50.0;
+    
+    /// Downward acceleration on a carried pod (pixels/sec²)
+    pub const GRAVITY: f32 = 300.0;
+    
+    /// Fraction of pod velocity lost per second
+    pub const DRAG: f32 = 0.8;
+    
+    /// Distance before the first drop-off platform spawns (pixels)
+    pub const INITIAL_SPAWN_DELAY: f32 = 1500.0;
+    
+    /// Average distance between drop-off platforms (pixels)
+    pub const PLATFORM_SPAWN_DISTANCE: f32 = 1400.0;
+    
+    /// Variation percentage for platform spacing (±30%)
+    pub const SPAWN_DISTANCE_VARIATION: f32 = 0.3;
+    
+    /// Width of a drop-off platform in pixels
+    pub const PLATFORM_WIDTH: f32 = 80.0;
+    
+    /// Height of a drop-off platform in pixels
+    pub const PLATFORM_HEIGHT: f32 = 8.0;
+    
+    /// Distance behind the camera after which platforms are removed (pixels)
+    pub const CLEANUP_DISTANCE: f32 = 400.0;
+    
+    /// Points awarded per delivered pod
+    pub const DELIVERY_POINTS: u32 = 500;
+    
+    /// Extra points for meeting a level's delivery goal
+    pub const GOAL_BONUS: u32 = 1500;
+}
+
+/// Landing pad constants
+pub struct LandingPadConstants;
+
+impl LandingPadConstants {
+    /// RNG seed offset to differentiate from the other spawners
+    pub const RNG_SEED_OFFSET: u32 = 6151;
+    
+    /// Distance before the first landing pad is generated (pixels)
+    pub const INITIAL_SPAWN_DELAY: f32 = 1200.0;
+    
+    /// Average distance between landing pads (pixels)
+    pub const PAD_SPAWN_DISTANCE: f32 = 2500.0;
+    
+    /// Variation percentage for pad spacing (±30%)
+    pub const SPAWN_DISTANCE_VARIATION: f32 = 0.3;
+    
+    /// Number of flat floor segments making up one pad
+    pub const PAD_SEGMENTS: u32 = 4;
+    
+    /// Highest ship speed at which touching a pad counts as a landing (pixels/sec)
+    pub const MAX_LANDING_SPEED: f32 = 60.0;
+    
+    /// Gap between ship and pad still counted as resting on it (pixels)
+    pub const REST_TOLERANCE: f32 = 2.0;
+}
+
+/// Near-miss detection constants
+pub struct NearMissConstants;
+
+impl NearMissConstants {
+    /// Clearance at or below which a pass counts as close (pixels)
+    pub const THRESHOLD: f32 = 12.0;
+    
+    /// Clearance the ship must pull back out to before a pass ends (pixels)
+    pub const RECOVERY_CLEARANCE: f32 = 30.0;
+    
+    /// Longest gap between near-misses that keeps a streak alive (seconds)
+    pub const STREAK_WINDOW: f32 = 3.0;
+    
+    /// Highest streak multiplier
+    pub const MAX_MULTIPLIER: u32 = 5;
+    
+    /// How long the near-miss cue stays on screen (seconds)
+    pub const CUE_DURATION: f32 = 0.8;
+}
+
+/// High-score table constants
+pub struct HighScoreConstants;
+
+impl HighScoreConstants {
+    /// Number of runs kept in the table
+    pub const TABLE_SIZE: usize = 10;
+    
+    /// Storage key of the table
+    pub const STORAGE_KEY: &str = "highscores";
+    
+    /// Storage key of the last name entered
+    pub const NAME_STORAGE_KEY: &str = "player_name";
+    
+    /// Longest name that can be typed
+    pub const MAX_NAME_LENGTH: usize = 10;
+    
+    /// Number of letters in arcade-style entry
+    pub const ARCADE_NAME_LENGTH: usize = 3;
+    
+    /// Characters arcade-style entry cycles through
+    pub const ARCADE_ALPHABET: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789 ";
+    
+    /// Name recorded when none was entered
+    pub const DEFAULT_NAME: &str = "???";
+}
+
+/// Run and lifetime statistics constants
+pub struct StatsConstants;
+
+impl StatsConstants {
+    /// Storage key of the lifetime statistics
+    pub const STORAGE_KEY: &str = "lifetime_stats";
+}
+
+/// Achievement constants
+pub struct AchievementConstants;
+
+impl AchievementConstants {
+    /// Storage key of the unlocked achievement ids
+    pub const STORAGE_KEY: &str = "achievements";
+    
+    /// Seconds an unlock toast stays on screen
+    pub const TOAST_DURATION: f32 = 3.0;
+}
+
+/// Death analytics constants
+pub struct AnalyticsConstants;
+
+impl AnalyticsConstants {
+    /// Storage key of the death log
+    pub const STORAGE_KEY: &str = "deaths";
+    
+    /// Maximum number of deaths kept in the log
+    pub const MAX_RECORDS: usize = 5000;
+    
+    /// Header row of the CSV death log
+    pub const CSV_HEADER: &str = "world_x,level,seed,cause,mode,difficulty";
+    
+    /// Width of one heat strip bucket in pixels (one cave segment)
+    pub const HEAT_BUCKET_WIDTH: f32 = 50.0;
+}
+
+/// Pickup combo constants
+pub struct ComboConstants;
+
+impl ComboConstants {
+    /// Time allowed between pickups to keep a combo going (seconds)
+    pub const WINDOW: f32 = 4.0;
+    
+    /// Highest combo multiplier
+    pub const MAX_MULTIPLIER: u32 = 5;
+    
+    /// Number of chained pickups between milestone rewards
+    pub const MILESTONE_STEP: u32 = 5;
+    
+    /// Fuel granted at odd-numbered milestones
+    pub const MILESTONE_FUEL: f32 = 25.0;
+    
+    /// Points granted at even-numbered milestones
+    pub const MILESTONE_POINTS: u32 = 250;
+}
+
+/// Scoring constants
+pub struct ScoreConstants;
+
+impl ScoreConstants {
+    /// Points per pixel of distance covered
+    pub const POINTS_PER_PIXEL: f32 = 0.1;
+    
+    /// Points for each collected pickup
+    pub const PICKUP_POINTS: u32 = 50;
+    
+    /// Extra points for a pickup caught with the tractor beam
+    pub const BEAM_CATCH_BONUS: u32 = 50;
+    
+    /// Points for a near-miss, multiplied by the streak
+    pub const NEAR_MISS_POINTS: u32 = 20;
+    
+    /// Extra points per pickup for each combo multiplier step above one
+    pub const COMBO_STEP_POINTS: u32 = 25;
+    
+    /// Points for finishing a level with a full tank, scaled by the fuel left
+    pub const FUEL_BONUS: f32 = 1000.0;
+    
+    /// Points for clearing a level, multiplied by the level number
+    pub const LEVEL_CLEAR_BONUS: u32 = 500;
+    
+    /// Points for clearing the final campaign level
+    pub const CAMPAIGN_VICTORY_BONUS: u32 = 5000;
+    
+    /// Points for finishing a time attack in par time; faster runs score proportionally more
+    pub const TIME_ATTACK_PAR_POINTS: f32 = 10000.0;
+}
+
+/// Game mode rule constants
+pub struct GameModeConstants;
+
+impl GameModeConstants {
+    /// Distance to cover in time attack, in pixels
+    pub const TIME_ATTACK_DISTANCE: f32 = 6000.0;
+    
+    /// Time attack finish time without boosting, in seconds
+    pub const TIME_ATTACK_PAR_SECONDS: f32 = 50.0;
+    
+    /// Scroll speed multiplier while boosting in time attack
+    pub const TIME_ATTACK_BOOST_MULTIPLIER: f32 = 2.0;
+    
+    /// Hazards per 1000 pixels added by each endless level past the last configured one
+    pub const ENDLESS_HAZARD_STEP: f32 = 0.3;
+    
+    /// Highest hazard density endless levels scale up to
+    pub const ENDLESS_MAX_HAZARD_DENSITY: f32 = 4.0;
+    
+    /// Pixels added to the fuel depot spacing by each endless level
+    pub const ENDLESS_FUEL_SPACING_STEP: f32 = 50.0;
+    
+    /// Widest fuel depot spacing endless levels scale up to
+    pub const ENDLESS_MAX_FUEL_SPACING: f32 = 1200.0;
+    
+    /// Factor applied to the landing pad refuel rate by each endless level
+    pub const ENDLESS_REFUEL_RATE_FACTOR: f32 = 0.9;
+    
+    /// Lowest landing pad refuel rate endless levels scale down to
+    pub const ENDLESS_MIN_REFUEL_RATE: f32 = 2.0;
+}
+
+/// World layout constants shared by the simulation and its tools
+pub struct WorldConstants;
+
+impl WorldConstants {
+    /// Horizontal scroll speed of the cave in pixels per second
+    pub const SCROLL_SPEED: f32 = 120.0;
+    
+    /// Height of the playfield in pixels (matches the window height)
+    pub const SCREEN_HEIGHT: f32 = 600.0;
+    
+    /// Fixed seed so every run flies the same cave
+    pub const CAVE_SEED: u32 = 42;
+}
+
+/// Cave passability search constants
+pub struct PassabilityConstants;
+
+impl PassabilityConstants {
+    /// Horizontal distance covered by one search step in pixels
+    pub const X_STEP: f32 = 10.0;
+    
+    /// Vertical position resolution of the search grid in pixels
+    pub const Y_STEP: f32 = 4.0;
+    
+    /// Vertical velocity resolution of the search grid in pixels per second
+    pub const VELOCITY_STEP: f32 = 20.0;
+    
+    /// Largest vertical speed the search will consider (pixels per second)
+    pub const MAX_VERTICAL_SPEED: f32 = 400.0;
+    
+    /// Upper bound on expanded search nodes before giving up
+    pub const MAX_EXPANSIONS: usize = 200_000;
+    
+    /// Number of trailing segments checked when a new segment is generated
+    pub const REPAIR_WINDOW: usize = 6;
+    
+    /// Number of times a segment is blended toward its predecessor before falling back to a copy
+    pub const MAX_REPAIR_STEPS: u32 = 4;
+}
+
+/// Hull damage model constants
+pub struct HullConstants;
+
+impl HullConstants {
+    /// Hull points of an undamaged ship
+    pub const MAX_POINTS: f32 = 100.0;
+    
+    /// Hull points lost per pixel/second of impact speed
+    pub const DAMAGE_PER_IMPACT_SPEED: f32 = 0.15;
+    
+    /// Minimum hull points lost by any wall contact
+    pub const MIN_DAMAGE: f32 = 5.0;
+    
+    /// Invulnerability time after taking a hit in seconds
+    pub const INVULNERABILITY_DURATION: f32 = 1.0;
+    
+    /// Fraction of impact speed kept when bouncing off a wall
+    pub const BOUNCE_RESTITUTION: f32 = 0.6;
+    
+    /// Minimum speed away from the wall after a bounce (pixels per second)
+    pub const MIN_BOUNCE_SPEED: f32 = 60.0;
+    
+    /// Maximum number of shield charges the ship can hold
+    pub const MAX_SHIELD_CHARGES: u32 = 3;
+    
+    /// Chance that a spawned pickup is a shield when the damage model is active
+    pub const SHIELD_SPAWN_CHANCE: f32 = 0.2;
+    
+    /// Hull fraction at or below which the hull display turns red
+    pub const LOW_HULL_THRESHOLD: f32 = 0.2;
+}
+
+/// Lives and checkpoint respawn constants
+pub struct RespawnConstants;
+
+impl RespawnConstants {
+    /// Number of ships at the start of a run
+    pub const STARTING_LIVES: u32 = 3;
+    
+    /// Distance between automatic checkpoints (pixels)
+    pub const CHECKPOINT_INTERVAL: f32 = 1000.0;
+    
+    /// Duration of the fly-in back to the checkpoint in seconds
+    pub const FLY_IN_DURATION: f32 = 1.5;
+    
+    /// Invulnerability time after the fly-in finishes in seconds
+    pub const INVULNERABILITY_DURATION: f32 = 2.0;
+}
+
+/// Moving hazard constants
+pub struct HazardConstants;
+
+impl HazardConstants {
+    /// RNG seed offset to differentiate from cave and pickup generation
+    pub const RNG_SEED_OFFSET: u32 = 1337;
+    
+    /// Distance before the first hazard spawns (pixels)
+    pub const INITIAL_SPAWN_DELAY: f32 = 1200.0;
+    
+    /// Variation percentage for hazard spacing (±30%)
+    pub const SPAWN_DISTANCE_VARIATION: f32 = 0.3;
+    
+    /// Distance behind the camera after which hazards are removed (pixels)
+    pub const CLEANUP_DISTANCE: f32 = 200.0;
+    
+    /// Impact speed used for hull damage when a hazard hits (pixels per second)
+    pub const IMPACT_SPEED: f32 = 250.0;
+    
+    /// Size of a proximity mine in pixels
+    pub const MINE_SIZE: f32 = 20.0;
+    
+    /// Vertical drift speed of an idle mine (pixels per second)
+    pub const MINE_DRIFT_SPEED: f32 = 20.0;
+    
+    /// Distance at which a mine starts drifting toward the ship (pixels)
+    pub const MINE_PROXIMITY_RADIUS: f32 = 90.0;
+    
+    /// Speed of a mine drifting toward the ship (pixels per second)
+    pub const MINE_CHASE_SPEED: f32 = 45.0;
+    
+    /// Speed at which the tractor beam pushes mines away (pixels per second)
+    pub const MINE_PUSH_SPEED: f32 = 250.0;
+    
+    /// Speed at which a pulling tractor beam draws mines in (pixels per second)
+    pub const MINE_PULL_SPEED: f32 = 250.0;
+    
+    /// Size of a falling rock in pixels
+    pub const ROCK_SIZE: f32 = 24.0;
+    
+    /// Horizontal distance ahead of the ship at which rocks detach (pixels)
+    pub const ROCK_TRIGGER_DISTANCE: f32 = 60.0;
+    
+    /// Downward acceleration of a falling rock (pixels/sec²)
+    pub const ROCK_GRAVITY: f32 = 400.0;
+    
+    /// Size of a wall turret in pixels
+    pub const TURRET_SIZE: f32 = 20.0;
+    
+    /// Distance at which turrets open fire on the ship (pixels)
+    pub const TURRET_RANGE: f32 = 350.0;
+    
+    /// Time between turret shots in seconds
+    pub const TURRET_FIRE_INTERVAL: f32 = 2.0;
+    
+    /// Speed of turret projectiles (pixels per second)
+    pub const PROJECTILE_SPEED: f32 = 90.0;
+    
+    /// Size of a turret projectile in pixels
+    pub const PROJECTILE_SIZE: f32 = 8.0;
+    
+    /// Lifetime of a turret projectile in seconds
+    pub const PROJECTILE_LIFETIME: f32 = 5.0;
 }
\ No newline at end of file
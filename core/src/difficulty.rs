@@ -0,0 +1,131 @@
+/// Difficulty preset chosen before a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Insane,
+}
+
+/// Multipliers a difficulty preset applies to the simulation.
+///
+/// Every field scales a base value; `Normal` leaves them all at 1.0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DifficultySettings {
+    /// Scales the cave scroll speed
+    pub scroll_speed: f32,
+    /// Scales the fuel burn rate
+    pub fuel_burn_rate: f32,
+    /// Scales the share of the tank a fuel depot refills
+    pub refill_percentage: f32,
+    /// Scales the gap between ceiling and floor, including the minimum gap
+    pub cave_gap: f32,
+    /// Scales the distance between fuel depots
+    pub pickup_spacing: f32,
+    /// Scales how long one tractor beam activation lasts
+    pub beam_duration: f32,
+    /// Scales the hazard density of every level
+    pub hazard_density: f32,
+}
+
+impl Difficulty {
+    /// Every difficulty, easiest first.
+    pub const ALL: [Difficulty; 4] = [
+        Difficulty::Easy,
+        Difficulty::Normal,
+        Difficulty::Hard,
+        Difficulty::Insane,
+    ];
+
+    /// Finds the difficulty with the given storage name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|difficulty| difficulty.to_string() == name)
+    }
+
+    /// Gets the next harder difficulty, wrapping around to the easiest.
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&difficulty| difficulty == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Gets the next easier difficulty, wrapping around to the hardest.
+    pub fn previous(self) -> Self {
+        let index = Self::ALL.iter().position(|&difficulty| difficulty == self).unwrap_or(0);
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    /// Name shown in menus.
+    pub fn title(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+            Difficulty::Insane => "Insane",
+        }
+    }
+
+    /// Gets the multipliers this preset applies.
+    ///
+    /// Harder presets scroll faster, burn more, refill less, narrow the cave,
+    /// space depots further apart, shorten the beam and add hazards together,
+    /// so no single setting makes a preset easier than the one below it.
+    pub fn settings(self) -> DifficultySettings {
+        match self {
+            Difficulty::Easy => DifficultySettings {
+                scroll_speed: 0.85,
+                fuel_burn_rate: 0.75,
+                refill_percentage: 1.3,
+                cave_gap: 1.15,
+                pickup_spacing: 0.8,
+                beam_duration: 1.25,
+                hazard_density: 0.5,
+            },
+            Difficulty::Normal => DifficultySettings {
+                scroll_speed: 1.0,
+                fuel_burn_rate: 1.0,
+                refill_percentage: 1.0,
+                cave_gap: 1.0,
+                pickup_spacing: 1.0,
+                beam_duration: 1.0,
+                hazard_density: 1.0,
+            },
+            Difficulty::Hard => DifficultySettings {
+                scroll_speed: 1.15,
+                fuel_burn_rate: 1.25,
+                refill_percentage: 0.8,
+                cave_gap: 0.9,
+                pickup_spacing: 1.2,
+                beam_duration: 0.85,
+                hazard_density: 1.5,
+            },
+            Difficulty::Insane => DifficultySettings {
+                scroll_speed: 1.3,
+                fuel_burn_rate: 1.5,
+                refill_percentage: 0.6,
+                cave_gap: 0.8,
+                pickup_spacing: 1.4,
+                beam_duration: 0.7,
+                hazard_density: 2.0,
+            },
+        }
+    }
+}
+
+impl Default for DifficultySettings {
+    fn default() -> Self {
+        Difficulty::Normal.settings()
+    }
+}
+
+impl std::fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+            Difficulty::Insane => "insane",
+        };
+        write!(f, "{}", name)
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::VecDeque;
+
+use crate::constants::FuelConstants;
+use crate::fuel::Fuel;
+
+/// Fuel warning level, ordered by severity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FuelWarning {
+    /// Plenty of thrust left
+    Normal,
+    /// Running low; time to look for a depot
+    Low,
+    /// About to run dry
+    Critical,
+}
+
+impl FuelWarning {
+    /// Gets the warning level for an endurance estimate.
+    ///
+    /// # Arguments
+    /// * `seconds_left` - Estimated seconds of burn left; `None` means unlimited
+    pub fn from_seconds_left(seconds_left: Option<f32>) -> Self {
+        match seconds_left {
+            Some(seconds) if seconds <= FuelConstants::CRITICAL_WARNING_SECONDS => FuelWarning::Critical,
+            Some(seconds) if seconds <= FuelConstants::LOW_WARNING_SECONDS => FuelWarning::Low,
+            _ => FuelWarning::Normal,
+        }
+    }
+}
+
+/// Endurance estimate for the current frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FuelOutlook {
+    /// Estimated seconds until the tank is empty; `None` if nothing is burning
+    pub seconds_left: Option<f32>,
+    /// Distance ahead to the next known fuel depot, if any
+    pub depot_distance: Option<f32>,
+    /// Whether the fuel lasts until the next known depot is reached
+    pub can_reach_depot: bool,
+    /// Warning level for the estimate
+    pub warning: FuelWarning,
+}
+
+/// Estimates remaining endurance from recent burn history.
+///
+/// Keeps a sliding window of net burn rates so the estimate follows how the
+/// ship is actually being flown rather than the worst case. Refuelling on a
+/// pad counts as negative burn.
+#[derive(Debug, Clone, Default)]
+pub struct EnduranceEstimator {
+    /// Recent (dt, burn rate) samples, oldest first
+    samples: VecDeque<(f32, f32)>,
+    /// Total time covered by the samples
+    window_time: f32,
+    /// Last warning level reported
+    warning: Option<FuelWarning>,
+}
+
+impl EnduranceEstimator {
+    /// Creates an estimator with no history.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records one frame of fuel burn.
+    ///
+    /// # Arguments
+    /// * `dt` - Delta time in seconds
+    /// * `burn_rate` - Net fuel burned per second during this frame; negative while refuelling
+    pub fn record(&mut self, dt: f32, burn_rate: f32) {
+        if dt <= 0.0 {
+            return;
+        }
+
+        self.samples.push_back((dt, burn_rate));
+        self.window_time += dt;
+
+        // Drop samples that have fallen out of the window
+        while self.window_time > FuelConstants::BURN_HISTORY_WINDOW {
+            match self.samples.pop_front() {
+                Some((old_dt, _)) => self.window_time -= old_dt,
+                None => break,
+            }
+        }
+    }
+
+    /// Gets the average burn rate over the history window (fuel per second).
+    pub fn average_burn_rate(&self) -> f32 {
+        if self.window_time <= 0.0 {
+            return 0.0;
+        }
+
+        let burned: f32 = self.samples.iter().map(|(dt, rate)| dt * rate).sum();
+        burned / self.window_time
+    }
+
+    /// Estimates the seconds of burn left at the recent average rate.
+    ///
+    /// Returns `None` when nothing has been burning, or refuelling kept up with it.
+    pub fn seconds_left(&self, fuel: &Fuel) -> Option<f32> {
+        let rate = self.average_burn_rate();
+        if rate <= 0.0 {
+            None
+        } else {
+            Some(fuel.current / rate)
+        }
+    }
+
+    /// Builds the endurance outlook for this frame.
+    ///
+    /// # Arguments
+    /// * `fuel` - Current fuel tank
+    /// * `ship_x` - Ship x-coordinate
+    /// * `next_depot_x` - X-coordinate of the next known fuel depot ahead
+    /// * `speed` - Forward speed of the ship in pixels per second
+    pub fn outlook(&self, fuel: &Fuel, ship_x: f32, next_depot_x: Option<f32>, speed: f32) -> FuelOutlook {
+        let seconds_left = self.seconds_left(fuel);
+        let depot_distance = next_depot_x.map(|x| (x - ship_x).max(0.0));
+
+        // Without a known depot ahead there is nothing to fall short of
+        let can_reach_depot = match (seconds_left, depot_distance) {
+            (None, _) | (_, None) => true,
+            (Some(seconds), Some(distance)) => speed > 0.0 && distance / speed <= seconds,
+        };
+
+        FuelOutlook {
+            seconds_left,
+            depot_distance,
+            can_reach_depot,
+            warning: FuelWarning::from_seconds_left(seconds_left),
+        }
+    }
+
+    /// Tracks the warning level and reports when it gets more severe.
+    ///
+    /// Returns the new level only when it rises, so each alarm fires once
+    /// per escalation rather than every frame.
+    ///
+    /// # Arguments
+    /// * `warning` - Warning level for this frame
+    pub fn update_warning(&mut self, warning: FuelWarning) -> Option<FuelWarning> {
+        let previous = self.warning.replace(warning).unwrap_or(FuelWarning::Normal);
+        (warning > previous).then_some(warning)
+    }
+
+    /// Clears the burn history and warning state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
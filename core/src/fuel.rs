@@ -3,6 +3,87 @@
 /// Manages fuel consumption, refilling, and empty state detection
 /// following the Single Responsibility Principle.
 
+use crate::constants::FuelConstants;
+use crate::player::PlayerInput;
+
+/// Throttle levels for every fuel-consuming action in one frame.
+///
+/// Each level is in [0, 1], so analog input can burn fuel in proportion
+/// to how hard it is pushed.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FuelDemand {
+    pub main_thrust: f32,
+    pub down_thrust: f32,
+    pub forward_boost: f32,
+    pub beam: f32,
+}
+
+impl FuelDemand {
+    /// Builds a demand from player input.
+    ///
+    /// Analog input burns in proportion to its throttle; pressed keys are
+    /// full throttle. Left movement (braking) does not consume fuel.
+    ///
+    /// # Arguments
+    /// * `input` - Player input for this frame
+    /// * `beam_fuelled` - Whether the beam's fuel cost is charged this frame
+    pub fn from_input(input: PlayerInput, beam_fuelled: bool) -> Self {
+        let throttle = input.throttle_levels();
+        Self {
+            main_thrust: throttle.up,
+            down_thrust: throttle.down,
+            forward_boost: throttle.right,
+            beam: if beam_fuelled { 1.0 } else { 0.0 },
+        }
+    }
+
+    /// Checks if any thruster is firing.
+    pub fn is_thrusting(&self) -> bool {
+        self.main_thrust > 0.0 || self.down_thrust > 0.0 || self.forward_boost > 0.0
+    }
+}
+
+/// Per-action fuel consumption profile.
+///
+/// Rates are multipliers of the fuel container's base burn rate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FuelConsumption {
+    pub main_thrust: f32,
+    pub down_thrust: f32,
+    pub forward_boost: f32,
+    pub beam: f32,
+    pub idle: f32,
+}
+
+impl Default for FuelConsumption {
+    fn default() -> Self {
+        Self {
+            main_thrust: FuelConstants::MAIN_THRUST_FACTOR,
+            down_thrust: FuelConstants::DOWN_THRUST_FACTOR,
+            forward_boost: FuelConstants::FORWARD_BOOST_FACTOR,
+            beam: FuelConstants::BEAM_FACTOR,
+            idle: FuelConstants::IDLE_FACTOR,
+        }
+    }
+}
+
+impl FuelConsumption {
+    /// Gets the burn rate multiplier for a frame's demand.
+    ///
+    /// Throttle levels are clamped to [0, 1]; the idle drain always applies.
+    ///
+    /// # Arguments
+    /// * `demand` - Throttle levels for this frame
+    pub fn multiplier(&self, demand: FuelDemand) -> f32 {
+        let throttle = |level: f32| level.clamp(0.0, 1.0);
+        self.idle
+            + self.main_thrust * throttle(demand.main_thrust)
+            + self.down_thrust * throttle(demand.down_thrust)
+            + self.forward_boost * throttle(demand.forward_boost)
+            + self.beam * throttle(demand.beam)
+    }
+}
+
 /// Fuel container with consumption and refilling capabilities.
 ///
 /// Tracks current fuel level, maximum capacity, and burn rate.
@@ -27,19 +108,19 @@ impl Fuel {
         }
     }
 
-    /// Burns fuel over time when consuming is active.
+    /// Burns fuel over time in proportion to the frame's demand.
     ///
     /// Returns true if fuel becomes empty during this burn cycle.
     ///
     /// # Arguments
     /// * `dt` - Delta time in seconds
-    /// * `consuming` - Whether fuel should be consumed this frame
-    pub fn burn(&mut self, dt: f32, consuming: bool) -> bool {
-        if !consuming {
+    /// * `multiplier` - Burn rate multiplier for this frame (see `FuelConsumption`)
+    pub fn burn(&mut self, dt: f32, multiplier: f32) -> bool {
+        if multiplier <= 0.0 {
             return false;
         }
 
-        let burn_amount = self.burn_rate * dt;
+        let burn_amount = self.burn_rate * multiplier * dt;
         let was_empty = self.current <= 0.0;
 
         self.current = (self.current - burn_amount).max(0.0);
@@ -48,6 +129,22 @@ impl Fuel {
         !was_empty && self.current <= 0.0
     }
 
+    /// Draws a fixed amount of fuel, for systems that run on the tank.
+    ///
+    /// Returns true if fuel becomes empty during this draw.
+    ///
+    /// # Arguments
+    /// * `amount` - Amount of fuel to draw
+    pub fn draw(&mut self, amount: f32) -> bool {
+        if amount <= 0.0 {
+            return false;
+        }
+
+        let was_empty = self.current <= 0.0;
+        self.current = (self.current - amount).max(0.0);
+        !was_empty && self.current <= 0.0
+    }
+
     /// Refills fuel by the specified amount, capped at maximum.
     ///
     /// # Arguments
@@ -61,6 +158,13 @@ impl Fuel {
         self.current <= 0.0
     }
 
+    /// Gets the mass of the fuel carried, in ship mass units.
+    ///
+    /// Scales linearly from nothing when empty to `FULL_TANK_MASS` when full.
+    pub fn mass(&self) -> f32 {
+        self.ratio() * FuelConstants::FULL_TANK_MASS
+    }
+
     /// Gets the fuel ratio (current/max) for UI display.
     pub fn ratio(&self) -> f32 {
         if self.max <= 0.0 {
@@ -8,6 +8,64 @@ pub enum GameState {
     Playing,
     Paused,
     GameOver,
+    Respawning, // Ship flies back in to the last checkpoint
+    NameEntry,  // Run made the high-score table; player enters a name
+    Victory,    // Run reached its goal: campaign cleared or time attack finished
+}
+
+/// What ended a ship's flight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeathCause {
+    CeilingCrash,
+    FloorCrash,
+    Obstacle,
+    Hazard,
+    FuelExhausted,
+}
+
+impl DeathCause {
+    /// Every death cause, in display order.
+    pub const ALL: [DeathCause; 5] = [
+        DeathCause::CeilingCrash,
+        DeathCause::FloorCrash,
+        DeathCause::Obstacle,
+        DeathCause::Hazard,
+        DeathCause::FuelExhausted,
+    ];
+
+    /// Finds the death cause with the given display name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|cause| cause.to_string() == name)
+    }
+
+    /// Short message for the game-over screen.
+    pub fn message(self) -> &'static str {
+        match self {
+            DeathCause::CeilingCrash => "CRASHED INTO THE CEILING!",
+            DeathCause::FloorCrash => "CRASHED INTO THE FLOOR!",
+            DeathCause::Obstacle => "HIT AN OBSTACLE!",
+            DeathCause::Hazard => "DESTROYED BY A HAZARD!",
+            DeathCause::FuelExhausted => "OUT OF FUEL!",
+        }
+    }
+
+    /// Checks if the death was caused by a collision rather than fuel.
+    pub fn is_collision(self) -> bool {
+        self != DeathCause::FuelExhausted
+    }
+}
+
+impl std::fmt::Display for DeathCause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            DeathCause::CeilingCrash => "ceiling crash",
+            DeathCause::FloorCrash => "floor crash",
+            DeathCause::Obstacle => "obstacle",
+            DeathCause::Hazard => "hazard",
+            DeathCause::FuelExhausted => "fuel exhaustion",
+        };
+        write!(f, "{}", name)
+    }
 }
 
 /// Events that can trigger state transitions.
@@ -17,9 +75,14 @@ pub enum GameState {
 pub enum GameEvent {
     Start,
     PauseToggle,
-    Dead,
+    Dead(DeathCause),
     Reset,
     BackToMenu, // Neues Event für Rückkehr zum Hauptmenü
+    LifeLost,
+    RespawnComplete,
+    NewHighScore, // Final ship lost with a score that makes the table
+    NameConfirmed,
+    RunComplete, // Run reached its goal, or a run without death was ended
 }
 
 impl GameState {
@@ -35,18 +98,36 @@ impl GameState {
 
             // From Playing
             (GameState::Playing, GameEvent::PauseToggle) => GameState::Paused,
-            (GameState::Playing, GameEvent::Dead) => GameState::GameOver,
+            (GameState::Playing, GameEvent::Dead(_)) => GameState::GameOver,
+            (GameState::Playing, GameEvent::LifeLost) => GameState::Respawning,
+            (GameState::Playing, GameEvent::NewHighScore) => GameState::NameEntry,
+            (GameState::Playing, GameEvent::RunComplete) => GameState::Victory,
+
+            // From NameEntry
+            (GameState::NameEntry, GameEvent::NameConfirmed) => GameState::GameOver,
+            (GameState::NameEntry, GameEvent::RunComplete) => GameState::Victory,
+
+            // From Respawning
+            (GameState::Respawning, GameEvent::RespawnComplete) => GameState::Playing,
+            (GameState::Respawning, GameEvent::BackToMenu) => GameState::Menu,
 
             // From Paused
             (GameState::Paused, GameEvent::PauseToggle) => GameState::Playing,
             (GameState::Paused, GameEvent::Reset) => GameState::Menu,
             (GameState::Paused, GameEvent::BackToMenu) => GameState::Menu,
+            (GameState::Paused, GameEvent::NewHighScore) => GameState::NameEntry,
+            (GameState::Paused, GameEvent::RunComplete) => GameState::Victory,
 
             // From GameOver
             (GameState::GameOver, GameEvent::Start) => GameState::Playing,
             (GameState::GameOver, GameEvent::Reset) => GameState::Menu,
             (GameState::GameOver, GameEvent::BackToMenu) => GameState::Menu,
 
+            // From Victory
+            (GameState::Victory, GameEvent::Start) => GameState::Playing,
+            (GameState::Victory, GameEvent::Reset) => GameState::Menu,
+            (GameState::Victory, GameEvent::BackToMenu) => GameState::Menu,
+
             // Invalid transitions remain in current state
             (state, _) => state,
         }
@@ -0,0 +1,195 @@
+use crate::cave::SimpleRng;
+use crate::constants::GrappleConstants;
+
+/// An anchor crystal embedded in a cave wall.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Anchor {
+    /// Centre position in world coordinates (x, y)
+    pub position: (f32, f32),
+    /// Whether the crystal sits on the ceiling (true) or floor (false)
+    pub on_ceiling: bool,
+}
+
+/// Manages spawning of anchor crystals along the cave.
+#[derive(Debug)]
+pub struct AnchorManager {
+    /// Anchors in the world
+    anchors: Vec<Anchor>,
+    /// Random number generator for spawn decisions
+    rng: SimpleRng,
+    /// X-coordinate where the next anchor may spawn
+    next_spawn_x: f32,
+}
+
+impl AnchorManager {
+    /// Creates a new anchor manager.
+    pub fn new(seed: u32) -> Self {
+        Self {
+            anchors: Vec::new(),
+            rng: SimpleRng::new(seed.wrapping_add(GrappleConstants::RNG_SEED_OFFSET)),
+            next_spawn_x: GrappleConstants::INITIAL_SPAWN_DELAY,
+        }
+    }
+
+    /// Checks if an anchor should spawn at the given x-coordinate.
+    ///
+    /// # Arguments
+    /// * `x` - Current x-coordinate to check
+    pub fn should_spawn_anchor(&mut self, x: f32) -> bool {
+        if x < self.next_spawn_x {
+            return false;
+        }
+
+        let average_distance = GrappleConstants::ANCHOR_SPAWN_DISTANCE;
+        let variation = average_distance * GrappleConstants::SPAWN_DISTANCE_VARIATION;
+        self.next_spawn_x = x + self.rng.range(average_distance - variation, average_distance + variation);
+        true
+    }
+
+    /// Spawns an anchor crystal on a randomly chosen wall.
+    ///
+    /// # Arguments
+    /// * `x` - X-coordinate for the anchor
+    /// * `ceiling_y` - Y-coordinate of the ceiling at this x position
+    /// * `floor_y` - Y-coordinate of the floor at this x position
+    pub fn spawn_anchor(&mut self, x: f32, ceiling_y: f32, floor_y: f32) {
+        let on_ceiling = self.rng.next_f32() < 0.5;
+        let half = GrappleConstants::ANCHOR_SIZE / 2.0;
+        let y = if on_ceiling { ceiling_y + half } else { floor_y - half };
+
+        self.anchors.push(Anchor {
+            position: (x, y),
+            on_ceiling,
+        });
+    }
+
+    /// Gets all anchors.
+    pub fn anchors(&self) -> &[Anchor] {
+        &self.anchors
+    }
+
+    /// Gets anchors in the specified x-range.
+    ///
+    /// # Arguments
+    /// * `x_min` - Minimum x-coordinate
+    /// * `x_max` - Maximum x-coordinate
+    pub fn get_anchors_in_range(&self, x_min: f32, x_max: f32) -> Vec<&Anchor> {
+        self.anchors
+            .iter()
+            .filter(|a| a.position.0 >= x_min && a.position.0 <= x_max)
+            .collect()
+    }
+
+    /// Removes anchors far behind the camera.
+    ///
+    /// # Arguments
+    /// * `camera_x` - Current camera x-position
+    pub fn cleanup_old_anchors(&mut self, camera_x: f32) {
+        self.anchors
+            .retain(|a| a.position.0 > camera_x - GrappleConstants::CLEANUP_DISTANCE);
+    }
+
+    /// Clears all anchors (used when transitioning to a new level).
+    ///
+    /// The next anchor spawns after the usual initial delay.
+    pub fn clear_all_anchors(&mut self) {
+        self.anchors.clear();
+        self.next_spawn_x = GrappleConstants::INITIAL_SPAWN_DELAY;
+    }
+}
+
+/// Result of one grapple update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GrappleStatus {
+    /// The rope is holding and reeling the ship in
+    Holding,
+    /// The ship reached the anchor and the rope let go
+    Arrived,
+    /// The rope was stretched too far and snapped
+    Broken,
+}
+
+/// Rope constraint between the ship and an anchor crystal.
+///
+/// The rope reels in over time and pulls the ship toward the anchor without
+/// using thrusters. It never lets the ship drift further out than its current
+/// length. Every pixel the ship gets dragged past that length strains the
+/// rope, and it snaps once the strain exceeds the break stretch.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Grapple {
+    /// Anchor position in world coordinates (x, y)
+    pub anchor: (f32, f32),
+    /// Current rope length in pixels
+    pub rope_length: f32,
+    /// Outward stretch the rope has absorbed and not yet recovered (pixels)
+    pub strain: f32,
+}
+
+impl Grapple {
+    /// Creates a rope from the ship to an anchor.
+    pub fn new(anchor: (f32, f32), player_pos: (f32, f32)) -> Self {
+        Self {
+            anchor,
+            rope_length: distance(player_pos, anchor),
+            strain: 0.0,
+        }
+    }
+
+    /// Reels the rope in and applies the constraint to the ship.
+    ///
+    /// The stretch since the last update is measured before the ship is pulled
+    /// back onto the rope; moving back toward the anchor relieves the strain.
+    ///
+    /// # Arguments
+    /// * `position` - Ship position (x, y), corrected onto the rope
+    /// * `velocity` - Ship velocity (x, y), updated by the pull
+    /// * `dt` - Delta time in seconds
+    pub fn update(&mut self, position: &mut (f32, f32), velocity: &mut (f32, f32), dt: f32) -> GrappleStatus {
+        let dx = self.anchor.0 - position.0;
+        let dy = self.anchor.1 - position.1;
+        let length = (dx * dx + dy * dy).sqrt();
+
+        self.strain = (self.strain + length - self.rope_length).max(0.0);
+        if self.strain > GrappleConstants::BREAK_STRETCH {
+            return GrappleStatus::Broken;
+        }
+        if length <= GrappleConstants::ARRIVAL_DISTANCE {
+            return GrappleStatus::Arrived;
+        }
+
+        let (dir_x, dir_y) = (dx / length, dy / length);
+        self.rope_length = (self.rope_length - GrappleConstants::REEL_SPEED * dt)
+            .max(GrappleConstants::ARRIVAL_DISTANCE);
+
+        // Pull toward the anchor
+        velocity.0 += dir_x * GrappleConstants::PULL_ACCELERATION * dt;
+        velocity.1 += dir_y * GrappleConstants::PULL_ACCELERATION * dt;
+
+        // Keep the ship on the rope and cancel velocity away from the anchor
+        if length > self.rope_length {
+            let excess = length - self.rope_length;
+            position.0 += dir_x * excess;
+            position.1 += dir_y * excess;
+
+            let inward_speed = velocity.0 * dir_x + velocity.1 * dir_y;
+            if inward_speed < 0.0 {
+                velocity.0 -= dir_x * inward_speed;
+                velocity.1 -= dir_y * inward_speed;
+            }
+        }
+
+        GrappleStatus::Holding
+    }
+
+    /// Gets the angle from the ship to the anchor in radians.
+    pub fn angle_from(&self, player_pos: (f32, f32)) -> f32 {
+        (self.anchor.1 - player_pos.1).atan2(self.anchor.0 - player_pos.0)
+    }
+}
+
+/// Gets the distance between two points.
+pub(crate) fn distance(a: (f32, f32), b: (f32, f32)) -> f32 {
+    let dx = b.0 - a.0;
+    let dy = b.1 - a.1;
+    (dx * dx + dy * dy).sqrt()
+}
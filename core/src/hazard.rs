@@ -0,0 +1,483 @@
+use crate::cave::SimpleRng;
+use crate::collision::{aabb_gap, aabb_overlap, Aabb};
+use crate::constants::HazardConstants;
+use crate::game_state::DeathCause;
+use crate::tractor::{BeamPolarity, TractorBeam};
+
+/// Types of moving hazards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HazardKind {
+    /// Proximity mine drifting through the gap
+    Mine,
+    /// Rock that detaches from the ceiling when the ship passes underneath
+    Rock,
+    /// Wall turret firing slow projectiles at the ship
+    Turret,
+}
+
+impl HazardKind {
+    /// Gets the square size of this hazard in pixels.
+    pub fn size(self) -> f32 {
+        match self {
+            HazardKind::Mine => HazardConstants::MINE_SIZE,
+            HazardKind::Rock => HazardConstants::ROCK_SIZE,
+            HazardKind::Turret => HazardConstants::TURRET_SIZE,
+        }
+    }
+}
+
+/// What hit the ship.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HazardHit {
+    Mine,
+    Rock,
+    Turret,
+    Projectile,
+}
+
+impl HazardHit {
+    /// Gets the death cause reported when this hit destroys the ship.
+    ///
+    /// Turrets are fixed to the wall and count as obstacles.
+    pub fn death_cause(self) -> DeathCause {
+        match self {
+            HazardHit::Turret => DeathCause::Obstacle,
+            _ => DeathCause::Hazard,
+        }
+    }
+}
+
+/// A hazard in the cave.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hazard {
+    /// Type of hazard
+    pub kind: HazardKind,
+    /// Centre position in world coordinates (x, y)
+    pub position: (f32, f32),
+    /// Velocity in pixels per second
+    pub velocity: (f32, f32),
+    /// Ceiling and floor of the cave where the hazard spawned
+    pub gap: (f32, f32),
+    /// Whether the hazard is mounted on (or hangs from) the ceiling
+    pub on_ceiling: bool,
+    /// Whether a rock is falling or a mine is chasing the ship
+    pub triggered: bool,
+    /// Whether the hazard has been destroyed
+    pub destroyed: bool,
+    fire_timer: f32,
+}
+
+impl Hazard {
+    /// Creates a new hazard at the specified centre position.
+    pub fn new(kind: HazardKind, position: (f32, f32), gap: (f32, f32), on_ceiling: bool) -> Self {
+        Self {
+            kind,
+            position,
+            velocity: (0.0, 0.0),
+            gap,
+            on_ceiling,
+            triggered: false,
+            destroyed: false,
+            fire_timer: HazardConstants::TURRET_FIRE_INTERVAL / 2.0,
+        }
+    }
+
+    /// Gets the bounding box of this hazard.
+    pub fn bounds(&self) -> Aabb {
+        let size = self.kind.size();
+        Aabb::new(
+            self.position.0 - size / 2.0,
+            self.position.1 - size / 2.0,
+            size,
+            size,
+        )
+    }
+
+    /// Keeps the hazard inside the gap it spawned in.
+    fn clamp_to_gap(&mut self) {
+        let half = self.kind.size() / 2.0;
+        let (min_y, max_y) = (self.gap.0 + half, self.gap.1 - half);
+
+        if self.position.1 < min_y {
+            self.position.1 = min_y;
+            self.velocity.1 = self.velocity.1.abs();
+        } else if self.position.1 > max_y {
+            self.position.1 = max_y;
+            self.velocity.1 = -self.velocity.1.abs();
+        }
+    }
+}
+
+/// A slow projectile fired by a turret.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Projectile {
+    /// Centre position in world coordinates (x, y)
+    pub position: (f32, f32),
+    /// Velocity in pixels per second
+    pub velocity: (f32, f32),
+    /// Time since the projectile was fired in seconds
+    pub age: f32,
+}
+
+impl Projectile {
+    /// Gets the bounding box of this projectile.
+    pub fn bounds(&self) -> Aabb {
+        let size = HazardConstants::PROJECTILE_SIZE;
+        Aabb::new(
+            self.position.0 - size / 2.0,
+            self.position.1 - size / 2.0,
+            size,
+            size,
+        )
+    }
+}
+
+/// Manages spawning, movement and collision of hazards.
+#[derive(Debug)]
+pub struct HazardManager {
+    /// Active hazards in the world
+    hazards: Vec<Hazard>,
+    /// Projectiles in flight
+    projectiles: Vec<Projectile>,
+    /// Random number generator for spawn decisions
+    rng: SimpleRng,
+    /// Average hazards per 1000 pixels; 0 disables spawning
+    density: f32,
+    /// X-coordinate where the next hazard may spawn
+    next_spawn_x: f32,
+}
+
+impl HazardManager {
+    /// Creates a new hazard manager with spawning disabled.
+    pub fn new(seed: u32) -> Self {
+        Self {
+            hazards: Vec::new(),
+            projectiles: Vec::new(),
+            rng: SimpleRng::new(seed.wrapping_add(HazardConstants::RNG_SEED_OFFSET)),
+            density: 0.0,
+            next_spawn_x: HazardConstants::INITIAL_SPAWN_DELAY,
+        }
+    }
+
+    /// Sets the hazard density from the level configuration.
+    ///
+    /// # Arguments
+    /// * `density` - Average hazards per 1000 pixels; 0 disables spawning
+    pub fn set_density(&mut self, density: f32) {
+        self.density = density.max(0.0);
+    }
+
+    /// Gets the current hazard density.
+    pub fn density(&self) -> f32 {
+        self.density
+    }
+
+    /// Checks if a hazard should spawn at the given x-coordinate.
+    ///
+    /// # Arguments
+    /// * `x` - Current x-coordinate to check
+    pub fn should_spawn_hazard(&mut self, x: f32) -> bool {
+        if self.density <= 0.0 || x < self.next_spawn_x {
+            return false;
+        }
+
+        let average_distance = 1000.0 / self.density;
+        let variation = average_distance * HazardConstants::SPAWN_DISTANCE_VARIATION;
+        self.next_spawn_x = x + self.rng.range(average_distance - variation, average_distance + variation);
+        true
+    }
+
+    /// Spawns a hazard of random type.
+    ///
+    /// # Arguments
+    /// * `x` - X-coordinate for the hazard
+    /// * `ceiling_y` - Y-coordinate of the ceiling at this x position
+    /// * `floor_y` - Y-coordinate of the floor at this x position
+    pub fn spawn_random_hazard(&mut self, x: f32, ceiling_y: f32, floor_y: f32) {
+        let roll = self.rng.next_f32();
+        let kind = if roll < 0.4 {
+            HazardKind::Mine
+        } else if roll < 0.75 {
+            HazardKind::Rock
+        } else {
+            HazardKind::Turret
+        };
+        self.spawn_hazard(x, ceiling_y, floor_y, kind);
+    }
+
+    /// Spawns a hazard of the given type.
+    ///
+    /// Mines are placed inside the gap, rocks hang from the ceiling and
+    /// turrets sit on a randomly chosen wall.
+    ///
+    /// # Arguments
+    /// * `x` - X-coordinate for the hazard
+    /// * `ceiling_y` - Y-coordinate of the ceiling at this x position
+    /// * `floor_y` - Y-coordinate of the floor at this x position
+    /// * `kind` - Type of hazard to spawn
+    pub fn spawn_hazard(&mut self, x: f32, ceiling_y: f32, floor_y: f32, kind: HazardKind) {
+        let half = kind.size() / 2.0;
+        let gap = (ceiling_y, floor_y);
+
+        let hazard = match kind {
+            HazardKind::Mine => {
+                let y = self.rng.range(ceiling_y + half, floor_y - half);
+                let mut mine = Hazard::new(kind, (x, y), gap, false);
+                let drift = if self.rng.next_f32() < 0.5 { -1.0 } else { 1.0 };
+                mine.velocity = (0.0, drift * HazardConstants::MINE_DRIFT_SPEED);
+                mine
+            }
+            HazardKind::Rock => Hazard::new(kind, (x, ceiling_y + half), gap, true),
+            HazardKind::Turret => {
+                let on_ceiling = self.rng.next_f32() < 0.5;
+                let y = if on_ceiling { ceiling_y + half } else { floor_y - half };
+                Hazard::new(kind, (x, y), gap, on_ceiling)
+            }
+        };
+
+        self.hazards.push(hazard);
+    }
+
+    /// Updates hazard movement, triggers and turret fire.
+    ///
+    /// # Arguments
+    /// * `dt` - Delta time in seconds
+    /// * `player_pos` - Player centre position
+    pub fn update(&mut self, dt: f32, player_pos: (f32, f32)) {
+        let mut shots = Vec::new();
+
+        for hazard in self.hazards.iter_mut().filter(|h| !h.destroyed) {
+            match hazard.kind {
+                HazardKind::Mine => Self::update_mine(hazard, player_pos, dt),
+                HazardKind::Rock => Self::update_rock(hazard, player_pos, dt),
+                HazardKind::Turret => {
+                    if let Some(shot) = Self::update_turret(hazard, player_pos, dt) {
+                        shots.push(shot);
+                    }
+                }
+            }
+        }
+
+        for projectile in &mut self.projectiles {
+            projectile.position.0 += projectile.velocity.0 * dt;
+            projectile.position.1 += projectile.velocity.1 * dt;
+            projectile.age += dt;
+        }
+
+        self.projectiles.extend(shots);
+        self.projectiles
+            .retain(|p| p.age < HazardConstants::PROJECTILE_LIFETIME);
+    }
+
+    /// Drifts a mine, steering toward the ship when it comes close.
+    fn update_mine(mine: &mut Hazard, player_pos: (f32, f32), dt: f32) {
+        let dx = player_pos.0 - mine.position.0;
+        let dy = player_pos.1 - mine.position.1;
+        let distance = (dx * dx + dy * dy).sqrt();
+
+        if distance > 0.0 && distance <= HazardConstants::MINE_PROXIMITY_RADIUS {
+            mine.triggered = true;
+            mine.velocity = (
+                dx / distance * HazardConstants::MINE_CHASE_SPEED,
+                dy / distance * HazardConstants::MINE_CHASE_SPEED,
+            );
+        }
+
+        mine.position.0 += mine.velocity.0 * dt;
+        mine.position.1 += mine.velocity.1 * dt;
+        mine.clamp_to_gap();
+    }
+
+    /// Drops a rock once the ship is about to pass underneath.
+    fn update_rock(rock: &mut Hazard, player_pos: (f32, f32), dt: f32) {
+        let ahead = rock.position.0 - player_pos.0;
+        let beneath = player_pos.1 > rock.position.1;
+
+        if !rock.triggered && beneath && ahead <= HazardConstants::ROCK_TRIGGER_DISTANCE {
+            rock.triggered = true;
+        }
+
+        if rock.triggered {
+            rock.velocity.1 += HazardConstants::ROCK_GRAVITY * dt;
+            rock.position.1 += rock.velocity.1 * dt;
+
+            // Rocks shatter on the floor
+            if rock.position.1 + rock.kind.size() / 2.0 >= rock.gap.1 {
+                rock.destroyed = true;
+            }
+        }
+    }
+
+    /// Fires a projectile at the ship when it is in range and the turret has reloaded.
+    fn update_turret(turret: &mut Hazard, player_pos: (f32, f32), dt: f32) -> Option<Projectile> {
+        turret.fire_timer = (turret.fire_timer - dt).max(0.0);
+
+        let dx = player_pos.0 - turret.position.0;
+        let dy = player_pos.1 - turret.position.1;
+        let distance = (dx * dx + dy * dy).sqrt();
+
+        if turret.fire_timer > 0.0 || distance <= 0.0 || distance > HazardConstants::TURRET_RANGE {
+            return None;
+        }
+
+        turret.fire_timer = HazardConstants::TURRET_FIRE_INTERVAL;
+        Some(Projectile {
+            position: turret.position,
+            velocity: (
+                dx / distance * HazardConstants::PROJECTILE_SPEED,
+                dy / distance * HazardConstants::PROJECTILE_SPEED,
+            ),
+            age: 0.0,
+        })
+    }
+
+    /// Moves mines and projectiles caught in the tractor beam.
+    ///
+    /// Mines follow the beam polarity: pulled toward the ship or pushed away.
+    /// A pushing beam also deflects projectiles back the way they came.
+    ///
+    /// # Arguments
+    /// * `tractor_beam` - The tractor beam system
+    /// * `player_pos` - Current player position
+    /// * `dt` - Delta time for frame-rate independent movement
+    pub fn update_tractor_beam(&mut self, tractor_beam: &TractorBeam, player_pos: (f32, f32), dt: f32) {
+        if !tractor_beam.is_active() {
+            return;
+        }
+
+        for mine in self
+            .hazards
+            .iter_mut()
+            .filter(|h| !h.destroyed && h.kind == HazardKind::Mine)
+        {
+            let force = tractor_beam.get_beam_force(player_pos, mine.position);
+            if force != (0.0, 0.0) {
+                let base_speed = match tractor_beam.polarity {
+                    BeamPolarity::Pull => HazardConstants::MINE_PULL_SPEED,
+                    BeamPolarity::Push => HazardConstants::MINE_PUSH_SPEED,
+                };
+                let speed = base_speed * tractor_beam.strength_at(player_pos, mine.position);
+                mine.position.0 += force.0 * speed * dt;
+                mine.position.1 += force.1 * speed * dt;
+                mine.triggered = false;
+                mine.velocity = (0.0, force.1 * HazardConstants::MINE_DRIFT_SPEED);
+                mine.clamp_to_gap();
+            }
+        }
+
+        if tractor_beam.polarity == BeamPolarity::Push {
+            for projectile in &mut self.projectiles {
+                let force = tractor_beam.get_beam_force(player_pos, projectile.position);
+                if force != (0.0, 0.0) {
+                    projectile.velocity = (
+                        force.0 * HazardConstants::PROJECTILE_SPEED,
+                        force.1 * HazardConstants::PROJECTILE_SPEED,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Checks for collision between the player and hazards.
+    ///
+    /// Mines, rocks and projectiles that hit the ship are destroyed;
+    /// turrets are fixed to the wall and remain.
+    ///
+    /// # Arguments
+    /// * `player_pos` - Player position (x, y)
+    /// * `player_size` - Player size (width, height)
+    pub fn check_collision(&mut self, player_pos: (f32, f32), player_size: (f32, f32)) -> Option<HazardHit> {
+        let overlaps = |bounds: Aabb| {
+            aabb_overlap(player_pos, player_size, (bounds.x, bounds.y), (bounds.width, bounds.height))
+        };
+
+        if let Some(index) = self.projectiles.iter().position(|p| overlaps(p.bounds())) {
+            self.projectiles.remove(index);
+            return Some(HazardHit::Projectile);
+        }
+
+        let hazard = self
+            .hazards
+            .iter_mut()
+            .find(|h| !h.destroyed && overlaps(h.bounds()))?;
+
+        match hazard.kind {
+            HazardKind::Mine => {
+                hazard.destroyed = true;
+                Some(HazardHit::Mine)
+            }
+            HazardKind::Rock => {
+                hazard.destroyed = true;
+                Some(HazardHit::Rock)
+            }
+            HazardKind::Turret => Some(HazardHit::Turret),
+        }
+    }
+
+    /// Gets all active hazards in the specified x-range.
+    ///
+    /// # Arguments
+    /// * `x_min` - Minimum x-coordinate
+    /// * `x_max` - Maximum x-coordinate
+    pub fn get_hazards_in_range(&self, x_min: f32, x_max: f32) -> Vec<&Hazard> {
+        self.hazards
+            .iter()
+            .filter(|h| !h.destroyed && h.position.0 >= x_min && h.position.0 <= x_max)
+            .collect()
+    }
+
+    /// Gets the distance from a box to the nearest active hazard or projectile.
+    ///
+    /// Returns `None` when there is nothing to measure against.
+    ///
+    /// # Arguments
+    /// * `bounds` - Box to measure from (usually the ship)
+    pub fn nearest_clearance(&self, bounds: &Aabb) -> Option<f32> {
+        let hazards = self.hazards.iter().filter(|h| !h.destroyed).map(|h| h.bounds());
+        let projectiles = self.projectiles.iter().map(|p| p.bounds());
+
+        hazards
+            .chain(projectiles)
+            .map(|other| aabb_gap(bounds, &other))
+            .min_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+    }
+
+    /// Gets all active hazards.
+    pub fn hazards(&self) -> &[Hazard] {
+        &self.hazards
+    }
+
+    /// Gets all projectiles in flight.
+    pub fn projectiles(&self) -> &[Projectile] {
+        &self.projectiles
+    }
+
+    /// Gets the number of active hazards (for debugging).
+    pub fn active_hazard_count(&self) -> usize {
+        self.hazards.iter().filter(|h| !h.destroyed).count()
+    }
+
+    /// Removes destroyed hazards and hazards far behind the camera.
+    ///
+    /// # Arguments
+    /// * `camera_x` - Current camera x-position
+    pub fn cleanup_old_hazards(&mut self, camera_x: f32) {
+        let min_x = camera_x - HazardConstants::CLEANUP_DISTANCE;
+        self.hazards.retain(|h| !h.destroyed && h.position.0 > min_x);
+        self.projectiles.retain(|p| p.position.0 > min_x);
+    }
+
+    /// Prevents hazards from spawning before the given x-coordinate.
+    ///
+    /// Used after a level change so hazards do not appear inside the view.
+    pub fn hold_spawns_until(&mut self, x: f32) {
+        self.next_spawn_x = self.next_spawn_x.max(x);
+    }
+
+    /// Clears all hazards (used when transitioning to a new level).
+    pub fn clear_all_hazards(&mut self) {
+        self.hazards.clear();
+        self.projectiles.clear();
+        self.next_spawn_x = HazardConstants::INITIAL_SPAWN_DELAY;
+    }
+}
@@ -0,0 +1,244 @@
+use crate::constants::HighScoreConstants;
+use crate::difficulty::Difficulty;
+use crate::game_state::DeathCause;
+use crate::run::RunResult;
+use crate::storage::{Storage, StorageError, StorageResult};
+
+/// Outcome written for runs that were completed rather than lost
+const FINISHED: &str = "finished";
+
+/// One finished run in the high-score table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HighScoreEntry {
+    /// Name entered by the player
+    pub name: String,
+    /// Final score
+    pub score: u32,
+    /// Distance covered in pixels
+    pub distance: u32,
+    /// Level reached (1-based)
+    pub level_number: u32,
+    /// Cave seed the run was played on
+    pub seed: u32,
+    /// When the run ended, in seconds since the Unix epoch
+    pub date: u64,
+    /// What destroyed the final ship, or `None` if the run was completed
+    pub death_cause: Option<DeathCause>,
+    /// Run time in seconds
+    pub elapsed_time: f32,
+    /// Difficulty preset the run was played on
+    pub difficulty: Difficulty,
+}
+
+impl HighScoreEntry {
+    /// Creates an entry from a finished run.
+    ///
+    /// # Arguments
+    /// * `run` - Result of the run
+    /// * `seed` - Cave seed the run was played on
+    /// * `date` - Seconds since the Unix epoch
+    pub fn from_run(run: &RunResult, seed: u32, date: u64) -> Self {
+        Self {
+            name: String::new(),
+            score: run.score.total(),
+            distance: run.distance_as_int(),
+            level_number: run.level_number,
+            seed,
+            date,
+            death_cause: run.death_cause,
+            elapsed_time: run.elapsed_time,
+            difficulty: Difficulty::default(),
+        }
+    }
+
+    /// Attaches the difficulty preset the run was played on.
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> Self {
+        self.difficulty = difficulty;
+        self
+    }
+
+    /// Attaches the player's name.
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Gets how the run ended for display.
+    pub fn outcome(&self) -> String {
+        self.death_cause.map_or(FINISHED.to_string(), |cause| cause.to_string())
+    }
+
+    /// Gets the date as YYYY-MM-DD (UTC).
+    pub fn date_formatted(&self) -> String {
+        let (year, month, day) = civil_from_days((self.date / 86_400) as i64);
+        format!("{:04}-{:02}-{:02}", year, month, day)
+    }
+
+    /// Writes the entry as one comma-separated line.
+    ///
+    /// The name goes last; commas in it are dropped.
+    fn format_line(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{:.2},{},{}",
+            self.score,
+            self.distance,
+            self.level_number,
+            self.seed,
+            self.date,
+            self.outcome(),
+            self.elapsed_time,
+            self.difficulty,
+            self.name.replace(',', "")
+        )
+    }
+
+    /// Reads an entry written by `format_line`.
+    fn parse_line(line: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = line.split(',').collect();
+        let [score, distance, level_number, seed, date, cause, time, difficulty, name] = fields[..] else {
+            return Err(format!("expected 9 fields, found {}", fields.len()));
+        };
+
+        let number = |field: &str| field.parse::<u32>().map_err(|e| format!("'{}': {}", field, e));
+        let death_cause = match cause {
+            FINISHED => None,
+            _ => Some(DeathCause::from_name(cause).ok_or_else(|| format!("unknown death cause '{}'", cause))?),
+        };
+        let elapsed_time = time.parse::<f32>().map_err(|e| format!("'{}': {}", time, e))?;
+        let difficulty = Difficulty::from_name(difficulty)
+            .ok_or_else(|| format!("unknown difficulty '{}'", difficulty))?;
+        Ok(Self {
+            name: name.to_string(),
+            score: number(score)?,
+            distance: number(distance)?,
+            level_number: number(level_number)?,
+            seed: number(seed)?,
+            date: date.parse::<u64>().map_err(|e| format!("'{}': {}", date, e))?,
+            death_cause,
+            elapsed_time,
+            difficulty,
+        })
+    }
+}
+
+/// Converts days since the Unix epoch to a (year, month, day) date.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Best runs, highest score first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HighScoreTable {
+    entries: Vec<HighScoreEntry>,
+    capacity: usize,
+}
+
+impl Default for HighScoreTable {
+    fn default() -> Self {
+        Self::new(HighScoreConstants::TABLE_SIZE)
+    }
+}
+
+impl HighScoreTable {
+    /// Creates an empty table keeping at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: Vec::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Checks if a score would make it into the table.
+    pub fn qualifies(&self, score: u32) -> bool {
+        self.capacity > 0
+            && (self.entries.len() < self.capacity
+                || self.entries.last().is_some_and(|lowest| score > lowest.score))
+    }
+
+    /// Inserts an entry if it qualifies.
+    ///
+    /// Returns the entry's 0-based rank, or `None` if it did not make the
+    /// table. Ties rank below the runs already in the table.
+    pub fn insert(&mut self, entry: HighScoreEntry) -> Option<usize> {
+        if !self.qualifies(entry.score) {
+            return None;
+        }
+
+        let rank = self.entries.partition_point(|existing| existing.score >= entry.score);
+        self.entries.insert(rank, entry);
+        self.entries.truncate(self.capacity);
+        Some(rank)
+    }
+
+    /// Gets the entries, highest score first.
+    pub fn entries(&self) -> &[HighScoreEntry] {
+        &self.entries
+    }
+
+    /// Gets the best entry.
+    pub fn best(&self) -> Option<&HighScoreEntry> {
+        self.entries.first()
+    }
+
+    /// Writes the table as text, one entry per line.
+    pub fn to_text(&self) -> String {
+        self.entries
+            .iter()
+            .map(|entry| entry.format_line() + "\n")
+            .collect()
+    }
+
+    /// Reads a table written by `to_text`.
+    ///
+    /// Entries are re-sorted and trimmed to `capacity`.
+    pub fn from_text(text: &str, capacity: usize) -> StorageResult<Self> {
+        Self::parse_text(text, capacity, HighScoreConstants::STORAGE_KEY)
+    }
+
+    /// Reads a table, naming `key` in errors.
+    fn parse_text(text: &str, capacity: usize, key: &str) -> StorageResult<Self> {
+        let mut table = Self::new(capacity);
+        for (index, line) in text.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let entry = HighScoreEntry::parse_line(line.trim()).map_err(|reason| StorageError::Corrupt {
+                key: key.to_string(),
+                reason: format!("line {}: {}", index + 1, reason),
+            })?;
+            table.insert(entry);
+        }
+        Ok(table)
+    }
+
+    /// Loads the table from storage, or an empty table if none was saved.
+    pub fn load(storage: &dyn Storage) -> StorageResult<Self> {
+        Self::load_from(storage, HighScoreConstants::STORAGE_KEY)
+    }
+
+    /// Saves the table to storage.
+    pub fn save(&self, storage: &mut dyn Storage) -> StorageResult<()> {
+        self.save_to(storage, HighScoreConstants::STORAGE_KEY)
+    }
+
+    /// Loads the table stored under `key`, or an empty table if none was saved.
+    ///
+    /// Each game mode keeps its own table under its own key.
+    pub fn load_from(storage: &dyn Storage, key: &str) -> StorageResult<Self> {
+        match storage.load(key)? {
+            Some(text) => Self::parse_text(&text, HighScoreConstants::TABLE_SIZE, key),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Saves the table under `key`.
+    pub fn save_to(&self, storage: &mut dyn Storage, key: &str) -> StorageResult<()> {
+        storage.save(key, &self.to_text())
+    }
+}
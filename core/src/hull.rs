@@ -0,0 +1,128 @@
+use crate::constants::HullConstants;
+
+/// How wall contact affects the ship.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DamageModel {
+    /// Any wall contact destroys the ship
+    #[default]
+    InstantDeath,
+    /// Wall contact costs hull points and bounces the ship
+    Hull,
+}
+
+/// Result of an impact against the hull.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImpactOutcome {
+    /// Hit ignored because the ship is invulnerable
+    Ignored,
+    /// Hit absorbed by a shield charge
+    Absorbed,
+    /// Hull points lost, ship still flying
+    Damaged(f32),
+    /// Hull reached zero
+    Destroyed,
+}
+
+/// Ship hull with hit points, shield charges and post-hit invulnerability.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hull {
+    pub points: f32,
+    pub max: f32,
+    pub shield_charges: u32,
+    invulnerability_timer: f32,
+}
+
+impl Hull {
+    /// Creates an undamaged hull with the given maximum points.
+    pub fn new(max: f32) -> Self {
+        Self {
+            points: max,
+            max,
+            shield_charges: 0,
+            invulnerability_timer: 0.0,
+        }
+    }
+
+    /// Applies an impact at the given speed.
+    ///
+    /// Damage scales with impact speed. Shields absorb a hit entirely, and
+    /// any registered hit grants a short invulnerability window.
+    ///
+    /// # Arguments
+    /// * `impact_speed` - Speed toward the wall at contact (pixels/sec)
+    pub fn take_impact(&mut self, impact_speed: f32) -> ImpactOutcome {
+        if self.is_destroyed() {
+            return ImpactOutcome::Destroyed;
+        }
+
+        if self.is_invulnerable() {
+            return ImpactOutcome::Ignored;
+        }
+
+        self.invulnerability_timer = HullConstants::INVULNERABILITY_DURATION;
+
+        if self.shield_charges > 0 {
+            self.shield_charges -= 1;
+            return ImpactOutcome::Absorbed;
+        }
+
+        let damage = Self::damage_for_speed(impact_speed);
+        self.points = (self.points - damage).max(0.0);
+
+        if self.is_destroyed() {
+            ImpactOutcome::Destroyed
+        } else {
+            ImpactOutcome::Damaged(damage)
+        }
+    }
+
+    /// Calculates hull damage for an impact speed.
+    pub fn damage_for_speed(impact_speed: f32) -> f32 {
+        (impact_speed.abs() * HullConstants::DAMAGE_PER_IMPACT_SPEED).max(HullConstants::MIN_DAMAGE)
+    }
+
+    /// Updates the invulnerability timer.
+    pub fn tick(&mut self, dt: f32) {
+        self.invulnerability_timer = (self.invulnerability_timer - dt).max(0.0);
+    }
+
+    /// Grants temporary invulnerability (e.g. after a respawn).
+    pub fn grant_invulnerability(&mut self, duration: f32) {
+        self.invulnerability_timer = self.invulnerability_timer.max(duration);
+    }
+
+    /// Adds a shield charge, capped at the maximum.
+    pub fn add_shield(&mut self) {
+        self.shield_charges = (self.shield_charges + 1).min(HullConstants::MAX_SHIELD_CHARGES);
+    }
+
+    /// Checks if hits are currently ignored.
+    pub fn is_invulnerable(&self) -> bool {
+        self.invulnerability_timer > 0.0
+    }
+
+    /// Gets remaining invulnerability time in seconds.
+    pub fn invulnerability_remaining(&self) -> f32 {
+        self.invulnerability_timer
+    }
+
+    /// Checks if the hull has no points left.
+    pub fn is_destroyed(&self) -> bool {
+        self.points <= 0.0
+    }
+
+    /// Gets the hull ratio (points/max) for UI display.
+    pub fn ratio(&self) -> f32 {
+        if self.max <= 0.0 {
+            0.0
+        } else {
+            self.points / self.max
+        }
+    }
+}
+
+impl Default for Hull {
+    fn default() -> Self {
+        Self::new(HullConstants::MAX_POINTS)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::cave::CaveSegment;
+use crate::collision::Contact;
+use crate::constants::LandingPadConstants;
+
+/// How the ship met a cave wall.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Touchdown {
+    /// Soft contact with a landing pad; the ship settles instead of crashing
+    Landed,
+    /// Any other wall contact
+    Crashed,
+}
+
+impl Touchdown {
+    /// Classifies a wall contact as a landing or a crash.
+    ///
+    /// Only the top of a landing pad, touched below the landing speed, is a
+    /// landing.
+    ///
+    /// # Arguments
+    /// * `contact` - Contact between ship and wall
+    /// * `segment` - Cave segment the wall belongs to
+    /// * `velocity` - Ship velocity (x, y) in pixels per second
+    pub fn classify(contact: &Contact, segment: &CaveSegment, velocity: (f32, f32)) -> Self {
+        let on_pad_top = segment.landing_pad && contact.normal == (0.0, -1.0);
+
+        if on_pad_top && is_landing_speed(velocity) {
+            Touchdown::Landed
+        } else {
+            Touchdown::Crashed
+        }
+    }
+}
+
+/// Checks if the ship is resting on a landing pad.
+///
+/// The ship must be over a pad, level with its surface and slower than the
+/// landing speed.
+///
+/// # Arguments
+/// * `segment` - Cave segment below the ship
+/// * `ship_bottom` - Y-coordinate of the ship's underside
+/// * `velocity` - Ship velocity (x, y) in pixels per second
+pub fn is_resting_on_pad(segment: &CaveSegment, ship_bottom: f32, velocity: (f32, f32)) -> bool {
+    let gap = segment.floor - ship_bottom;
+
+    segment.landing_pad
+        && (0.0..=LandingPadConstants::REST_TOLERANCE).contains(&gap)
+        && is_landing_speed(velocity)
+}
+
+/// Checks if a velocity is slow enough to land.
+fn is_landing_speed(velocity: (f32, f32)) -> bool {
+    let speed = (velocity.0 * velocity.0 + velocity.1 * velocity.1).sqrt();
+    speed <= LandingPadConstants::MAX_LANDING_SPEED
+}
@@ -2,6 +2,8 @@
 /// 
 /// Manages level progression and difficulty parameters
 
+use crate::constants::GameModeConstants;
+
 /// Errors that can occur in the level system
 #[derive(Debug, Clone, PartialEq)]
 pub enum LevelError {
@@ -40,6 +42,12 @@ pub struct Level {
     pub fuel_spawn_distance: f32,
     /// Width of the cave passage in pixels
     pub cave_width: f32,
+    /// Average number of hazards per 1000 pixels
+    pub hazard_density: f32,
+    /// Cargo pods to deliver in cargo mode
+    pub cargo_goal: u32,
+    /// Fuel restored per second while resting on a landing pad
+    pub refuel_rate: f32,
 }
 
 impl Level {
@@ -50,6 +58,43 @@ impl Level {
             duration_seconds,
             fuel_spawn_distance,
             cave_width,
+            hazard_density: 0.0,
+            cargo_goal: 0,
+            refuel_rate: 0.0,
+        }
+    }
+
+    /// Sets the average number of hazards per 1000 pixels
+    pub fn with_hazard_density(mut self, hazard_density: f32) -> Self {
+        self.hazard_density = hazard_density;
+        self
+    }
+
+    /// Sets the number of cargo pods to deliver in cargo mode
+    pub fn with_cargo_goal(mut self, cargo_goal: u32) -> Self {
+        self.cargo_goal = cargo_goal;
+        self
+    }
+
+    /// Sets the fuel restored per second on a landing pad
+    pub fn with_refuel_rate(mut self, refuel_rate: f32) -> Self {
+        self.refuel_rate = refuel_rate;
+        self
+    }
+
+    /// Creates the next, harder level for endless play
+    ///
+    /// Hazards get denser, depots sparser and pads slower, up to fixed limits.
+    pub fn escalated(&self) -> Self {
+        Self {
+            number: self.number + 1,
+            hazard_density: (self.hazard_density + GameModeConstants::ENDLESS_HAZARD_STEP)
+                .min(GameModeConstants::ENDLESS_MAX_HAZARD_DENSITY),
+            fuel_spawn_distance: (self.fuel_spawn_distance + GameModeConstants::ENDLESS_FUEL_SPACING_STEP)
+                .min(GameModeConstants::ENDLESS_MAX_FUEL_SPACING),
+            refuel_rate: (self.refuel_rate * GameModeConstants::ENDLESS_REFUEL_RATE_FACTOR)
+                .max(GameModeConstants::ENDLESS_MIN_REFUEL_RATE),
+            ..self.clone()
         }
     }
 }
@@ -59,6 +104,9 @@ pub struct LevelManager {
     levels: Vec<Level>,
     current_level_index: usize,
     level_start_time: f32,
+    /// Number of configured levels; endless scaling appends more
+    configured_levels: usize,
+    endless: bool,
 }
 
 impl LevelManager {
@@ -66,23 +114,25 @@ impl LevelManager {
     pub fn new() -> Self {
         let levels = vec![
             // Level 1: Easy introduction
-            Level::new(1, 60.0, 300.0, 200.0),
+            Level::new(1, 60.0, 300.0, 200.0).with_hazard_density(0.3).with_cargo_goal(1).with_refuel_rate(15.0),
             // Level 2: Slightly harder
-            Level::new(2, 90.0, 400.0, 180.0),
+            Level::new(2, 90.0, 400.0, 180.0).with_hazard_density(0.6).with_cargo_goal(2).with_refuel_rate(12.0),
             // Level 3: Medium difficulty
-            Level::new(3, 120.0, 500.0, 160.0),
+            Level::new(3, 120.0, 500.0, 160.0).with_hazard_density(0.9).with_cargo_goal(2).with_refuel_rate(10.0),
             // Level 4: Getting challenging
-            Level::new(4, 120.0, 600.0, 140.0),
+            Level::new(4, 120.0, 600.0, 140.0).with_hazard_density(1.2).with_cargo_goal(3).with_refuel_rate(8.0),
             // Level 5: Hard
-            Level::new(5, 150.0, 700.0, 120.0),
+            Level::new(5, 150.0, 700.0, 120.0).with_hazard_density(1.5).with_cargo_goal(3).with_refuel_rate(6.0),
             // Level 6+: Very hard (repeats)
-            Level::new(6, 180.0, 800.0, 100.0),
+            Level::new(6, 180.0, 800.0, 100.0).with_hazard_density(1.8).with_cargo_goal(4).with_refuel_rate(5.0),
         ];
 
         Self {
+            configured_levels: levels.len(),
             levels,
             current_level_index: 0,
             level_start_time: 0.0,
+            endless: false,
         }
     }
 
@@ -92,18 +142,36 @@ impl LevelManager {
             return Err(LevelError::EmptyLevelList);
         }
         Ok(Self {
+            configured_levels: levels.len(),
             levels,
             current_level_index: 0,
             level_start_time: 0.0,
+            endless: false,
         })
     }
 
+    /// Keeps escalating past the last level instead of repeating it
+    pub fn with_endless_scaling(mut self) -> Self {
+        self.endless = true;
+        self
+    }
+
+    /// Checks if the current level is the last configured one
+    pub fn is_last_level(&self) -> bool {
+        self.current_level_index + 1 >= self.configured_levels
+    }
+
     /// Gets the current level
     pub fn current_level(&self) -> LevelResult<&Level> {
         self.levels.get(self.current_level_index)
             .ok_or(LevelError::InvalidLevelIndex(self.current_level_index))
     }
 
+    /// Gets all configured levels
+    pub fn levels(&self) -> &[Level] {
+        &self.levels
+    }
+
     /// Gets the current level number (1-based)
     pub fn current_level_number(&self) -> u32 {
         self.current_level_index as u32 + 1
@@ -124,6 +192,10 @@ impl LevelManager {
 
     /// Advances to the next level
     fn advance_level(&mut self, current_time: f32) -> bool {
+        if self.endless && self.current_level_index == self.levels.len() - 1 {
+            let next = self.levels[self.current_level_index].escalated();
+            self.levels.push(next);
+        }
         if self.current_level_index < self.levels.len() - 1 {
             self.current_level_index += 1;
         }
@@ -139,8 +211,29 @@ impl LevelManager {
         Ok((elapsed / current_level.duration_seconds).min(1.0))
     }
 
+    /// Gets the elapsed time at which the current level started
+    pub fn level_start_time(&self) -> f32 {
+        self.level_start_time
+    }
+
+    /// Rewinds to a level reached earlier, as when respawning at a checkpoint
+    ///
+    /// Returns true if the current level changed.
+    pub fn rewind_to(&mut self, level_number: u32, level_start_time: f32) -> LevelResult<bool> {
+        let index = level_number.saturating_sub(1) as usize;
+        if level_number == 0 || index >= self.levels.len() {
+            return Err(LevelError::InvalidLevelIndex(index));
+        }
+
+        let changed = index != self.current_level_index;
+        self.current_level_index = index;
+        self.level_start_time = level_start_time;
+        Ok(changed)
+    }
+
     /// Resets the level manager to the first level
     pub fn reset(&mut self) {
+        self.levels.truncate(self.configured_levels);
         self.current_level_index = 0;
         self.level_start_time = 0.0;
     }
@@ -165,6 +258,35 @@ mod tests {
         assert_eq!(level.cave_width, 200.0);
     }
 
+    #[test]
+    fn test_hazard_density_increases_with_level() {
+        let manager = LevelManager::new();
+        assert_eq!(Level::new(1, 60.0, 300.0, 200.0).hazard_density, 0.0);
+
+        let densities: Vec<f32> = manager.levels().iter().map(|l| l.hazard_density).collect();
+        assert!(densities.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_refuel_rate_decreases_with_level() {
+        let manager = LevelManager::new();
+        assert_eq!(Level::new(1, 60.0, 300.0, 200.0).refuel_rate, 0.0);
+
+        let rates: Vec<f32> = manager.levels().iter().map(|l| l.refuel_rate).collect();
+        assert!(rates.iter().all(|&rate| rate > 0.0));
+        assert!(rates.windows(2).all(|pair| pair[0] > pair[1]));
+    }
+
+    #[test]
+    fn test_cargo_goal_never_decreases() {
+        let manager = LevelManager::new();
+        assert_eq!(Level::new(1, 60.0, 300.0, 200.0).cargo_goal, 0);
+
+        let goals: Vec<u32> = manager.levels().iter().map(|l| l.cargo_goal).collect();
+        assert!(goals.iter().all(|&goal| goal > 0));
+        assert!(goals.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
     #[test]
     fn test_level_manager_creation() {
         let manager = LevelManager::new();
@@ -216,6 +338,41 @@ mod tests {
         assert_eq!(manager.current_level_index, 1);
     }
 
+    #[test]
+    fn test_endless_scaling() {
+        let mut manager = LevelManager::with_levels(vec![
+            Level::new(1, 10.0, 100.0, 200.0).with_hazard_density(1.0).with_refuel_rate(10.0),
+        ]).unwrap().with_endless_scaling();
+        assert!(manager.is_last_level());
+
+        // Past the last level, each new level is harder than the one before
+        assert!(manager.update(11.0).unwrap());
+        assert!(manager.update(22.0).unwrap());
+        let level = manager.current_level().unwrap();
+        assert_eq!(level.number, 3);
+        assert_eq!(manager.current_level_number(), 3);
+        assert!(level.hazard_density > 1.0);
+        assert!(level.fuel_spawn_distance > 100.0);
+        assert!(level.refuel_rate < 10.0);
+
+        // Resetting drops the generated levels
+        manager.reset();
+        assert_eq!(manager.levels().len(), 1);
+    }
+
+    #[test]
+    fn test_escalation_limits() {
+        let mut level = Level::new(6, 180.0, 800.0, 100.0).with_hazard_density(1.8).with_refuel_rate(5.0);
+        for _ in 0..100 {
+            level = level.escalated();
+        }
+
+        assert_eq!(level.number, 106);
+        assert_eq!(level.hazard_density, GameModeConstants::ENDLESS_MAX_HAZARD_DENSITY);
+        assert_eq!(level.fuel_spawn_distance, GameModeConstants::ENDLESS_MAX_FUEL_SPACING);
+        assert_eq!(level.refuel_rate, GameModeConstants::ENDLESS_MIN_REFUEL_RATE);
+    }
+
     #[test]
     fn test_reset() {
         let mut manager = LevelManager::new();
@@ -24,16 +24,96 @@ pub mod player;
 /// Procedural cave generation for endless gameplay.
 pub mod cave;
 
+/// Achievements unlocked by feats within a run.
+///
+/// A data-driven registry of conditions checked against run events.
+pub mod achievements;
+
+/// Death analytics for balancing.
+///
+/// Logs where ships are lost and bins them into a heat strip.
+pub mod analytics;
+
+/// Pickup combo chains.
+///
+/// Rewards collecting pickups in quick succession.
+pub mod combo;
+
 /// Collision detection system.
 ///
 /// AABB collision detection for game objects.
 pub mod collision;
 
+/// Difficulty presets.
+///
+/// Easy, Normal, Hard and Insane scale the whole simulation together.
+pub mod difficulty;
+
+/// Fuel endurance estimates and low-fuel warnings.
+///
+/// Predicts remaining burn time from recent history.
+pub mod endurance;
+
 /// Fuel system for consumption and refilling mechanics.
 ///
-/// Manages fuel levels, burn rates, and empty state detection.
+/// Manages fuel levels, per-action burn rates, and empty state detection.
 pub mod fuel;
 
+/// Persistent high-score table.
+///
+/// Keeps the best runs with their seed, date and death cause.
+pub mod highscore;
+
+/// High-score name entry.
+///
+/// Keyboard text entry and arcade-style letter cycling.
+pub mod name_entry;
+
+/// Hull damage model with shields.
+///
+/// Tracks hull points, shield charges and post-hit invulnerability.
+pub mod hull;
+
+/// Landing pads.
+///
+/// Tells soft touchdowns on a pad apart from crashes.
+pub mod landing;
+
+/// Game modes and their rules.
+///
+/// Campaign, Endless, Time Attack and Zen differ in progression, fuel, death and scoring.
+pub mod mode;
+
+/// Near-miss detection.
+///
+/// Rewards passing close to walls and hazards without touching them.
+pub mod near_miss;
+
+/// Lives, checkpoints and respawn fly-in.
+///
+/// Lets a run continue from the last checkpoint after losing a ship.
+pub mod respawn;
+
+/// Run results and events.
+///
+/// Records how and where a run ended and what happened along the way.
+pub mod run;
+
+/// Per-run and lifetime statistics.
+///
+/// Gathered from run events; lifetime totals are persisted.
+pub mod stats;
+
+/// Persistent storage for records that outlive the process.
+///
+/// Key-value storage trait with memory and file backends.
+pub mod storage;
+
+/// Scoring driven by run events.
+///
+/// Combines distance, pickups, near-misses and level bonuses.
+pub mod score;
+
 /// Tractor beam system for attraction and repulsion mechanics.
 ///
 /// Manages beam activation, direction, and timing without rendering concerns.
@@ -55,6 +135,26 @@ pub mod level;
 /// Manages fuel depots and other collectibles that spawn on cave walls.
 pub mod pickup;
 
+/// Moving hazards: drifting mines, falling rocks and wall turrets.
+///
+/// Seeded spawning, movement and collision against the ship.
+pub mod hazard;
+
+/// Grapple mode: anchor crystals and the rope that reels the ship in.
+///
+/// Fuel-free movement built on the tractor beam.
+pub mod grapple;
+
+/// Cargo delivery: tethered pods, drop-off platforms and mission goals.
+///
+/// Carrying cargo adds its mass to the ship.
+pub mod cargo;
+
+/// Cave passability validation.
+///
+/// A* path search proving a stretch of cave can be flown without collision.
+pub mod passability;
+
 /// Game constants for centralized configuration.
 ///
 /// Contains all magic numbers and configuration constants.
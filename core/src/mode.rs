@@ -0,0 +1,159 @@
+use crate::constants::{GameModeConstants, HighScoreConstants, WorldConstants};
+use crate::difficulty::Difficulty;
+use crate::level::LevelManager;
+
+/// Rule set a run is played under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum GameMode {
+    /// Fixed levels ending in victory after the last one
+    Campaign,
+    /// Levels keep getting harder for as long as the ship survives
+    #[default]
+    Endless,
+    /// Race to a fixed distance; boosting speeds up the scroll
+    TimeAttack,
+    /// No fuel drain and no death, for practice
+    Zen,
+}
+
+impl GameMode {
+    /// Every game mode, in menu order.
+    pub const ALL: [GameMode; 4] = [
+        GameMode::Campaign,
+        GameMode::Endless,
+        GameMode::TimeAttack,
+        GameMode::Zen,
+    ];
+
+    /// Finds the game mode with the given storage name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|mode| mode.to_string() == name)
+    }
+
+    /// Gets the mode after this one in menu order, wrapping around.
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&mode| mode == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Gets the mode before this one in menu order, wrapping around.
+    pub fn previous(self) -> Self {
+        let index = Self::ALL.iter().position(|&mode| mode == self).unwrap_or(0);
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    /// Name shown in menus.
+    pub fn title(self) -> &'static str {
+        match self {
+            GameMode::Campaign => "Campaign",
+            GameMode::Endless => "Endless",
+            GameMode::TimeAttack => "Time Attack",
+            GameMode::Zen => "Zen",
+        }
+    }
+
+    /// One-line summary of the rules.
+    pub fn description(self) -> &'static str {
+        match self {
+            GameMode::Campaign => "Clear every level to win",
+            GameMode::Endless => "Survive as difficulty keeps rising",
+            GameMode::TimeAttack => "Reach the finish fast; hold RIGHT to boost",
+            GameMode::Zen => "No fuel drain, no crashes - just practice",
+        }
+    }
+
+    /// Short message for the victory screen.
+    pub fn completion_message(self) -> &'static str {
+        match self {
+            GameMode::Campaign => "ALL LEVELS CLEARED!",
+            GameMode::TimeAttack => "FINISH LINE REACHED!",
+            GameMode::Endless | GameMode::Zen => "RUN ENDED",
+        }
+    }
+
+    /// Creates the level progression for a run in this mode.
+    pub fn level_manager(self) -> LevelManager {
+        match self {
+            GameMode::Endless => LevelManager::new().with_endless_scaling(),
+            GameMode::Campaign | GameMode::TimeAttack | GameMode::Zen => LevelManager::new(),
+        }
+    }
+
+    /// Checks if flying costs fuel.
+    pub fn burns_fuel(self) -> bool {
+        self != GameMode::Zen
+    }
+
+    /// Checks if crashes and hazards destroy the ship.
+    ///
+    /// Runs in a mode without death end from the pause menu.
+    pub fn is_lethal(self) -> bool {
+        self != GameMode::Zen
+    }
+
+    /// Checks if runs unlock achievements and add to lifetime stats.
+    ///
+    /// Practice runs without fuel drain or death would make both trivial.
+    pub fn tracks_progress(self) -> bool {
+        self != GameMode::Zen
+    }
+
+    /// Gets the cave scroll speed in pixels per second.
+    ///
+    /// # Arguments
+    /// * `boosting` - Whether the forward boost is held
+    pub fn scroll_speed(self, boosting: bool) -> f32 {
+        if self == GameMode::TimeAttack && boosting {
+            WorldConstants::SCROLL_SPEED * GameModeConstants::TIME_ATTACK_BOOST_MULTIPLIER
+        } else {
+            WorldConstants::SCROLL_SPEED
+        }
+    }
+
+    /// Gets the distance that finishes the run, if there is one.
+    pub fn target_distance(self) -> Option<f32> {
+        match self {
+            GameMode::TimeAttack => Some(GameModeConstants::TIME_ATTACK_DISTANCE),
+            GameMode::Campaign | GameMode::Endless | GameMode::Zen => None,
+        }
+    }
+
+    /// Checks if the run is complete after covering a distance.
+    pub fn reached_target(self, distance: f32) -> bool {
+        self.target_distance().is_some_and(|target| distance >= target)
+    }
+
+    /// Checks if clearing a level completes the run.
+    ///
+    /// # Arguments
+    /// * `level_manager` - Progression before the cleared level was left
+    pub fn completes_on_clear(self, level_manager: &LevelManager) -> bool {
+        self == GameMode::Campaign && level_manager.is_last_level()
+    }
+
+    /// Checks if a finished run may enter the high-score table.
+    ///
+    /// A time attack only counts if the finish line was reached.
+    pub fn ranks_run(self, completed: bool) -> bool {
+        completed || self != GameMode::TimeAttack
+    }
+
+    /// Gets the storage key of the high-score table for this mode at a difficulty.
+    ///
+    /// Each mode and difficulty pair ranks separately.
+    pub fn high_score_key(self, difficulty: Difficulty) -> String {
+        format!("{}_{}_{}", HighScoreConstants::STORAGE_KEY, self, difficulty)
+    }
+}
+
+impl std::fmt::Display for GameMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            GameMode::Campaign => "campaign",
+            GameMode::Endless => "endless",
+            GameMode::TimeAttack => "time_attack",
+            GameMode::Zen => "zen",
+        };
+        write!(f, "{}", name)
+    }
+}
@@ -0,0 +1,137 @@
+use crate::constants::HighScoreConstants;
+
+/// How the name is being entered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameEntryMode {
+    /// Free text typed on the keyboard
+    Keyboard,
+    /// Three letters picked by cycling each slot up and down
+    Arcade,
+}
+
+/// Name being entered for a new high score.
+///
+/// Typing switches to keyboard entry; cycling a letter switches to arcade
+/// entry, which trims or pads the name to three letters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameEntry {
+    chars: Vec<char>,
+    /// Slot edited by arcade entry
+    cursor: usize,
+    mode: NameEntryMode,
+}
+
+impl NameEntry {
+    /// Starts entry prefilled with the last name used.
+    ///
+    /// Characters that cannot appear in a name are dropped, and letters are
+    /// uppercased like typed ones.
+    pub fn new(last_name: &str) -> Self {
+        let chars: Vec<char> = last_name
+            .trim()
+            .chars()
+            .filter(|&c| Self::accepts(c))
+            .map(|c| c.to_ascii_uppercase())
+            .take(HighScoreConstants::MAX_NAME_LENGTH)
+            .collect();
+        Self {
+            chars,
+            cursor: 0,
+            mode: NameEntryMode::Keyboard,
+        }
+    }
+
+    /// Checks if a character may appear in a name.
+    pub fn accepts(c: char) -> bool {
+        c.is_ascii_alphanumeric() || c == ' '
+    }
+
+    /// Types a character at the end of the name.
+    ///
+    /// Returns false if the character was rejected or the name is full.
+    pub fn type_char(&mut self, c: char) -> bool {
+        self.mode = NameEntryMode::Keyboard;
+        if !Self::accepts(c) || self.chars.len() >= HighScoreConstants::MAX_NAME_LENGTH {
+            return false;
+        }
+
+        self.chars.push(c.to_ascii_uppercase());
+        true
+    }
+
+    /// Deletes the last character.
+    pub fn backspace(&mut self) {
+        self.mode = NameEntryMode::Keyboard;
+        self.chars.pop();
+    }
+
+    /// Cycles the letter in the current slot forward.
+    pub fn cycle_up(&mut self) {
+        self.cycle(1);
+    }
+
+    /// Cycles the letter in the current slot backward.
+    pub fn cycle_down(&mut self) {
+        self.cycle(-1);
+    }
+
+    /// Moves arcade entry to the next slot, stopping at the last one.
+    pub fn next_slot(&mut self) {
+        self.enter_arcade_mode();
+        self.cursor = (self.cursor + 1).min(HighScoreConstants::ARCADE_NAME_LENGTH - 1);
+    }
+
+    /// Moves arcade entry to the previous slot, stopping at the first one.
+    pub fn previous_slot(&mut self) {
+        self.enter_arcade_mode();
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    /// Gets the name as entered so far.
+    pub fn text(&self) -> String {
+        self.chars.iter().collect()
+    }
+
+    /// Gets the name to record, falling back to a placeholder when blank.
+    pub fn name(&self) -> String {
+        let name = self.text().trim().to_string();
+        if name.is_empty() {
+            HighScoreConstants::DEFAULT_NAME.to_string()
+        } else {
+            name
+        }
+    }
+
+    /// Gets the current entry mode.
+    pub fn mode(&self) -> NameEntryMode {
+        self.mode
+    }
+
+    /// Gets the slot edited by arcade entry.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Steps the letter in the current slot through the arcade alphabet.
+    fn cycle(&mut self, step: isize) {
+        self.enter_arcade_mode();
+        let alphabet: Vec<char> = HighScoreConstants::ARCADE_ALPHABET.chars().collect();
+        let current = alphabet
+            .iter()
+            .position(|&c| c == self.chars[self.cursor])
+            .unwrap_or(0) as isize;
+        let next = (current + step).rem_euclid(alphabet.len() as isize) as usize;
+        self.chars[self.cursor] = alphabet[next];
+    }
+
+    /// Switches to arcade entry, fitting the name to the arcade slots.
+    fn enter_arcade_mode(&mut self) {
+        if self.mode == NameEntryMode::Arcade {
+            return;
+        }
+
+        self.mode = NameEntryMode::Arcade;
+        self.chars.resize(HighScoreConstants::ARCADE_NAME_LENGTH, 'A');
+        self.cursor = 0;
+    }
+}
@@ -0,0 +1,107 @@
+use crate::constants::NearMissConstants;
+use crate::run::RunEvent;
+
+/// Distances from the ship to everything it could hit in one tick.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Clearance {
+    /// Distance to the ceiling
+    pub ceiling: f32,
+    /// Distance to the floor
+    pub floor: f32,
+    /// Distance to the nearest hazard or projectile, if any
+    pub obstacle: Option<f32>,
+}
+
+impl Clearance {
+    /// Gets the smallest of the distances.
+    pub fn min(&self) -> f32 {
+        let walls = self.ceiling.min(self.floor);
+        self.obstacle.map_or(walls, |obstacle| walls.min(obstacle))
+    }
+}
+
+/// Detects near-misses and tracks the streak multiplier.
+///
+/// A near-miss is scored when the ship flies into the threshold zone around
+/// a wall or obstacle and pulls back out to the recovery clearance without
+/// touching anything, so hovering across the threshold scores only once.
+/// Touching ends the pass unscored and breaks the streak; so does going too
+/// long between near-misses.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NearMissDetector {
+    /// Whether the ship is currently inside the threshold zone
+    in_zone: bool,
+    /// Whether the ship touched something during the current pass
+    touched: bool,
+    /// Consecutive near-misses in the current streak
+    streak: u32,
+    /// Time since the last near-miss in seconds
+    since_last: f32,
+    /// Closest clearance seen this tick
+    closest: Option<f32>,
+}
+
+impl NearMissDetector {
+    /// Creates a detector with no streak.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the detector with this tick's clearance.
+    ///
+    /// Returns a `NearMiss` event when a close pass ends without contact.
+    ///
+    /// # Arguments
+    /// * `clearance` - Distances from the ship this tick
+    /// * `touching` - Whether the ship hit a wall or obstacle this tick
+    /// * `dt` - Delta time in seconds
+    pub fn update(&mut self, clearance: Clearance, touching: bool, dt: f32) -> Option<RunEvent> {
+        self.closest = Some(clearance.min());
+        self.since_last += dt;
+        if self.since_last > NearMissConstants::STREAK_WINDOW {
+            self.streak = 0;
+        }
+
+        if touching {
+            self.touched = true;
+            self.streak = 0;
+        }
+
+        let close = clearance.min() <= NearMissConstants::THRESHOLD;
+        if close {
+            self.in_zone = true;
+            return None;
+        }
+        if self.in_zone && clearance.min() <= NearMissConstants::RECOVERY_CLEARANCE {
+            return None;
+        }
+
+        // Leaving the zone ends the pass
+        let was_in_zone = std::mem::replace(&mut self.in_zone, false);
+        let clean_pass = was_in_zone && !std::mem::replace(&mut self.touched, false);
+        if !clean_pass {
+            return None;
+        }
+
+        self.streak = (self.streak + 1).min(NearMissConstants::MAX_MULTIPLIER);
+        self.since_last = 0.0;
+        Some(RunEvent::NearMiss {
+            multiplier: self.streak,
+        })
+    }
+
+    /// Gets the current streak multiplier (0 when there is no streak).
+    pub fn streak(&self) -> u32 {
+        self.streak
+    }
+
+    /// Gets the closest clearance measured in the last tick.
+    pub fn closest(&self) -> Option<f32> {
+        self.closest
+    }
+
+    /// Clears the streak and the current pass.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
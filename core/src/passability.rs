@@ -0,0 +1,376 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashSet};
+
+use crate::cave::CaveSegment;
+use crate::collision::{aabb_overlap, Aabb};
+use crate::constants::{PassabilityConstants, WorldConstants};
+use crate::player::PlayerConstants;
+
+/// Errors that can occur while validating a stretch of cave.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PassabilityError {
+    /// No segments were supplied
+    EmptyStretch,
+    /// No collision-free path exists; holds the furthest ship x-position reached
+    Blocked { furthest_x: f32 },
+    /// The search gave up before proving or disproving a path
+    SearchLimitExceeded,
+}
+
+impl std::fmt::Display for PassabilityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PassabilityError::EmptyStretch => write!(f, "No cave segments to validate"),
+            PassabilityError::Blocked { furthest_x } => {
+                write!(f, "Cave is impassable beyond x={:.0}", furthest_x)
+            }
+            PassabilityError::SearchLimitExceeded => {
+                write!(f, "Path search exceeded its node limit")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PassabilityError {}
+
+/// Result type for passability checks
+pub type PassabilityResult<T> = Result<T, PassabilityError>;
+
+/// Physical limits of the ship used by the path search.
+///
+/// Accelerations follow the player convention: negative y is upward.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShipLimits {
+    pub width: f32,
+    pub height: f32,
+    /// Acceleration from the main thruster (pixels/sec²)
+    pub thrust: f32,
+    /// Acceleration from the down thruster (pixels/sec²)
+    pub down_thrust: f32,
+    /// Constant vertical acceleration (pixels/sec²)
+    pub gravity: f32,
+    /// Largest vertical speed considered by the search (pixels/sec)
+    pub max_vertical_speed: f32,
+    /// Forward speed of the ship through the cave (pixels/sec)
+    pub scroll_speed: f32,
+}
+
+impl Default for ShipLimits {
+    /// Limits matching the player physics used by the game.
+    fn default() -> Self {
+        Self {
+            width: PlayerConstants::WIDTH,
+            height: PlayerConstants::HEIGHT,
+            thrust: PlayerConstants::THRUST,
+            down_thrust: -PlayerConstants::THRUST * PlayerConstants::DOWN_THRUST_MULTIPLIER,
+            gravity: PlayerConstants::GRAVITY,
+            max_vertical_speed: PassabilityConstants::MAX_VERTICAL_SPEED,
+            scroll_speed: WorldConstants::SCROLL_SPEED,
+        }
+    }
+}
+
+/// Vertical clearance available to the ship in one search column.
+#[derive(Debug, Clone)]
+struct Column {
+    x: f32,
+    ceiling: f32,
+    floor: f32,
+    obstacles: Vec<Aabb>,
+}
+
+/// A node in the search arena.
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    column: usize,
+    y: f32,
+    vel_y: f32,
+    parent: Option<usize>,
+}
+
+/// Open-list entry ordered by lowest estimated total cost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct OpenEntry {
+    estimate: u32,
+    column: usize,
+    node: usize,
+}
+
+impl Ord for OpenEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reverse for a min-heap; prefer deeper columns on ties
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then(self.column.cmp(&other.column))
+    }
+}
+
+impl PartialOrd for OpenEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A* search over discretised ship position and vertical velocity.
+///
+/// Proves that a collision-free path through a stretch of cave exists
+/// for a ship with the given physics limits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PassabilityValidator {
+    limits: ShipLimits,
+    x_step: f32,
+    y_step: f32,
+    velocity_step: f32,
+}
+
+impl PassabilityValidator {
+    /// Cost of advancing one column.
+    const STEP_COST: u32 = 10;
+
+    /// Extra cost of firing a thruster, so coasting paths are preferred.
+    const THRUST_COST: u32 = 1;
+
+    /// Creates a validator with the default search resolution.
+    pub fn new(limits: ShipLimits) -> Self {
+        Self {
+            limits,
+            x_step: PassabilityConstants::X_STEP,
+            y_step: PassabilityConstants::Y_STEP,
+            velocity_step: PassabilityConstants::VELOCITY_STEP,
+        }
+    }
+
+    /// Overrides the search resolution.
+    ///
+    /// # Arguments
+    /// * `x_step` - Horizontal distance per search step in pixels
+    /// * `y_step` - Vertical position cell size in pixels
+    /// * `velocity_step` - Vertical velocity cell size in pixels per second
+    pub fn with_resolution(mut self, x_step: f32, y_step: f32, velocity_step: f32) -> Self {
+        self.x_step = x_step;
+        self.y_step = y_step;
+        self.velocity_step = velocity_step;
+        self
+    }
+
+    /// Gets the ship limits used by this validator.
+    pub fn limits(&self) -> &ShipLimits {
+        &self.limits
+    }
+
+    /// Checks whether a collision-free path exists through the stretch.
+    pub fn is_passable(&self, segments: &[CaveSegment], obstacles: &[Aabb]) -> bool {
+        self.find_path(segments, obstacles).is_ok()
+    }
+
+    /// Searches for a collision-free path through the stretch.
+    ///
+    /// The ship may start anywhere in the first column at rest and must
+    /// reach the last column. Returns the ship centre positions along the path.
+    ///
+    /// # Arguments
+    /// * `segments` - Contiguous cave segments ordered by x
+    /// * `obstacles` - Additional solid rectangles inside the stretch
+    pub fn find_path(
+        &self,
+        segments: &[CaveSegment],
+        obstacles: &[Aabb],
+    ) -> PassabilityResult<Vec<(f32, f32)>> {
+        let columns = self.build_columns(segments, obstacles)?;
+        let goal = columns.len() - 1;
+
+        let mut nodes: Vec<Node> = Vec::new();
+        let mut open = BinaryHeap::new();
+        let mut closed = HashSet::new();
+        let mut furthest_x = columns[0].x;
+
+        for y in self.start_positions(&columns[0]) {
+            nodes.push(Node { column: 0, y, vel_y: 0.0, parent: None });
+            open.push(OpenEntry {
+                estimate: self.heuristic(0, goal),
+                column: 0,
+                node: nodes.len() - 1,
+            });
+        }
+
+        let mut costs: Vec<u32> = vec![0; nodes.len()];
+        let mut expansions = 0;
+
+        while let Some(entry) = open.pop() {
+            let node = nodes[entry.node];
+            if !closed.insert(self.key(&node)) {
+                continue;
+            }
+
+            furthest_x = furthest_x.max(columns[node.column].x);
+            if node.column == goal {
+                return Ok(Self::reconstruct_path(&nodes, &columns, entry.node));
+            }
+
+            expansions += 1;
+            if expansions > PassabilityConstants::MAX_EXPANSIONS {
+                return Err(PassabilityError::SearchLimitExceeded);
+            }
+
+            for (acceleration, action_cost) in self.actions() {
+                let next = self.step(&node, acceleration, entry.node);
+                if self.collides(&columns[next.column], next.y) || closed.contains(&self.key(&next)) {
+                    continue;
+                }
+
+                let cost = costs[entry.node] + Self::STEP_COST + action_cost;
+                nodes.push(next);
+                costs.push(cost);
+                open.push(OpenEntry {
+                    estimate: cost + self.heuristic(next.column, goal),
+                    column: next.column,
+                    node: nodes.len() - 1,
+                });
+            }
+        }
+
+        Err(PassabilityError::Blocked { furthest_x })
+    }
+
+    /// Builds the search columns with their wall clearance.
+    fn build_columns(
+        &self,
+        segments: &[CaveSegment],
+        obstacles: &[Aabb],
+    ) -> PassabilityResult<Vec<Column>> {
+        let first = segments.first().ok_or(PassabilityError::EmptyStretch)?;
+        let last = segments.last().ok_or(PassabilityError::EmptyStretch)?;
+
+        let half_width = self.limits.width / 2.0;
+        let start_x = first.x_start + half_width;
+        let end_x = (last.x_end() - half_width).max(start_x);
+        let count = ((end_x - start_x) / self.x_step).floor() as usize + 1;
+
+        let columns = (0..count)
+            .map(|index| {
+                let x = start_x + index as f32 * self.x_step;
+                self.build_column(x, segments, obstacles)
+            })
+            .collect();
+
+        Ok(columns)
+    }
+
+    /// Finds the tightest ceiling and floor under the ship at one x-position.
+    fn build_column(&self, x: f32, segments: &[CaveSegment], obstacles: &[Aabb]) -> Column {
+        let left = x - self.limits.width / 2.0;
+        let right = x + self.limits.width / 2.0;
+
+        let mut column = Column {
+            x,
+            ceiling: 0.0,
+            floor: WorldConstants::SCREEN_HEIGHT,
+            obstacles: Vec::new(),
+        };
+
+        for segment in segments.iter().filter(|s| s.x_start < right && s.x_end() > left) {
+            column.ceiling = column.ceiling.max(segment.ceiling);
+            column.floor = column.floor.min(segment.floor);
+        }
+
+        column.obstacles = obstacles
+            .iter()
+            .filter(|o| o.left() < right && o.right() > left)
+            .copied()
+            .collect();
+
+        column
+    }
+
+    /// Collision-free starting positions in the first column.
+    fn start_positions(&self, column: &Column) -> Vec<f32> {
+        let mut positions = Vec::new();
+        let mut y = column.ceiling + self.limits.height / 2.0;
+
+        while y + self.limits.height / 2.0 <= column.floor {
+            if !self.collides(column, y) {
+                positions.push(y);
+            }
+            y += self.y_step;
+        }
+
+        positions
+    }
+
+    /// Available actions as (vertical acceleration, extra cost).
+    fn actions(&self) -> [(f32, u32); 3] {
+        [
+            (self.limits.gravity, 0),
+            (self.limits.gravity + self.limits.thrust, Self::THRUST_COST),
+            (self.limits.gravity + self.limits.down_thrust, Self::THRUST_COST),
+        ]
+    }
+
+    /// Advances a node by one column using the player's integration order.
+    fn step(&self, node: &Node, acceleration: f32, parent: usize) -> Node {
+        let dt = self.x_step / self.limits.scroll_speed;
+        let vel_y = (node.vel_y + acceleration * dt)
+            .clamp(-self.limits.max_vertical_speed, self.limits.max_vertical_speed);
+
+        Node {
+            column: node.column + 1,
+            y: node.y + vel_y * dt,
+            vel_y,
+            parent: Some(parent),
+        }
+    }
+
+    /// Checks whether the ship centred at `y` touches walls or obstacles.
+    fn collides(&self, column: &Column, y: f32) -> bool {
+        let top = y - self.limits.height / 2.0;
+        let bottom = y + self.limits.height / 2.0;
+
+        if top < column.ceiling || bottom > column.floor {
+            return true;
+        }
+
+        let ship_pos = (column.x - self.limits.width / 2.0, top);
+        let ship_size = (self.limits.width, self.limits.height);
+
+        column
+            .obstacles
+            .iter()
+            .any(|o| aabb_overlap(ship_pos, ship_size, (o.x, o.y), (o.width, o.height)))
+    }
+
+    /// Discretised state used for the closed set.
+    fn key(&self, node: &Node) -> (usize, i32, i32) {
+        (
+            node.column,
+            (node.y / self.y_step).round() as i32,
+            (node.vel_y / self.velocity_step).round() as i32,
+        )
+    }
+
+    /// Admissible estimate of the remaining cost.
+    fn heuristic(&self, column: usize, goal: usize) -> u32 {
+        (goal - column) as u32 * Self::STEP_COST
+    }
+
+    /// Walks parent links back to the start.
+    fn reconstruct_path(nodes: &[Node], columns: &[Column], last: usize) -> Vec<(f32, f32)> {
+        let mut path = Vec::new();
+        let mut current = Some(last);
+
+        while let Some(index) = current {
+            let node = nodes[index];
+            path.push((columns[node.column].x, node.y));
+            current = node.parent;
+        }
+
+        path.reverse();
+        path
+    }
+}
+
+impl Default for PassabilityValidator {
+    fn default() -> Self {
+        Self::new(ShipLimits::default())
+    }
+}
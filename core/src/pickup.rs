@@ -3,23 +3,53 @@
 /// Manages fuel depots and other collectibles that spawn on cave walls.
 
 use crate::cave::SimpleRng;
-use crate::constants::{PickupConstants, TractorBeamConstants};
-use crate::tractor::TractorBeam;
+use crate::constants::{CargoConstants, PickupConstants, TractorBeamConstants};
+use crate::tractor::{BeamPolarity, TractorBeam};
 
 /// Types of pickups available in the game.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum PickupType {
     /// Fuel depot that refills the player's fuel tank
     Fuel,
+    /// Shield cell that absorbs one wall hit under the hull damage model
+    Shield,
+    /// Cargo pod to be carried to a drop-off platform in cargo mode
+    Cargo,
+}
+
+impl PickupType {
+    /// Gets the mass used when the pickup is moved by the tractor beam.
+    pub fn mass(self) -> f32 {
+        match self {
+            PickupType::Fuel => PickupConstants::FUEL_MASS,
+            PickupType::Shield => PickupConstants::SHIELD_MASS,
+            PickupType::Cargo => CargoConstants::POD_MASS,
+        }
+    }
+}
+
+/// Something that happened to a pickup, reported by the `PickupManager`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PickupEvent {
+    /// The ship collected the pickup
+    Collected(PickupType),
+    /// The pickup scrolled past uncollected
+    Missed(PickupType),
 }
 
 /// A collectible item positioned on cave walls.
+///
+/// Pickups rest on a wall until the tractor beam grabs them. While in the
+/// beam and after release they move as physics bodies, falling under gravity
+/// until they land on the floor.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Pickup {
     /// Position in world coordinates (x, y)
     pub position: (f32, f32),
-    /// Original position when spawned (for beam attraction)
+    /// Resting position on the wall (updated when a dropped pickup lands)
     pub original_position: (f32, f32),
+    /// Velocity in pixels per second
+    pub velocity: (f32, f32),
     /// Type of pickup
     pub pickup_type: PickupType,
     /// Whether the pickup is attached to the ceiling (true) or floor (false)
@@ -28,6 +58,10 @@ pub struct Pickup {
     pub collected: bool,
     /// Whether this pickup is currently being attracted by a tractor beam
     pub being_attracted: bool,
+    /// Whether this pickup was released and is drifting under gravity
+    pub falling: bool,
+    /// Whether this pickup scrolled past uncollected
+    pub missed: bool,
 }
 
 impl Pickup {
@@ -36,10 +70,13 @@ impl Pickup {
         Self {
             position,
             original_position: position,
+            velocity: (0.0, 0.0),
             pickup_type,
             is_on_ceiling,
             collected: false,
             being_attracted: false,
+            falling: false,
+            missed: false,
         }
     }
 
@@ -51,22 +88,122 @@ impl Pickup {
     /// Resets pickup position to original wall position.
     pub fn reset_to_wall(&mut self) {
         self.position = self.original_position;
+        self.velocity = (0.0, 0.0);
         self.being_attracted = false;
+        self.falling = false;
     }
 
-    /// Updates pickup position during tractor beam attraction.
+    /// Accelerates the pickup along a beam force and moves it.
+    ///
+    /// Heavier pickups accelerate more slowly; the speed is capped.
     ///
     /// # Arguments
-    /// * `force` - Normalized attraction force vector
-    /// * `speed` - Attraction speed multiplier
+    /// * `force` - Normalized beam force vector
+    /// * `acceleration` - Beam acceleration for a pickup of unit mass
+    /// * `max_speed` - Speed cap in pixels per second
     /// * `dt` - Delta time for frame-rate independent movement
-    pub fn apply_attraction(&mut self, force: (f32, f32), speed: f32, dt: f32) {
-        if !self.collected {
-            self.position.0 += force.0 * speed * dt;
-            self.position.1 += force.1 * speed * dt;
-            self.being_attracted = true;
+    pub fn apply_beam_force(&mut self, force: (f32, f32), acceleration: f32, max_speed: f32, dt: f32) {
+        if self.collected {
+            return;
+        }
+
+        let accel = acceleration / self.pickup_type.mass();
+        self.velocity.0 += force.0 * accel * dt;
+        self.velocity.1 += force.1 * accel * dt;
+
+        let speed = (self.velocity.0 * self.velocity.0 + self.velocity.1 * self.velocity.1).sqrt();
+        if speed > max_speed {
+            self.velocity.0 *= max_speed / speed;
+            self.velocity.1 *= max_speed / speed;
+        }
+
+        self.position.0 += self.velocity.0 * dt;
+        self.position.1 += self.velocity.1 * dt;
+        self.being_attracted = true;
+        self.falling = false;
+    }
+
+    /// Lets go of the pickup so it drifts with its current velocity.
+    ///
+    /// A pickup the beam only pressed against its wall stays resting there,
+    /// where it slid to along the wall.
+    pub fn release(&mut self) {
+        self.being_attracted = false;
+        if self.position.1 == self.original_position.1 && self.velocity.1 == 0.0 {
+            self.velocity = (0.0, 0.0);
+            self.original_position = self.position;
+            self.falling = false;
+        } else {
+            self.falling = true;
+        }
+    }
+
+    /// Moves a released pickup under gravity and drag.
+    pub fn update_free(&mut self, dt: f32) {
+        if !self.falling || self.collected {
+            return;
+        }
+
+        let damping = (1.0 - PickupConstants::DRAG * dt).max(0.0);
+        self.velocity.0 *= damping;
+        self.velocity.1 = self.velocity.1 * damping + PickupConstants::GRAVITY * dt;
+
+        self.position.0 += self.velocity.0 * dt;
+        self.position.1 += self.velocity.1 * dt;
+    }
+
+    /// Keeps a moving pickup inside the cave.
+    ///
+    /// A falling pickup that reaches the floor lands there and rests again;
+    /// one that hits the ceiling bounces off it.
+    ///
+    /// # Arguments
+    /// * `ceiling_y` - Y-coordinate of the ceiling at the pickup
+    /// * `floor_y` - Y-coordinate of the floor at the pickup
+    pub fn collide_with_walls(&mut self, ceiling_y: f32, floor_y: f32) {
+        let top = ceiling_y + PickupConstants::WALL_OFFSET;
+        let bottom = floor_y - PickupConstants::SIZE - PickupConstants::WALL_OFFSET;
+
+        if self.position.1 >= bottom {
+            self.position.1 = bottom;
+            self.velocity.1 = self.velocity.1.min(0.0);
+            if self.falling {
+                self.land();
+            }
+        } else if self.position.1 <= top {
+            self.position.1 = top;
+            if self.velocity.1 < 0.0 {
+                self.velocity.1 = -self.velocity.1 * PickupConstants::RESTITUTION;
+            }
+        }
+    }
+
+    /// Brings a falling pickup to rest on the floor.
+    fn land(&mut self) {
+        self.falling = false;
+        self.velocity = (0.0, 0.0);
+        self.original_position = self.position;
+        self.is_on_ceiling = false;
+    }
+
+    /// Keeps a pushed pickup from being driven into its wall.
+    pub fn clamp_to_wall(&mut self) {
+        let clamped = if self.is_on_ceiling {
+            self.position.1.max(self.original_position.1)
+        } else {
+            self.position.1.min(self.original_position.1)
+        };
+
+        if clamped != self.position.1 {
+            self.position.1 = clamped;
+            self.velocity.1 = 0.0;
         }
     }
+
+    /// Checks if the pickup is moving freely (in the beam or drifting).
+    pub fn is_moving(&self) -> bool {
+        self.being_attracted || self.falling
+    }
 }
 
 /// Manages spawning and tracking of pickups.
@@ -80,6 +217,12 @@ pub struct PickupManager {
     last_pickup_x: f32,
     /// Distance to next pickup spawn
     next_pickup_distance: f32,
+    /// Probability that a spawned pickup is a shield instead of fuel
+    shield_spawn_chance: f32,
+    /// Probability that a spawned pickup is a cargo pod instead of fuel
+    cargo_spawn_chance: f32,
+    /// Collections and misses since the last drain
+    events: Vec<PickupEvent>,
 }
 
 impl PickupManager {
@@ -90,9 +233,28 @@ impl PickupManager {
             rng: SimpleRng::new(seed.wrapping_add(PickupConstants::RNG_SEED_OFFSET)),
             last_pickup_x: -PickupConstants::INITIAL_SPAWN_DELAY,
             next_pickup_distance: 0.0,
+            shield_spawn_chance: 0.0,
+            cargo_spawn_chance: 0.0,
+            events: Vec::new(),
         }
     }
 
+    /// Sets the probability that a spawned pickup is a shield.
+    ///
+    /// # Arguments
+    /// * `chance` - Probability in [0, 1]; 0 spawns only fuel
+    pub fn set_shield_spawn_chance(&mut self, chance: f32) {
+        self.shield_spawn_chance = chance.clamp(0.0, 1.0);
+    }
+
+    /// Sets the probability that a spawned pickup is a cargo pod.
+    ///
+    /// # Arguments
+    /// * `chance` - Probability in [0, 1]; 0 disables cargo pods
+    pub fn set_cargo_spawn_chance(&mut self, chance: f32) {
+        self.cargo_spawn_chance = chance.clamp(0.0, 1.0);
+    }
+
     /// Checks if a pickup should spawn at the given x-coordinate.
     ///
     /// # Arguments
@@ -120,6 +282,36 @@ impl PickupManager {
     /// * `ceiling_y` - Y-coordinate of the ceiling at this x position
     /// * `floor_y` - Y-coordinate of the floor at this x position
     pub fn spawn_fuel_pickup(&mut self, x: f32, ceiling_y: f32, floor_y: f32) {
+        self.spawn_pickup(x, ceiling_y, floor_y, PickupType::Fuel);
+    }
+
+    /// Spawns a pickup whose type is rolled from the configured shield and cargo chances.
+    ///
+    /// # Arguments
+    /// * `x` - X-coordinate for the pickup
+    /// * `ceiling_y` - Y-coordinate of the ceiling at this x position
+    /// * `floor_y` - Y-coordinate of the floor at this x position
+    pub fn spawn_random_pickup(&mut self, x: f32, ceiling_y: f32, floor_y: f32) {
+        let special_chance = self.shield_spawn_chance + self.cargo_spawn_chance;
+        let roll = if special_chance > 0.0 { self.rng.next_f32() } else { 1.0 };
+        let pickup_type = if roll < self.shield_spawn_chance {
+            PickupType::Shield
+        } else if roll < special_chance {
+            PickupType::Cargo
+        } else {
+            PickupType::Fuel
+        };
+        self.spawn_pickup(x, ceiling_y, floor_y, pickup_type);
+    }
+
+    /// Spawns a pickup of the given type on a randomly chosen wall.
+    ///
+    /// # Arguments
+    /// * `x` - X-coordinate for the pickup
+    /// * `ceiling_y` - Y-coordinate of the ceiling at this x position
+    /// * `floor_y` - Y-coordinate of the floor at this x position
+    /// * `pickup_type` - Type of pickup to spawn
+    pub fn spawn_pickup(&mut self, x: f32, ceiling_y: f32, floor_y: f32, pickup_type: PickupType) {
         // Randomly choose ceiling or floor
         let is_on_ceiling = self.rng.next_f32() < 0.5;
         
@@ -130,7 +322,7 @@ impl PickupManager {
             floor_y - PickupConstants::SIZE - PickupConstants::WALL_OFFSET
         };
         
-        let pickup = Pickup::new((x, y), PickupType::Fuel, is_on_ceiling);
+        let pickup = Pickup::new((x, y), pickup_type, is_on_ceiling);
         self.pickups.push(pickup);
     }
 
@@ -146,6 +338,18 @@ impl PickupManager {
             .collect()
     }
 
+    /// Gets the x-coordinate of the nearest uncollected fuel depot ahead.
+    ///
+    /// # Arguments
+    /// * `x` - X-coordinate to search forward from
+    pub fn next_fuel_pickup_x(&self, x: f32) -> Option<f32> {
+        self.pickups
+            .iter()
+            .filter(|p| !p.collected && p.pickup_type == PickupType::Fuel && p.position.0 >= x)
+            .map(|p| p.position.0)
+            .min_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+    }
+
     /// Checks for collision between player and pickups.
     ///
     /// Returns the index of the first pickup that collides with the player.
@@ -179,6 +383,35 @@ impl PickupManager {
         None
     }
 
+    /// Finds a pickup of the given type that the beam has brought within reach.
+    ///
+    /// Returns the index of the first uncollected pickup being attracted whose
+    /// centre lies within `reach` of the player.
+    ///
+    /// # Arguments
+    /// * `pickup_type` - Type of pickup to look for
+    /// * `player_pos` - Player position (x, y)
+    /// * `reach` - Maximum distance from the player in pixels
+    pub fn find_held_in_reach(&self, pickup_type: PickupType, player_pos: (f32, f32), reach: f32) -> Option<usize> {
+        let half = PickupConstants::SIZE / 2.0;
+        self.pickups.iter().position(|pickup| {
+            let dx = pickup.position.0 + half - player_pos.0;
+            let dy = pickup.position.1 + half - player_pos.1;
+            !pickup.collected
+                && pickup.being_attracted
+                && pickup.pickup_type == pickup_type
+                && (dx * dx + dy * dy).sqrt() <= reach
+        })
+    }
+
+    /// Gets the pickup at the specified index.
+    ///
+    /// # Arguments
+    /// * `index` - Index returned by `check_collision`
+    pub fn pickup_at(&self, index: usize) -> Option<&Pickup> {
+        self.pickups.get(index)
+    }
+
     /// Collects a pickup at the specified index.
     ///
     /// # Arguments
@@ -187,6 +420,7 @@ impl PickupManager {
         if let Some(pickup) = self.pickups.get_mut(index) {
             if !pickup.collected {
                 pickup.collect();
+                self.events.push(PickupEvent::Collected(pickup.pickup_type));
                 return Some(pickup.pickup_type);
             }
         }
@@ -204,6 +438,27 @@ impl PickupManager {
         });
     }
 
+    /// Reports pickups that have scrolled past uncollected.
+    ///
+    /// Each pickup is reported once, as a `Missed` event.
+    ///
+    /// # Arguments
+    /// * `x` - X-coordinate a pickup must fall behind to count as missed
+    pub fn check_missed_pickups(&mut self, x: f32) {
+        for pickup in &mut self.pickups {
+            let behind = pickup.position.0 + PickupConstants::SIZE < x;
+            if behind && !pickup.collected && !pickup.missed && !pickup.is_moving() {
+                pickup.missed = true;
+                self.events.push(PickupEvent::Missed(pickup.pickup_type));
+            }
+        }
+    }
+
+    /// Consumes and returns all collection and miss events.
+    pub fn drain_events(&mut self) -> Vec<PickupEvent> {
+        std::mem::take(&mut self.events)
+    }
+
     /// Gets the total number of active pickups (for debugging).
     pub fn active_pickup_count(&self) -> usize {
         self.pickups.iter().filter(|p| !p.collected).count()
@@ -231,7 +486,7 @@ impl PickupManager {
             if tractor_beam.is_active() {
                 Self::update_pickup_attraction(pickup, tractor_beam, player_pos, dt);
             } else if pickup.being_attracted {
-                pickup.reset_to_wall();
+                pickup.release();
             }
         }
     }
@@ -260,7 +515,7 @@ impl PickupManager {
         if tractor_beam.should_maintain_attraction(player_pos, pickup.position) {
             Self::apply_attraction_force(pickup, tractor_beam, player_pos, dt);
         } else {
-            pickup.reset_to_wall();
+            pickup.release();
         }
     }
 
@@ -276,16 +531,42 @@ impl PickupManager {
         }
     }
 
-    /// Applies attraction force to a pickup if force is non-zero.
+    /// Applies the beam force to a pickup if force is non-zero.
+    ///
+    /// Pulling draws the pickup toward the player, pushing shoves it back
+    /// toward its wall. The force falls off with distance along the beam.
     fn apply_attraction_force(
         pickup: &mut Pickup,
         tractor_beam: &TractorBeam,
         player_pos: (f32, f32),
         dt: f32,
     ) {
-        let force = tractor_beam.get_attraction_force(player_pos, pickup.position);
+        let force = tractor_beam.get_beam_force(player_pos, pickup.position);
         if force != (0.0, 0.0) {
-            pickup.apply_attraction(force, TractorBeamConstants::ATTRACTION_SPEED, dt);
+            let acceleration =
+                TractorBeamConstants::BEAM_ACCELERATION * tractor_beam.strength_at(player_pos, pickup.position);
+            pickup.apply_beam_force(force, acceleration, TractorBeamConstants::ATTRACTION_SPEED, dt);
+            if tractor_beam.polarity == BeamPolarity::Push {
+                pickup.clamp_to_wall();
+            }
+        }
+    }
+
+    /// Moves released pickups and keeps all moving pickups inside the cave.
+    ///
+    /// # Arguments
+    /// * `dt` - Delta time for frame-rate independent movement
+    /// * `walls_at` - Gets the (ceiling, floor) heights at an x-coordinate
+    pub fn update_physics<F>(&mut self, dt: f32, walls_at: F)
+    where
+        F: Fn(f32) -> Option<(f32, f32)>,
+    {
+        for pickup in self.pickups.iter_mut().filter(|p| !p.collected && p.is_moving()) {
+            pickup.update_free(dt);
+
+            if let Some((ceiling_y, floor_y)) = walls_at(pickup.position.0 + PickupConstants::SIZE / 2.0) {
+                pickup.collide_with_walls(ceiling_y, floor_y);
+            }
         }
     }
 
@@ -316,6 +597,7 @@ impl PickupManager {
     /// Clears all pickups (used when transitioning to a new level).
     pub fn clear_all_pickups(&mut self) {
         self.pickups.clear();
+        self.events.clear();
         self.last_pickup_x = 0.0;
         self.next_pickup_distance = PickupConstants::INITIAL_SPAWN_DELAY;
     }
@@ -503,10 +785,11 @@ mod tests {
         assert_eq!(pickup.original_position, (100.0, 50.0));
         assert!(!pickup.being_attracted);
         
-        // Apply attraction force
-        pickup.apply_attraction((1.0, 0.0), 100.0, 0.1);
+        // Apply beam force: velocity picks up first, then the position follows
+        pickup.apply_beam_force((1.0, 0.0), 1000.0, 200.0, 0.1);
         
         // Position should have moved
+        assert_eq!(pickup.velocity, (100.0, 0.0));
         assert_eq!(pickup.position, (110.0, 50.0));
         assert!(pickup.being_attracted);
         
@@ -616,7 +899,22 @@ mod tests {
     }
 
     #[test]
-    fn test_beam_deactivation_resets_pickups() {
+    fn test_beam_force_respects_mass_and_speed_cap() {
+        let mut fuel = Pickup::new((0.0, 0.0), PickupType::Fuel, true);
+        let mut shield = Pickup::new((0.0, 0.0), PickupType::Shield, true);
+
+        fuel.apply_beam_force((0.0, 1.0), 100.0, 1000.0, 0.1);
+        shield.apply_beam_force((0.0, 1.0), 100.0, 1000.0, 0.1);
+        assert!(shield.velocity.1 > fuel.velocity.1); // Lighter pickups accelerate faster
+
+        for _ in 0..100 {
+            fuel.apply_beam_force((0.0, 1.0), 5000.0, 200.0, 0.1);
+        }
+        assert!((fuel.velocity.1 - 200.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_beam_deactivation_releases_pickups() {
         use crate::tractor::{TractorBeam, BeamDir};
         
         let mut manager = PickupManager::new(0);
@@ -646,9 +944,99 @@ mod tests {
         
         manager.update_tractor_beam_attraction(&beam, player_pos, dt);
         
-        // Pickup should be reset to original position
+        // Pickup is released where it is and keeps its momentum
         assert!(!manager.pickups[0].being_attracted);
-        assert_eq!(manager.pickups[0].position, manager.pickups[0].original_position);
+        assert!(manager.pickups[0].falling);
+        assert_eq!(manager.pickups[0].position, moved_position);
+        assert!(manager.pickups[0].velocity.1 > 0.0);
+    }
+
+    #[test]
+    fn test_released_pickup_falls_and_lands_on_floor() {
+        let mut manager = PickupManager::new(0);
+        let mut pickup = Pickup::new((100.0, 105.0), PickupType::Fuel, true);
+        pickup.velocity = (0.0, 50.0);
+        pickup.release();
+        manager.pickups.push(pickup);
+
+        let walls = |_x: f32| Some((100.0, 400.0));
+        for _ in 0..300 {
+            manager.update_physics(0.016, walls);
+        }
+
+        let landed = manager.pickups[0];
+        let rest_y = 400.0 - PickupConstants::SIZE - PickupConstants::WALL_OFFSET;
+        assert!(!landed.falling);
+        assert!(!landed.is_on_ceiling);
+        assert_eq!(landed.position.1, rest_y);
+        assert_eq!(landed.original_position, landed.position);
+        assert_eq!(landed.velocity, (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_released_pickup_bounces_off_ceiling() {
+        let mut pickup = Pickup::new((100.0, 110.0), PickupType::Fuel, true);
+        pickup.velocity = (0.0, -300.0);
+        pickup.release();
+
+        pickup.update_free(0.1);
+        pickup.collide_with_walls(100.0, 400.0);
+
+        assert_eq!(pickup.position.1, 100.0 + PickupConstants::WALL_OFFSET);
+        assert!(pickup.velocity.1 > 0.0);
+        assert!(pickup.falling);
+    }
+
+    #[test]
+    fn test_pushed_ceiling_pickup_stays_on_ceiling_after_release() {
+        use crate::tractor::{BeamDir, BeamPolarity, TractorBeam};
+
+        let mut manager = PickupManager::new(0);
+        manager.pickups.push(Pickup::new((100.0, 105.0), PickupType::Fuel, true));
+
+        let mut beam = TractorBeam::new();
+        beam.polarity = BeamPolarity::Push;
+        beam.activate(BeamDir::Up);
+        let player_pos = (100.0, 200.0);
+        for _ in 0..10 {
+            manager.update_tractor_beam_attraction(&beam, player_pos, 0.016);
+        }
+        assert!(manager.pickups[0].being_attracted);
+        assert_eq!(manager.pickups[0].position.1, 105.0);
+
+        // Deactivate beam by letting it expire
+        beam.timer = 0.0;
+        beam.active = false;
+        manager.update_tractor_beam_attraction(&beam, player_pos, 0.016);
+        let walls = |_x: f32| Some((100.0, 400.0));
+        for _ in 0..60 {
+            manager.update_physics(0.016, walls);
+        }
+
+        let pickup = manager.pickups[0];
+        assert!(!pickup.being_attracted);
+        assert!(!pickup.falling);
+        assert!(pickup.is_on_ceiling);
+        assert_eq!(pickup.position.1, 105.0);
+        assert_eq!(pickup.original_position, pickup.position);
+    }
+
+    #[test]
+    fn test_beam_catches_falling_pickup() {
+        use crate::tractor::{TractorBeam, BeamDir};
+
+        let mut manager = PickupManager::new(0);
+        let mut pickup = Pickup::new((100.0, 150.0), PickupType::Fuel, true);
+        pickup.velocity = (0.0, 100.0);
+        pickup.release();
+        manager.pickups.push(pickup);
+
+        let mut beam = TractorBeam::new();
+        beam.activate(BeamDir::Up);
+        manager.update_tractor_beam_attraction(&beam, (100.0, 200.0), 0.016);
+
+        assert!(manager.pickups[0].being_attracted);
+        assert!(!manager.pickups[0].falling);
     }
 
     #[test]
@@ -739,10 +1127,135 @@ mod tests {
         // Move pickup way outside hold width
         manager.pickups[0].position.0 = 140.0; // 40px right (outside 24px hold half-width)
         
-        // Should stop being attracted and reset to wall
+        // Should stop being attracted and drift free from where it was
         manager.update_tractor_beam_attraction(&beam, player_pos, dt);
         assert!(!manager.pickups[0].being_attracted);
-        assert_eq!(manager.pickups[0].position, manager.pickups[0].original_position);
+        assert!(manager.pickups[0].falling);
+        assert_eq!(manager.pickups[0].position.0, 140.0);
+    }
+
+    #[test]
+    fn test_shield_spawn_chance() {
+        // Default manager never spawns shields
+        let mut manager = PickupManager::new(7);
+        for i in 0..20 {
+            manager.spawn_random_pickup(i as f32 * 100.0, 50.0, 300.0);
+        }
+        assert!(manager.pickups.iter().all(|p| p.pickup_type == PickupType::Fuel));
+
+        // Guaranteed shields when chance is 1
+        let mut manager = PickupManager::new(7);
+        manager.set_shield_spawn_chance(1.0);
+        for i in 0..20 {
+            manager.spawn_random_pickup(i as f32 * 100.0, 50.0, 300.0);
+        }
+        assert!(manager.pickups.iter().all(|p| p.pickup_type == PickupType::Shield));
+    }
+
+    #[test]
+    fn test_next_fuel_pickup_x() {
+        let mut manager = PickupManager::new(0);
+        manager.pickups.push(Pickup::new((300.0, 100.0), PickupType::Fuel, true));
+        manager.pickups.push(Pickup::new((200.0, 100.0), PickupType::Shield, true));
+        manager.pickups.push(Pickup::new((500.0, 100.0), PickupType::Fuel, false));
+
+        assert_eq!(manager.next_fuel_pickup_x(100.0), Some(300.0));
+        manager.pickups[0].collect();
+        assert_eq!(manager.next_fuel_pickup_x(100.0), Some(500.0));
+        assert_eq!(manager.next_fuel_pickup_x(600.0), None);
+    }
+
+    #[test]
+    fn test_collection_and_miss_events() {
+        let mut manager = PickupManager::new(0);
+        manager.pickups.push(Pickup::new((100.0, 100.0), PickupType::Fuel, true));
+        manager.pickups.push(Pickup::new((200.0, 100.0), PickupType::Shield, false));
+        manager.pickups.push(Pickup::new((400.0, 100.0), PickupType::Fuel, false));
+
+        manager.collect_pickup(0);
+        manager.check_missed_pickups(300.0);
+        // Already reported pickups are not reported again
+        manager.check_missed_pickups(300.0);
+        manager.collect_pickup(0);
+
+        assert_eq!(
+            manager.drain_events(),
+            vec![
+                PickupEvent::Collected(PickupType::Fuel),
+                PickupEvent::Missed(PickupType::Shield),
+            ]
+        );
+        assert!(manager.drain_events().is_empty());
+        assert!(manager.pickups[1].missed);
+        assert!(!manager.pickups[2].missed);
+    }
+
+    #[test]
+    fn test_cargo_spawn_chance() {
+        // Guaranteed pods when chance is 1
+        let mut manager = PickupManager::new(7);
+        manager.set_cargo_spawn_chance(1.0);
+        for i in 0..20 {
+            manager.spawn_random_pickup(i as f32 * 100.0, 50.0, 300.0);
+        }
+        assert!(manager.pickups.iter().all(|p| p.pickup_type == PickupType::Cargo));
+
+        // Mixed chances still spawn some fuel
+        let mut manager = PickupManager::new(7);
+        manager.set_shield_spawn_chance(0.2);
+        manager.set_cargo_spawn_chance(0.3);
+        for i in 0..100 {
+            manager.spawn_random_pickup(i as f32 * 100.0, 50.0, 300.0);
+        }
+        for pickup_type in [PickupType::Fuel, PickupType::Shield, PickupType::Cargo] {
+            assert!(manager.pickups.iter().any(|p| p.pickup_type == pickup_type));
+        }
+    }
+
+    #[test]
+    fn test_find_held_in_reach() {
+        use crate::tractor::{TractorBeam, BeamDir};
+
+        let mut manager = PickupManager::new(0);
+        manager.pickups.push(Pickup::new((100.0, 100.0), PickupType::Cargo, true));
+        let player_pos = (100.0 + PickupConstants::SIZE / 2.0, 200.0);
+
+        // A pod resting on the wall is not held, however close
+        assert_eq!(manager.find_held_in_reach(PickupType::Cargo, player_pos, 200.0), None);
+
+        let mut beam = TractorBeam::new();
+        beam.activate(BeamDir::Up);
+        manager.update_tractor_beam_attraction(&beam, player_pos, 0.016);
+
+        assert_eq!(manager.find_held_in_reach(PickupType::Cargo, player_pos, 10.0), None);
+        assert_eq!(manager.find_held_in_reach(PickupType::Fuel, player_pos, 200.0), None);
+        assert_eq!(manager.find_held_in_reach(PickupType::Cargo, player_pos, 200.0), Some(0));
+    }
+
+    #[test]
+    fn test_push_beam_returns_pickup_to_wall() {
+        use crate::tractor::{TractorBeam, BeamDir};
+
+        let mut manager = PickupManager::new(0);
+        manager.pickups.push(Pickup::new((100.0, 100.0), PickupType::Fuel, true));
+        let player_pos = (100.0, 200.0);
+
+        // Pull the pickup away from the ceiling
+        let mut beam = TractorBeam::new();
+        beam.activate(BeamDir::Up);
+        for _ in 0..10 {
+            manager.update_tractor_beam_attraction(&beam, player_pos, 0.016);
+        }
+        let pulled_y = manager.pickups[0].position.1;
+        assert!(pulled_y > 100.0);
+
+        // Pushing shoves it back but never into the ceiling
+        beam.toggle_polarity();
+        for _ in 0..60 {
+            manager.update_tractor_beam_attraction(&beam, player_pos, 0.016);
+        }
+        assert!(manager.pickups[0].position.1 < pulled_y);
+        assert_eq!(manager.pickups[0].position.1, 100.0);
     }
 
     #[test]
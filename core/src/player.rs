@@ -1,3 +1,5 @@
+use crate::fuel::Fuel;
+
 /// 2D vector for position and velocity calculations.
 ///
 /// Simple structure following the principle of least surprise.
@@ -17,9 +19,18 @@ impl Vec2 {
     pub const ZERO: Vec2 = Vec2 { x: 0.0, y: 0.0 };
 }
 
+/// Analog thruster levels for a single frame, each in [0, 1].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Throttle {
+    pub up: f32,
+    pub down: f32,
+    pub right: f32,
+}
+
 /// Player input state for a single frame.
 ///
-/// Contains only boolean flags for clean input handling.
+/// Boolean flags for keys, plus an optional analog throttle that takes
+/// over from the thruster flags when an analog device drives the ship.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct PlayerInput {
     pub up: bool,
@@ -28,6 +39,31 @@ pub struct PlayerInput {
     pub right: bool,
     pub tractor_up: bool,   // W key for upward tractor beam
     pub tractor_down: bool, // S key for downward tractor beam
+    pub tractor_polarity: bool, // E key to toggle beam pull/push
+    pub tractor_aim: Option<f32>, // Beam aim angle in radians while the mouse aims
+    pub tractor_fire: bool, // Fires the beam toward the aim angle
+    pub throttle: Option<Throttle>, // Analog thruster levels; None for digital input
+}
+
+impl PlayerInput {
+    /// Gets the thruster levels, treating pressed keys as full throttle.
+    ///
+    /// Analog levels are clamped to [0, 1].
+    pub fn throttle_levels(&self) -> Throttle {
+        let level = |pressed: bool| if pressed { 1.0 } else { 0.0 };
+        match self.throttle {
+            Some(throttle) => Throttle {
+                up: throttle.up.clamp(0.0, 1.0),
+                down: throttle.down.clamp(0.0, 1.0),
+                right: throttle.right.clamp(0.0, 1.0),
+            },
+            None => Throttle {
+                up: level(self.up),
+                down: level(self.down),
+                right: level(self.right),
+            },
+        }
+    }
 }
 
 /// Player physics constants.
@@ -40,6 +76,9 @@ impl PlayerConstants {
     pub const THRUST: f32 = -400.0; // pixels/sec² (negative = upward)
     pub const MAX_HORIZONTAL_SPEED: f32 = 200.0; // pixels/sec
     pub const DOWN_THRUST_MULTIPLIER: f32 = 0.5;
+    pub const WIDTH: f32 = 30.0; // pixels
+    pub const HEIGHT: f32 = 18.0; // pixels
+    pub const MASS: f32 = 1.0; // ship mass units
 }
 
 /// Player entity with position and velocity.
@@ -50,6 +89,10 @@ impl PlayerConstants {
 pub struct Player {
     pub pos: Vec2,
     pub vel: Vec2,
+    /// Extra mass carried by the ship (e.g. cargo), in ship mass units
+    pub payload_mass: f32,
+    /// Mass of the fuel in the tank, in ship mass units
+    pub fuel_mass: f32,
 }
 
 impl Player {
@@ -58,6 +101,8 @@ impl Player {
         Self {
             pos,
             vel: Vec2::ZERO,
+            payload_mass: 0.0,
+            fuel_mass: 0.0,
         }
     }
 
@@ -78,15 +123,32 @@ impl Player {
         self.vel.y += PlayerConstants::GRAVITY * dt;
     }
 
+    /// Gets the total mass of ship, payload and fuel.
+    pub fn mass(&self) -> f32 {
+        PlayerConstants::MASS + self.payload_mass + self.fuel_mass
+    }
+
+    /// Sets the fuel mass from the tank's current level.
+    ///
+    /// A full tank makes the ship sluggish; a nearly empty one makes it twitchy.
+    pub fn load_fuel(&mut self, fuel: &Fuel) {
+        self.fuel_mass = fuel.mass();
+    }
+
+    /// Gets the fraction of full acceleration left with the current payload.
+    fn acceleration_factor(&self) -> f32 {
+        PlayerConstants::MASS / self.mass()
+    }
+
     /// Applies thrust based on input.
+    ///
+    /// Thrust scales with the throttle level. A heavier ship accelerates more slowly.
     fn apply_thrust(&mut self, dt: f32, input: PlayerInput) {
-        if input.up {
-            self.vel.y += PlayerConstants::THRUST * dt;
-        }
+        let factor = self.acceleration_factor();
+        let throttle = input.throttle_levels();
 
-        if input.down {
-            self.vel.y += -PlayerConstants::THRUST * PlayerConstants::DOWN_THRUST_MULTIPLIER * dt;
-        }
+        self.vel.y += PlayerConstants::THRUST * throttle.up * factor * dt;
+        self.vel.y += -PlayerConstants::THRUST * PlayerConstants::DOWN_THRUST_MULTIPLIER * throttle.down * factor * dt;
     }
 
     /// Applies horizontal movement with speed clamping.
@@ -107,12 +169,14 @@ impl Player {
         let at_right_boundary = screen_x >= MAX_SCREEN_X;
         
         // Apply acceleration based on input, but only if not pushing against boundary
+        let acceleration = HORIZONTAL_ACCELERATION * self.acceleration_factor();
         if input.left && (!at_left_boundary || self.vel.x > 0.0) {
-            self.vel.x -= HORIZONTAL_ACCELERATION * dt;
+            self.vel.x -= acceleration * dt;
         }
 
-        if input.right && (!at_right_boundary || self.vel.x < 0.0) {
-            self.vel.x += HORIZONTAL_ACCELERATION * dt;
+        let boost = input.throttle_levels().right;
+        if boost > 0.0 && (!at_right_boundary || self.vel.x < 0.0) {
+            self.vel.x += acceleration * boost * dt;
         }
 
         // Clamp horizontal speed
@@ -138,11 +202,35 @@ impl Player {
         self.pos.x += self.vel.x * dt;
         self.pos.y += self.vel.y * dt;
     }
+
+    /// Pushes the player out of a wall and bounces it along the contact normal.
+    ///
+    /// Returns the impact speed (velocity component into the wall).
+    ///
+    /// # Arguments
+    /// * `normal` - Unit contact normal pointing away from the wall
+    /// * `depth` - Penetration depth in pixels
+    /// * `restitution` - Fraction of the impact speed kept after the bounce
+    /// * `min_speed` - Minimum speed away from the wall after the bounce
+    pub fn bounce(&mut self, normal: (f32, f32), depth: f32, restitution: f32, min_speed: f32) -> f32 {
+        self.pos.x += normal.0 * depth;
+        self.pos.y += normal.1 * depth;
+
+        let normal_speed = self.vel.x * normal.0 + self.vel.y * normal.1;
+        let impact_speed = (-normal_speed).max(0.0);
+        let separation_speed = (impact_speed * restitution).max(normal_speed).max(min_speed);
+
+        self.vel.x += normal.0 * (separation_speed - normal_speed);
+        self.vel.y += normal.1 * (separation_speed - normal_speed);
+
+        impact_speed
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::constants::FuelConstants;
 
     #[test]
     fn test_left_boundary_release() {
@@ -213,4 +301,44 @@ mod tests {
         assert_eq!(player.pos.x, 15.0, "Player should stay at left boundary");
         assert_eq!(player.vel.x, 0.0, "Leftward velocity should be stopped");
     }
+
+    #[test]
+    fn test_full_tank_is_sluggish() {
+        let input = PlayerInput {
+            up: true,
+            ..Default::default()
+        };
+        let mut full = Player::new(Vec2::new(100.0, 100.0));
+        let mut empty = Player::new(Vec2::new(100.0, 100.0));
+        let mut tank = Fuel::new(100.0, 10.0);
+        full.load_fuel(&tank);
+        tank.current = 0.0;
+        empty.load_fuel(&tank);
+
+        full.tick(0.016, input, 0.0, 0.0);
+        empty.tick(0.016, input, 0.0, 0.0);
+
+        assert_eq!(full.fuel_mass, FuelConstants::FULL_TANK_MASS);
+        assert_eq!(empty.fuel_mass, 0.0);
+        assert!(full.vel.y.abs() < empty.vel.y.abs());
+    }
+
+    #[test]
+    fn test_payload_slows_thrust() {
+        let input = PlayerInput {
+            up: true,
+            right: true,
+            ..Default::default()
+        };
+        let mut light = Player::new(Vec2::new(100.0, 100.0));
+        let mut heavy = Player::new(Vec2::new(100.0, 100.0));
+        heavy.payload_mass = 1.0;
+
+        light.tick(0.016, input, 0.0, 0.0);
+        heavy.tick(0.016, input, 0.0, 0.0);
+
+        assert_eq!(heavy.mass(), 2.0);
+        assert!(heavy.vel.x < light.vel.x);
+        assert!((heavy.vel.x - light.vel.x / 2.0).abs() < 0.001);
+    }
 }
@@ -0,0 +1,159 @@
+use crate::constants::RespawnConstants;
+use crate::distance::DistanceTracker;
+
+/// Lives remaining in the current run, including the ship in flight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lives {
+    remaining: u32,
+}
+
+impl Lives {
+    /// Creates a lives counter with the given number of ships.
+    pub fn new(count: u32) -> Self {
+        Self { remaining: count }
+    }
+
+    /// Gets the number of ships left.
+    pub fn remaining(&self) -> u32 {
+        self.remaining
+    }
+
+    /// Loses one ship.
+    ///
+    /// Returns true if another ship is available to continue the run.
+    pub fn lose_life(&mut self) -> bool {
+        self.remaining = self.remaining.saturating_sub(1);
+        self.remaining > 0
+    }
+
+    /// Checks if no ships are left.
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == 0
+    }
+}
+
+impl Default for Lives {
+    fn default() -> Self {
+        Self::new(RespawnConstants::STARTING_LIVES)
+    }
+}
+
+/// Snapshot of the run taken at a checkpoint.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Checkpoint {
+    /// Camera offset when the checkpoint was taken
+    pub camera_offset_x: f32,
+    /// Player centre position in world coordinates
+    pub player_pos: (f32, f32),
+    /// Fuel in the tank when the checkpoint was taken
+    pub fuel: f32,
+    /// Level number the checkpoint belongs to
+    pub level_number: u32,
+    /// Elapsed time at which the checkpoint's level started
+    pub level_start_time: f32,
+    /// Distance and elapsed time at the checkpoint
+    pub distance: DistanceTracker,
+}
+
+/// Records checkpoints every fixed distance and at level boundaries.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CheckpointTracker {
+    interval: f32,
+    next_distance: f32,
+    last: Option<Checkpoint>,
+}
+
+impl CheckpointTracker {
+    /// Creates a tracker recording a checkpoint every `interval` pixels.
+    pub fn new(interval: f32) -> Self {
+        Self {
+            interval,
+            next_distance: 0.0,
+            last: None,
+        }
+    }
+
+    /// Checks if an automatic checkpoint is due at the given distance.
+    pub fn is_due(&self, distance: f32) -> bool {
+        distance >= self.next_distance
+    }
+
+    /// Records a checkpoint and schedules the next automatic one.
+    ///
+    /// Called for automatic checkpoints and at level boundaries.
+    pub fn record(&mut self, checkpoint: Checkpoint) {
+        self.next_distance = checkpoint.distance.distance + self.interval;
+        self.last = Some(checkpoint);
+    }
+
+    /// Gets the most recent checkpoint.
+    pub fn last(&self) -> Option<&Checkpoint> {
+        self.last.as_ref()
+    }
+
+    /// Clears all checkpoints for a new run.
+    pub fn reset(&mut self) {
+        self.next_distance = 0.0;
+        self.last = None;
+    }
+}
+
+impl Default for CheckpointTracker {
+    fn default() -> Self {
+        Self::new(RespawnConstants::CHECKPOINT_INTERVAL)
+    }
+}
+
+/// Scripted fly-in moving the ship to its respawn position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlyIn {
+    start: (f32, f32),
+    target: (f32, f32),
+    elapsed: f32,
+    duration: f32,
+}
+
+impl FlyIn {
+    /// Creates a fly-in from `start` to `target` lasting `duration` seconds.
+    pub fn new(start: (f32, f32), target: (f32, f32), duration: f32) -> Self {
+        Self {
+            start,
+            target,
+            elapsed: 0.0,
+            duration,
+        }
+    }
+
+    /// Advances the fly-in.
+    ///
+    /// Returns true once the ship has reached its target.
+    pub fn tick(&mut self, dt: f32) -> bool {
+        self.elapsed = (self.elapsed + dt).min(self.duration);
+        self.is_finished()
+    }
+
+    /// Gets the current ship position, easing out toward the target.
+    pub fn position(&self) -> (f32, f32) {
+        let t = self.progress();
+        let eased = 1.0 - (1.0 - t) * (1.0 - t);
+
+        (
+            self.start.0 + (self.target.0 - self.start.0) * eased,
+            self.start.1 + (self.target.1 - self.start.1) * eased,
+        )
+    }
+
+    /// Gets the fly-in progress from 0.0 to 1.0.
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            1.0
+        } else {
+            self.elapsed / self.duration
+        }
+    }
+
+    /// Checks if the fly-in has finished.
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
@@ -0,0 +1,167 @@
+use crate::combo::ComboReward;
+use crate::distance::DistanceTracker;
+use crate::game_state::DeathCause;
+use crate::pickup::PickupType;
+use crate::score::ScoreBreakdown;
+
+/// Something that happened during a run.
+///
+/// The simulation pushes these as they happen; scoring and other
+/// bookkeeping consume them, so they work the same with or without a window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RunEvent {
+    /// A pickup was collected
+    PickupCollected {
+        /// Kind of pickup
+        pickup_type: PickupType,
+        /// Whether the tractor beam brought it in
+        beam_catch: bool,
+    },
+    /// The ship passed close to a wall or obstacle without touching it
+    NearMiss {
+        /// Streak multiplier for consecutive near-misses
+        multiplier: u32,
+    },
+    /// A pickup extended a combo
+    Combo {
+        /// Pickups chained so far
+        count: u32,
+        /// Combo multiplier applied to the pickup
+        multiplier: u32,
+        /// Milestone reward, if this pickup reached one
+        reward: Option<ComboReward>,
+    },
+    /// A level was completed
+    LevelCleared {
+        /// Number of the level that was cleared
+        level_number: u32,
+        /// Fuel ratio left at the end of the level
+        fuel_ratio: f32,
+    },
+    /// A cargo pod reached a drop-off platform
+    CargoDelivered {
+        /// Whether this delivery met the level's goal
+        goal_met: bool,
+    },
+    /// A pickup scrolled past uncollected
+    PickupMissed {
+        /// Kind of pickup
+        pickup_type: PickupType,
+    },
+    /// Fuel was used up by thrust, the beam or idling
+    FuelBurned {
+        /// Fuel units burned
+        amount: f32,
+    },
+    /// Fuel was added from a depot, a landing pad or a combo reward
+    Refuelled {
+        /// Fuel units added
+        amount: f32,
+    },
+    /// The tractor beam was switched on
+    BeamFired,
+    /// One simulation step of flight
+    Flight {
+        /// Step length in seconds
+        dt: f32,
+        /// Distance scrolled during the step, in pixels
+        distance: f32,
+        /// Whether any thruster fired during the step
+        thrusting: bool,
+        /// Ship speed in pixels per second
+        speed: f32,
+        /// Distance from the ship to the nearer wall, if known
+        wall_clearance: Option<f32>,
+    },
+    /// A ship was destroyed
+    ShipLost {
+        /// What destroyed it
+        cause: DeathCause,
+    },
+}
+
+/// Run event queue for collecting events during a frame.
+#[derive(Debug, Default)]
+pub struct RunEventQueue {
+    events: Vec<RunEvent>,
+}
+
+impl RunEventQueue {
+    /// Creates a new empty run event queue.
+    pub fn new() -> Self {
+        Self { events: Vec::new() }
+    }
+
+    /// Adds a run event to the queue.
+    pub fn push(&mut self, event: RunEvent) {
+        self.events.push(event);
+    }
+
+    /// Consumes and returns all queued events.
+    pub fn drain(&mut self) -> Vec<RunEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Checks if the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+/// Outcome of a finished run.
+///
+/// Built when the last ship is lost or the run is completed, and read by
+/// the game-over and victory screens.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RunResult {
+    /// What destroyed the final ship, or `None` if the run was completed
+    pub death_cause: Option<DeathCause>,
+    /// Distance covered in pixels
+    pub distance: f32,
+    /// Time survived in seconds
+    pub elapsed_time: f32,
+    /// Level reached (1-based)
+    pub level_number: u32,
+    /// Final score with its breakdown
+    pub score: ScoreBreakdown,
+}
+
+impl RunResult {
+    /// Creates a run result from the final tracker state.
+    pub fn new(death_cause: DeathCause, distance_tracker: &DistanceTracker, level_number: u32) -> Self {
+        Self {
+            death_cause: Some(death_cause),
+            distance: distance_tracker.distance,
+            elapsed_time: distance_tracker.elapsed_time(),
+            level_number,
+            score: ScoreBreakdown::default(),
+        }
+    }
+
+    /// Creates a result for a run that reached its goal.
+    pub fn completed(distance_tracker: &DistanceTracker, level_number: u32) -> Self {
+        Self {
+            death_cause: None,
+            distance: distance_tracker.distance,
+            elapsed_time: distance_tracker.elapsed_time(),
+            level_number,
+            score: ScoreBreakdown::default(),
+        }
+    }
+
+    /// Checks if the run reached its goal rather than losing its last ship.
+    pub fn is_completed(&self) -> bool {
+        self.death_cause.is_none()
+    }
+
+    /// Attaches the final score breakdown.
+    pub fn with_score(mut self, score: ScoreBreakdown) -> Self {
+        self.score = score;
+        self
+    }
+
+    /// Gets the distance as an integer for display.
+    pub fn distance_as_int(&self) -> u32 {
+        self.distance as u32
+    }
+}
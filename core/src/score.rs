@@ -0,0 +1,183 @@
+use crate::combo::ComboReward;
+use crate::constants::{CargoConstants, GameModeConstants, ScoreConstants};
+use crate::mode::GameMode;
+use crate::run::RunEvent;
+
+/// Points earned from each source during a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ScoreBreakdown {
+    /// Points for distance covered
+    pub distance: u32,
+    /// Points for collected pickups
+    pub pickups: u32,
+    /// Extra points for pickups caught with the tractor beam
+    pub beam_catches: u32,
+    /// Points for near-misses
+    pub near_misses: u32,
+    /// Points for pickup combos and combo milestones
+    pub combos: u32,
+    /// Points for fuel left at the end of each level
+    pub fuel_bonus: u32,
+    /// Points for clearing levels
+    pub level_bonus: u32,
+    /// Points for delivered cargo and met delivery goals
+    pub cargo: u32,
+    /// Points for completing the run: campaign victory or time attack finish
+    pub finish_bonus: u32,
+}
+
+impl ScoreBreakdown {
+    /// Gets the total score.
+    pub fn total(&self) -> u32 {
+        self.distance
+            + self.pickups
+            + self.beam_catches
+            + self.near_misses
+            + self.combos
+            + self.fuel_bonus
+            + self.level_bonus
+            + self.cargo
+            + self.finish_bonus
+    }
+
+    /// Gets labelled lines for display, in a fixed order.
+    pub fn lines(&self) -> [(&'static str, u32); 9] {
+        [
+            ("Distance", self.distance),
+            ("Pickups", self.pickups),
+            ("Beam catches", self.beam_catches),
+            ("Near misses", self.near_misses),
+            ("Combos", self.combos),
+            ("Fuel bonus", self.fuel_bonus),
+            ("Level bonus", self.level_bonus),
+            ("Cargo", self.cargo),
+            ("Finish bonus", self.finish_bonus),
+        ]
+    }
+}
+
+/// Score keeper driven by run events.
+///
+/// Holds no references to the game world, so a headless simulation can
+/// score a run by feeding it the same events.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Score {
+    breakdown: ScoreBreakdown,
+    /// Furthest distance seen, in pixels
+    best_distance: f32,
+    /// Rules deciding which sources earn points
+    mode: GameMode,
+}
+
+impl Score {
+    /// Creates an empty score with endless scoring.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty score for a game mode.
+    ///
+    /// Time attack only scores the finish time. Zen scores skill (pickups,
+    /// near-misses and combos) but not distance or levels, which cost nothing
+    /// without fuel drain or death.
+    pub fn for_mode(mode: GameMode) -> Self {
+        Self {
+            mode,
+            ..Self::default()
+        }
+    }
+
+    /// Gets the game mode the score follows.
+    pub fn mode(&self) -> GameMode {
+        self.mode
+    }
+
+    /// Applies one run event.
+    pub fn apply(&mut self, event: &RunEvent) {
+        if self.mode == GameMode::TimeAttack {
+            return;
+        }
+        match *event {
+            RunEvent::PickupCollected { beam_catch, .. } => {
+                self.breakdown.pickups += ScoreConstants::PICKUP_POINTS;
+                if beam_catch {
+                    self.breakdown.beam_catches += ScoreConstants::BEAM_CATCH_BONUS;
+                }
+            }
+            RunEvent::NearMiss { multiplier } => {
+                self.breakdown.near_misses += ScoreConstants::NEAR_MISS_POINTS * multiplier.max(1);
+            }
+            RunEvent::Combo { multiplier, reward, .. } => {
+                self.breakdown.combos += ScoreConstants::COMBO_STEP_POINTS * multiplier.saturating_sub(1);
+                if let Some(ComboReward::Points(points)) = reward {
+                    self.breakdown.combos += points;
+                }
+            }
+            RunEvent::LevelCleared { level_number, fuel_ratio } if self.mode != GameMode::Zen => {
+                let fuel_bonus = ScoreConstants::FUEL_BONUS * fuel_ratio.clamp(0.0, 1.0);
+                self.breakdown.fuel_bonus += fuel_bonus.round() as u32;
+                self.breakdown.level_bonus += ScoreConstants::LEVEL_CLEAR_BONUS * level_number;
+            }
+            RunEvent::CargoDelivered { goal_met } => {
+                self.breakdown.cargo += CargoConstants::DELIVERY_POINTS;
+                if goal_met {
+                    self.breakdown.cargo += CargoConstants::GOAL_BONUS;
+                }
+            }
+            // Statistics only; worth no points
+            RunEvent::LevelCleared { .. }
+            | RunEvent::PickupMissed { .. }
+            | RunEvent::FuelBurned { .. }
+            | RunEvent::Refuelled { .. }
+            | RunEvent::BeamFired
+            | RunEvent::Flight { .. }
+            | RunEvent::ShipLost { .. } => {}
+        }
+    }
+
+    /// Updates the distance points.
+    ///
+    /// Only the furthest distance counts, so rewinding to a checkpoint
+    /// does not cost points.
+    ///
+    /// # Arguments
+    /// * `distance` - Distance covered in pixels
+    pub fn record_distance(&mut self, distance: f32) {
+        if matches!(self.mode, GameMode::TimeAttack | GameMode::Zen) {
+            return;
+        }
+        if distance > self.best_distance {
+            self.best_distance = distance;
+            self.breakdown.distance = (distance * ScoreConstants::POINTS_PER_PIXEL) as u32;
+        }
+    }
+
+    /// Awards the bonus for completing the run.
+    ///
+    /// Campaign earns a fixed victory bonus; time attack earns par points
+    /// scaled by how much faster than par the finish was. Other modes have
+    /// no finish and earn nothing.
+    ///
+    /// # Arguments
+    /// * `elapsed_time` - Run time in seconds
+    pub fn finish(&mut self, elapsed_time: f32) {
+        self.breakdown.finish_bonus = match self.mode {
+            GameMode::Campaign => ScoreConstants::CAMPAIGN_VICTORY_BONUS,
+            GameMode::TimeAttack => {
+                let par = ScoreConstants::TIME_ATTACK_PAR_POINTS * GameModeConstants::TIME_ATTACK_PAR_SECONDS;
+                (par / elapsed_time.max(1.0)).round() as u32
+            }
+            GameMode::Endless | GameMode::Zen => 0,
+        };
+    }
+
+    /// Gets the points per source.
+    pub fn breakdown(&self) -> ScoreBreakdown {
+        self.breakdown
+    }
+
+    /// Gets the total score.
+    pub fn total(&self) -> u32 {
+        self.breakdown.total()
+    }
+}
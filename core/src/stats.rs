@@ -0,0 +1,328 @@
+use crate::constants::StatsConstants;
+use crate::game_state::DeathCause;
+use crate::run::RunEvent;
+use crate::storage::{Storage, StorageError, StorageResult};
+
+/// Statistics for a single run, gathered from run events.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RunStats {
+    /// Fuel units burned
+    pub fuel_burned: f32,
+    /// Fuel units added from depots, pads and combo rewards
+    pub fuel_collected: f32,
+    /// Pickups collected
+    pub pickups_collected: u32,
+    /// Pickups that scrolled past uncollected
+    pub pickups_missed: u32,
+    /// Times the tractor beam was switched on
+    pub beams_fired: u32,
+    /// Pickups caught with the tractor beam
+    pub beam_catches: u32,
+    /// Seconds spent in each level; the last entry is the level in progress
+    pub level_times: Vec<f32>,
+    /// Highest ship speed in pixels per second
+    pub max_speed: f32,
+    /// Closest the ship came to a wall, in pixels
+    pub closest_wall: Option<f32>,
+    /// Ships destroyed
+    pub ships_lost: u32,
+    /// What destroyed the most recent ship
+    pub death_cause: Option<DeathCause>,
+}
+
+impl RunStats {
+    /// Creates empty statistics.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies one run event.
+    pub fn apply(&mut self, event: &RunEvent) {
+        match *event {
+            RunEvent::PickupCollected { beam_catch, .. } => {
+                self.pickups_collected += 1;
+                if beam_catch {
+                    self.beam_catches += 1;
+                }
+            }
+            RunEvent::PickupMissed { .. } => self.pickups_missed += 1,
+            RunEvent::FuelBurned { amount } => self.fuel_burned += amount,
+            RunEvent::Refuelled { amount } => self.fuel_collected += amount,
+            RunEvent::BeamFired => self.beams_fired += 1,
+            RunEvent::Flight { dt, speed, wall_clearance, .. } => {
+                if self.level_times.is_empty() {
+                    self.level_times.push(0.0);
+                }
+                if let Some(current) = self.level_times.last_mut() {
+                    *current += dt;
+                }
+                self.max_speed = self.max_speed.max(speed);
+                if let Some(clearance) = wall_clearance {
+                    let closest = self.closest_wall.map_or(clearance, |closest| closest.min(clearance));
+                    self.closest_wall = Some(closest.max(0.0));
+                }
+            }
+            RunEvent::LevelCleared { .. } => {
+                if self.level_times.is_empty() {
+                    self.level_times.push(0.0);
+                }
+                self.level_times.push(0.0);
+            }
+            RunEvent::ShipLost { cause } => {
+                self.ships_lost += 1;
+                self.death_cause = Some(cause);
+            }
+            RunEvent::NearMiss { .. } | RunEvent::Combo { .. } | RunEvent::CargoDelivered { .. } => {}
+        }
+    }
+
+    /// Gets the number of pickups that came past, collected or not.
+    pub fn pickups_seen(&self) -> u32 {
+        self.pickups_collected + self.pickups_missed
+    }
+
+    /// Gets the number of levels completed.
+    pub fn levels_cleared(&self) -> u32 {
+        self.level_times.len().saturating_sub(1) as u32
+    }
+
+    /// Gets the total flight time in seconds.
+    pub fn elapsed_time(&self) -> f32 {
+        self.level_times.iter().sum()
+    }
+
+    /// Gets labelled lines for display, in a fixed order.
+    pub fn lines(&self) -> Vec<(String, String)> {
+        let level_times = if self.level_times.is_empty() {
+            "-".to_string()
+        } else {
+            self.level_times
+                .iter()
+                .map(|seconds| format!("{:.1}s", seconds))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        vec![
+            ("Fuel burned".to_string(), format!("{:.1}", self.fuel_burned)),
+            ("Fuel collected".to_string(), format!("{:.1}", self.fuel_collected)),
+            (
+                "Pickups collected".to_string(),
+                format!("{}/{}", self.pickups_collected, self.pickups_seen()),
+            ),
+            ("Beams fired".to_string(), self.beams_fired.to_string()),
+            ("Beam catches".to_string(), self.beam_catches.to_string()),
+            ("Level times".to_string(), level_times),
+            ("Max speed".to_string(), format!("{:.0} px/s", self.max_speed)),
+            ("Closest wall".to_string(), format_clearance(self.closest_wall)),
+            ("Ships lost".to_string(), self.ships_lost.to_string()),
+            (
+                "Cause of death".to_string(),
+                self.death_cause.map_or("-".to_string(), |cause| cause.to_string()),
+            ),
+        ]
+    }
+
+    /// Gets the statistics as a plain-text report, one line per statistic.
+    pub fn report(&self) -> String {
+        format_report(&self.lines())
+    }
+}
+
+/// Totals over every finished run, persisted between sessions.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LifetimeStats {
+    /// Runs finished
+    pub runs: u32,
+    /// Total flight time in seconds
+    pub play_time: f32,
+    /// Levels completed
+    pub levels_cleared: u32,
+    /// Fuel units burned
+    pub fuel_burned: f32,
+    /// Fuel units added from depots, pads and combo rewards
+    pub fuel_collected: f32,
+    /// Pickups collected
+    pub pickups_collected: u32,
+    /// Pickups that scrolled past uncollected
+    pub pickups_missed: u32,
+    /// Times the tractor beam was switched on
+    pub beams_fired: u32,
+    /// Pickups caught with the tractor beam
+    pub beam_catches: u32,
+    /// Highest ship speed in pixels per second
+    pub max_speed: f32,
+    /// Closest the ship came to a wall, in pixels
+    pub closest_wall: Option<f32>,
+    /// Ships destroyed, per cause in `DeathCause::ALL` order
+    deaths: [u32; DeathCause::ALL.len()],
+}
+
+impl LifetimeStats {
+    /// Creates empty lifetime statistics.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a finished run to the totals.
+    pub fn add_run(&mut self, run: &RunStats) {
+        self.runs += 1;
+        self.play_time += run.elapsed_time();
+        self.levels_cleared += run.levels_cleared();
+        self.fuel_burned += run.fuel_burned;
+        self.fuel_collected += run.fuel_collected;
+        self.pickups_collected += run.pickups_collected;
+        self.pickups_missed += run.pickups_missed;
+        self.beams_fired += run.beams_fired;
+        self.beam_catches += run.beam_catches;
+        self.max_speed = self.max_speed.max(run.max_speed);
+        self.closest_wall = match (self.closest_wall, run.closest_wall) {
+            (Some(lifetime), Some(run)) => Some(lifetime.min(run)),
+            (lifetime, run) => lifetime.or(run),
+        };
+        if let Some(cause) = run.death_cause {
+            self.deaths[Self::death_index(cause)] += 1;
+        }
+    }
+
+    /// Gets the number of runs that ended with the given cause.
+    pub fn deaths(&self, cause: DeathCause) -> u32 {
+        self.deaths[Self::death_index(cause)]
+    }
+
+    /// Gets labelled lines for display, in a fixed order.
+    pub fn lines(&self) -> Vec<(String, String)> {
+        let mut lines = vec![
+            ("Runs".to_string(), self.runs.to_string()),
+            ("Play time".to_string(), format!("{:.0}s", self.play_time)),
+            ("Levels cleared".to_string(), self.levels_cleared.to_string()),
+            ("Fuel burned".to_string(), format!("{:.0}", self.fuel_burned)),
+            ("Fuel collected".to_string(), format!("{:.0}", self.fuel_collected)),
+            (
+                "Pickups collected".to_string(),
+                format!("{}/{}", self.pickups_collected, self.pickups_collected + self.pickups_missed),
+            ),
+            ("Beams fired".to_string(), self.beams_fired.to_string()),
+            ("Beam catches".to_string(), self.beam_catches.to_string()),
+            ("Top speed".to_string(), format!("{:.0} px/s", self.max_speed)),
+            ("Closest wall".to_string(), format_clearance(self.closest_wall)),
+        ];
+        for cause in DeathCause::ALL {
+            lines.push((format!("Deaths: {}", cause), self.deaths(cause).to_string()));
+        }
+        lines
+    }
+
+    /// Gets the totals as a plain-text report, one line per statistic.
+    pub fn report(&self) -> String {
+        format_report(&self.lines())
+    }
+
+    /// Writes the totals as `key=value` lines.
+    pub fn to_text(&self) -> String {
+        let mut text = format!(
+            "runs={}\nplay_time={}\nlevels_cleared={}\nfuel_burned={}\nfuel_collected={}\n\
+             pickups_collected={}\npickups_missed={}\nbeams_fired={}\nbeam_catches={}\nmax_speed={}\n",
+            self.runs,
+            self.play_time,
+            self.levels_cleared,
+            self.fuel_burned,
+            self.fuel_collected,
+            self.pickups_collected,
+            self.pickups_missed,
+            self.beams_fired,
+            self.beam_catches,
+            self.max_speed,
+        );
+        if let Some(closest) = self.closest_wall {
+            text.push_str(&format!("closest_wall={}\n", closest));
+        }
+        for cause in DeathCause::ALL {
+            text.push_str(&format!("deaths.{}={}\n", cause, self.deaths(cause)));
+        }
+        text
+    }
+
+    /// Reads totals written by `to_text`.
+    ///
+    /// Unknown keys are skipped so older builds can read newer records.
+    pub fn from_text(text: &str) -> StorageResult<Self> {
+        let mut stats = Self::default();
+        for (index, line) in text.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            stats
+                .read_line(line.trim())
+                .map_err(|reason| StorageError::Corrupt {
+                    key: StatsConstants::STORAGE_KEY.to_string(),
+                    reason: format!("line {}: {}", index + 1, reason),
+                })?;
+        }
+        Ok(stats)
+    }
+
+    /// Loads the totals from storage, or empty totals if none were saved.
+    pub fn load(storage: &dyn Storage) -> StorageResult<Self> {
+        match storage.load(StatsConstants::STORAGE_KEY)? {
+            Some(text) => Self::from_text(&text),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Saves the totals to storage.
+    pub fn save(&self, storage: &mut dyn Storage) -> StorageResult<()> {
+        storage.save(StatsConstants::STORAGE_KEY, &self.to_text())
+    }
+
+    /// Reads one `key=value` line into the totals.
+    fn read_line(&mut self, line: &str) -> Result<(), String> {
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("expected key=value, found '{}'", line))?;
+        let count = || value.parse::<u32>().map_err(|e| format!("{}: {}", key, e));
+        let amount = || value.parse::<f32>().map_err(|e| format!("{}: {}", key, e));
+
+        match key {
+            "runs" => self.runs = count()?,
+            "play_time" => self.play_time = amount()?,
+            "levels_cleared" => self.levels_cleared = count()?,
+            "fuel_burned" => self.fuel_burned = amount()?,
+            "fuel_collected" => self.fuel_collected = amount()?,
+            "pickups_collected" => self.pickups_collected = count()?,
+            "pickups_missed" => self.pickups_missed = count()?,
+            "beams_fired" => self.beams_fired = count()?,
+            "beam_catches" => self.beam_catches = count()?,
+            "max_speed" => self.max_speed = amount()?,
+            "closest_wall" => self.closest_wall = Some(amount()?),
+            _ => {
+                let cause = key.strip_prefix("deaths.").and_then(DeathCause::from_name);
+                if let Some(cause) = cause {
+                    self.deaths[Self::death_index(cause)] = count()?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Gets the slot of a death cause in the per-cause counts.
+    fn death_index(cause: DeathCause) -> usize {
+        match cause {
+            DeathCause::CeilingCrash => 0,
+            DeathCause::FloorCrash => 1,
+            DeathCause::Obstacle => 2,
+            DeathCause::Hazard => 3,
+            DeathCause::FuelExhausted => 4,
+        }
+    }
+}
+
+/// Formats a wall clearance, or a dash if none was measured.
+fn format_clearance(clearance: Option<f32>) -> String {
+    clearance.map_or("-".to_string(), |clearance| format!("{:.1} px", clearance))
+}
+
+/// Joins labelled lines into `label: value` text.
+fn format_report(lines: &[(String, String)]) -> String {
+    lines
+        .iter()
+        .map(|(label, value)| format!("{}: {}\n", label, value))
+        .collect()
+}
@@ -0,0 +1,164 @@
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+/// Errors that can occur when loading or saving persistent data
+#[derive(Debug, Clone, PartialEq)]
+pub enum StorageError {
+    /// The backend could not read or write a record
+    Io(String),
+    /// A stored record could not be parsed
+    Corrupt {
+        /// Key of the record
+        key: String,
+        /// What was wrong with it
+        reason: String,
+    },
+}
+
+impl std::fmt::Display for StorageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StorageError::Io(message) => write!(f, "Storage I/O error: {}", message),
+            StorageError::Corrupt { key, reason } => {
+                write!(f, "Corrupt record '{}': {}", key, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for StorageError {}
+
+/// Result type for storage operations
+pub type StorageResult<T> = Result<T, StorageError>;
+
+/// Key-value store for small text records that outlive the process.
+///
+/// The game picks a backend per platform; everything stored goes through
+/// this trait so the records themselves stay platform independent.
+pub trait Storage {
+    /// Loads the record stored under `key`, or `None` if there is none.
+    fn load(&self, key: &str) -> StorageResult<Option<String>>;
+
+    /// Stores `data` under `key`, replacing any previous record.
+    fn save(&mut self, key: &str, data: &str) -> StorageResult<()>;
+}
+
+/// Storage that keeps records in memory only.
+///
+/// Used by tests and as a fallback when no persistent backend is available.
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    records: HashMap<String, String>,
+}
+
+impl MemoryStorage {
+    /// Creates an empty memory storage.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn load(&self, key: &str) -> StorageResult<Option<String>> {
+        Ok(self.records.get(key).cloned())
+    }
+
+    fn save(&mut self, key: &str, data: &str) -> StorageResult<()> {
+        self.records.insert(key.to_string(), data.to_string());
+        Ok(())
+    }
+}
+
+/// Storage that keeps one text file per key in a directory.
+#[derive(Debug, Clone)]
+pub struct FileStorage {
+    dir: PathBuf,
+}
+
+impl FileStorage {
+    /// Creates a file storage in the given directory.
+    ///
+    /// The directory is created on the first save.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Gets the path of the file holding a key.
+    pub fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.txt", key))
+    }
+}
+
+impl Storage for FileStorage {
+    fn load(&self, key: &str) -> StorageResult<Option<String>> {
+        match std::fs::read_to_string(self.path(key)) {
+            Ok(data) => Ok(Some(data)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(StorageError::Io(e.to_string())),
+        }
+    }
+
+    fn save(&mut self, key: &str, data: &str) -> StorageResult<()> {
+        std::fs::create_dir_all(&self.dir).map_err(|e| StorageError::Io(e.to_string()))?;
+        std::fs::write(self.path(key), data).map_err(|e| StorageError::Io(e.to_string()))
+    }
+}
+
+/// Storage wrapper that refuses to overwrite records it could not read.
+///
+/// When a record fails to load, the game falls back to defaults and would
+/// otherwise replace the user's data on its next save. Protecting the key
+/// first copies the raw record to `<key>.bak`; if even that fails, saves to
+/// the key are refused for the rest of the session.
+pub struct GuardedStorage {
+    inner: Box<dyn Storage>,
+    blocked: HashSet<String>,
+}
+
+impl GuardedStorage {
+    /// Wraps a storage backend.
+    pub fn new(inner: Box<dyn Storage>) -> Self {
+        Self {
+            inner,
+            blocked: HashSet::new(),
+        }
+    }
+
+    /// Gets the key a record's backup is stored under.
+    pub fn backup_key(key: &str) -> String {
+        format!("{}.bak", key)
+    }
+
+    /// Backs up the raw record under `key` before it gets saved over.
+    ///
+    /// On failure the key stays blocked and later saves to it are refused.
+    pub fn protect(&mut self, key: &str) -> StorageResult<()> {
+        self.blocked.insert(key.to_string());
+        if let Some(data) = self.inner.load(key)? {
+            self.inner.save(&Self::backup_key(key), &data)?;
+        }
+        self.blocked.remove(key);
+        Ok(())
+    }
+
+    /// Checks whether saves to a key are refused.
+    pub fn is_blocked(&self, key: &str) -> bool {
+        self.blocked.contains(key)
+    }
+}
+
+impl Storage for GuardedStorage {
+    fn load(&self, key: &str) -> StorageResult<Option<String>> {
+        self.inner.load(key)
+    }
+
+    fn save(&mut self, key: &str, data: &str) -> StorageResult<()> {
+        if self.is_blocked(key) {
+            return Err(StorageError::Io(format!(
+                "refusing to overwrite unreadable record '{}'",
+                key
+            )));
+        }
+        self.inner.save(key, data)
+    }
+}
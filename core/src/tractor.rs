@@ -5,6 +5,13 @@
 /// Manages tractor beam activation, direction, and timing
 /// following the Single Responsibility Principle.
 
+use std::f32::consts::FRAC_PI_2;
+
+use crate::constants::{GrappleConstants, TractorBeamConstants};
+use crate::fuel::Fuel;
+use crate::grapple::{distance, Anchor, Grapple, GrappleStatus};
+use crate::player::Player;
+
 /// Direction of the tractor beam.
 ///
 /// Simple enum for clear beam direction specification.
@@ -14,15 +21,73 @@ pub enum BeamDir {
     Down,
 }
 
-/// Tractor beam with activation state and timer.
+impl BeamDir {
+    /// Gets the beam angle for this direction in radians.
+    pub fn angle(self) -> f32 {
+        match self {
+            BeamDir::Up => -FRAC_PI_2,
+            BeamDir::Down => FRAC_PI_2,
+        }
+    }
+
+    /// Gets the vertical direction an angle points into.
+    ///
+    /// Exactly horizontal angles count as `Down`.
+    pub fn from_angle(angle: f32) -> Self {
+        if angle.sin() < 0.0 {
+            BeamDir::Up
+        } else {
+            BeamDir::Down
+        }
+    }
+}
+
+/// Polarity of the tractor beam.
+///
+/// Pull draws targets toward the ship, push shoves them away.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BeamPolarity {
+    #[default]
+    Pull,
+    Push,
+}
+
+impl BeamPolarity {
+    /// Gets the opposite polarity.
+    pub fn toggled(self) -> Self {
+        match self {
+            BeamPolarity::Pull => BeamPolarity::Push,
+            BeamPolarity::Push => BeamPolarity::Pull,
+        }
+    }
+}
+
+/// Tractor beam with activation state, timer and energy pool.
 ///
 /// Handles beam activation, direction, and automatic deactivation
 /// following clean code principles with low cyclomatic complexity.
+/// Energy drains while the beam is active and recharges while it is off;
+/// draining the pool completely overheats the beam for a short lockout.
+/// The beam is an oriented rectangle that can point at any angle.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct TractorBeam {
     pub active: bool,
+    /// Vertical side the beam faces, derived from the angle
     pub dir: BeamDir,
+    /// Beam angle in radians; 0 points right and positive angles turn
+    /// clockwise on screen (y grows downward)
+    pub angle: f32,
+    pub polarity: BeamPolarity,
     pub timer: f32,
+    pub energy: f32,
+    /// Whether an energy shortfall may be covered from the fuel tank
+    pub fuel_fallback: bool,
+    /// Seconds one activation lasts; difficulty presets scale it
+    pub max_duration: f32,
+    overheat_timer: f32,
+    grapple: Option<Grapple>,
+    latched_this_burst: bool,
+    running_on_fuel: bool,
 }
 
 impl TractorBeam {
@@ -37,33 +102,229 @@ impl TractorBeam {
         Self {
             active: false,
             dir: BeamDir::Up, // Default direction
+            angle: BeamDir::Up.angle(),
+            polarity: BeamPolarity::Pull,
             timer: 0.0,
+            energy: TractorBeamConstants::MAX_ENERGY,
+            fuel_fallback: false,
+            max_duration: Self::MAX_DURATION,
+            overheat_timer: 0.0,
+            grapple: None,
+            latched_this_burst: false,
+            running_on_fuel: false,
         }
     }
 
     /// Activates the tractor beam with specified direction.
     ///
-    /// Only activates if beam is not already active.
+    /// Only activates if beam is not already active and enough energy is
+    /// available outside an overheat lockout.
     /// Sets timer to maximum duration when activated.
     pub fn activate(&mut self, dir: BeamDir) {
-        if !self.active {
+        self.activate_at(dir.angle());
+    }
+
+    /// Activates the tractor beam aimed at an arbitrary angle.
+    ///
+    /// Follows the same rules as `activate`.
+    ///
+    /// # Arguments
+    /// * `angle` - Beam angle in radians (see `angle`)
+    pub fn activate_at(&mut self, angle: f32) {
+        if !self.active && self.can_activate() {
             self.active = true;
-            self.dir = dir;
-            self.timer = Self::MAX_DURATION;
+            self.aim(angle);
+            self.timer = self.max_duration;
+            self.latched_this_burst = false;
         }
     }
 
-    /// Updates the tractor beam timer.
+    /// Points the beam at a new angle.
+    ///
+    /// Can be used while the beam is active to sweep it around.
+    pub fn aim(&mut self, angle: f32) {
+        self.angle = angle;
+        self.dir = BeamDir::from_angle(angle);
+    }
+
+    /// Gets the unit vector the beam points along.
+    ///
+    /// Rounding noise is snapped to zero so cardinal beams stay exact.
+    pub fn direction(&self) -> (f32, f32) {
+        let snap = |value: f32| if value.abs() < 1e-6 { 0.0 } else { value };
+        (snap(self.angle.cos()), snap(self.angle.sin()))
+    }
+
+    /// Updates the tractor beam timer and energy pool.
     ///
     /// Decrements timer and deactivates beam when timer reaches zero.
+    /// Running out of energy deactivates the beam and starts an overheat lockout.
     /// Call this method every frame during game updates.
     pub fn tick(&mut self, dt: f32) {
+        self.overheat_timer = (self.overheat_timer - dt).max(0.0);
+
         if self.active {
-            self.timer -= dt;
-            if self.timer <= 0.0 {
+            let cost = self.cost_multiplier();
+            self.timer -= dt * cost;
+            self.energy = (self.energy - TractorBeamConstants::ENERGY_DRAIN_RATE * cost * dt).max(0.0);
+
+            if self.energy <= 0.0 {
+                self.active = false;
+                self.timer = 0.0;
+                self.overheat_timer = TractorBeamConstants::OVERHEAT_DURATION;
+                self.grapple = None;
+            } else if self.timer <= 0.0 {
                 self.active = false;
                 self.timer = 0.0;
+                self.grapple = None;
             }
+        } else if !self.is_overheated() {
+            self.energy = (self.energy + TractorBeamConstants::ENERGY_RECHARGE_RATE * dt)
+                .min(TractorBeamConstants::MAX_ENERGY);
+        }
+    }
+
+    /// Updates the beam, covering an energy shortfall from the fuel tank.
+    ///
+    /// Only draws fuel when `fuel_fallback` is enabled, and never takes the
+    /// last of it; once fuel cannot cover the drain the beam overheats.
+    /// Frames paid for this way are flagged by `is_running_on_fuel`, so the
+    /// beam's regular fuel consumption is not charged on top.
+    ///
+    /// # Arguments
+    /// * `dt` - Delta time in seconds
+    /// * `fuel` - Fuel tank to draw from
+    pub fn tick_with_fuel(&mut self, dt: f32, fuel: &mut Fuel) {
+        self.running_on_fuel = false;
+        if self.active && self.fuel_fallback {
+            let needed = TractorBeamConstants::ENERGY_DRAIN_RATE * self.cost_multiplier() * dt;
+            let cost = needed * TractorBeamConstants::FUEL_PER_ENERGY;
+
+            // Fuel pays for the whole frame once the pool can no longer cover it
+            if self.energy <= needed && fuel.current > cost {
+                fuel.draw(cost);
+                self.energy += needed;
+                self.running_on_fuel = true;
+            }
+        }
+
+        self.tick(dt);
+    }
+
+    /// Checks if the fuel tank paid for the beam on the last update.
+    pub fn is_running_on_fuel(&self) -> bool {
+        self.running_on_fuel
+    }
+
+    /// Checks if the beam has enough energy and is not overheated.
+    pub fn can_activate(&self) -> bool {
+        !self.is_overheated() && self.energy >= TractorBeamConstants::MIN_ACTIVATION_ENERGY
+    }
+
+    /// Checks if the beam is locked out after draining its energy.
+    pub fn is_overheated(&self) -> bool {
+        self.overheat_timer > 0.0
+    }
+
+    /// Gets the remaining overheat lockout in seconds.
+    pub fn overheat_remaining(&self) -> f32 {
+        self.overheat_timer
+    }
+
+    /// Gets the energy ratio (energy/max) for UI display.
+    pub fn energy_ratio(&self) -> f32 {
+        self.energy / TractorBeamConstants::MAX_ENERGY
+    }
+
+    /// Switches between pull and push.
+    ///
+    /// Can be used while the beam is active. Switching lets go of a grapple.
+    pub fn toggle_polarity(&mut self) {
+        self.polarity = self.polarity.toggled();
+        self.grapple = None;
+    }
+
+    /// Finds the anchor closest to the ship inside the beam.
+    ///
+    /// # Arguments
+    /// * `anchors` - Anchor crystals to search
+    /// * `player_pos` - Current player position
+    pub fn find_anchor(&self, anchors: &[Anchor], player_pos: (f32, f32)) -> Option<Anchor> {
+        anchors
+            .iter()
+            .filter(|a| self.should_maintain_attraction(player_pos, a.position))
+            .min_by(|a, b| {
+                let da = distance(player_pos, a.position);
+                let db = distance(player_pos, b.position);
+                da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .copied()
+    }
+
+    /// Latches a pulling beam onto an anchor crystal.
+    ///
+    /// Ignored unless the beam is active and pulling. Each activation can
+    /// latch once, so a snapped rope does not reattach straight away. While
+    /// latched the beam burns time and energy faster.
+    ///
+    /// # Arguments
+    /// * `anchor` - Anchor position (x, y)
+    /// * `player_pos` - Player position (x, y)
+    pub fn latch(&mut self, anchor: (f32, f32), player_pos: (f32, f32)) {
+        if self.active && self.polarity == BeamPolarity::Pull && !self.latched_this_burst {
+            self.latched_this_burst = true;
+            let grapple = Grapple::new(anchor, player_pos);
+            self.aim(grapple.angle_from(player_pos));
+            self.grapple = Some(grapple);
+        }
+    }
+
+    /// Lets go of the anchor without switching the beam off.
+    pub fn release_grapple(&mut self) {
+        self.grapple = None;
+    }
+
+    /// Checks if the beam is latched onto an anchor.
+    pub fn is_grappling(&self) -> bool {
+        self.grapple.is_some()
+    }
+
+    /// Gets the current grapple, if latched.
+    pub fn grapple(&self) -> Option<&Grapple> {
+        self.grapple.as_ref()
+    }
+
+    /// Reels the ship toward the anchor while latched.
+    ///
+    /// Keeps the beam aimed at the anchor and lets go once the ship arrives
+    /// or the rope snaps. Returns `None` when not grappling.
+    ///
+    /// # Arguments
+    /// * `player` - The ship on the end of the rope
+    /// * `dt` - Delta time in seconds
+    pub fn update_grapple(&mut self, player: &mut Player, dt: f32) -> Option<GrappleStatus> {
+        let grapple = self.grapple.as_mut()?;
+        let mut position = (player.pos.x, player.pos.y);
+        let mut velocity = (player.vel.x, player.vel.y);
+        let status = grapple.update(&mut position, &mut velocity, dt);
+        (player.pos.x, player.pos.y) = position;
+        (player.vel.x, player.vel.y) = velocity;
+        let angle = grapple.angle_from(position);
+
+        if status == GrappleStatus::Holding {
+            self.aim(angle);
+        } else {
+            self.grapple = None;
+        }
+        Some(status)
+    }
+
+    /// Gets the drain multiplier for beam time and energy.
+    fn cost_multiplier(&self) -> f32 {
+        if self.grapple.is_some() {
+            GrappleConstants::BEAM_COST_MULTIPLIER
+        } else {
+            1.0
         }
     }
 
@@ -115,26 +376,37 @@ impl TractorBeam {
             return false;
         }
 
+        let (along, across) = self.beam_coordinates(player_pos, target_pos);
+
+        // Must be in front of the ship, within range and within the beam width
+        along > 0.0 && along <= Self::MAX_RANGE && across.abs() <= beam_width / 2.0
+    }
+
+    /// Splits the offset to a target into distances along and across the beam.
+    fn beam_coordinates(&self, player_pos: (f32, f32), target_pos: (f32, f32)) -> (f32, f32) {
         let dx = target_pos.0 - player_pos.0;
         let dy = target_pos.1 - player_pos.1;
-        
-        // Check if target is in the correct direction
-        let in_direction = match self.dir {
-            BeamDir::Up => dy < 0.0, // Target is above player (lower y)
-            BeamDir::Down => dy > 0.0, // Target is below player (higher y)
-        };
-        
-        if !in_direction {
-            return false;
+        let (dir_x, dir_y) = self.direction();
+
+        (dx * dir_x + dy * dir_y, dy * dir_x - dx * dir_y)
+    }
+
+    /// Gets the beam strength at a point, falling off with range.
+    ///
+    /// Returns 1.0 right at the ship, `FALLOFF_MIN_STRENGTH` at maximum range
+    /// and 0.0 outside the beam.
+    ///
+    /// # Arguments
+    /// * `player_pos` - Player position (x, y)
+    /// * `target_pos` - Target position (x, y)
+    pub fn strength_at(&self, player_pos: (f32, f32), target_pos: (f32, f32)) -> f32 {
+        if !self.is_point_in_beam_area(player_pos, target_pos, TractorBeamConstants::ATTRACTION_HOLD_WIDTH) {
+            return 0.0;
         }
-        
-        // Check if within rectangular beam area
-        let beam_half_width = beam_width / 2.0;
-        let horizontal_distance = dx.abs();
-        let vertical_distance = dy.abs();
-        
-        // Must be within beam width horizontally and within range vertically
-        horizontal_distance <= beam_half_width && vertical_distance <= Self::MAX_RANGE
+
+        let (along, _) = self.beam_coordinates(player_pos, target_pos);
+        let falloff = 1.0 - TractorBeamConstants::FALLOFF_MIN_STRENGTH;
+        1.0 - falloff * along / Self::MAX_RANGE
     }
 
     /// Calculates attraction force for a point within the beam.
@@ -160,6 +432,43 @@ impl TractorBeam {
             (0.0, 0.0)
         }
     }
+
+    /// Calculates the beam force for a point, honouring the polarity.
+    ///
+    /// Returns a normalized direction toward the player when pulling and
+    /// away from the player when pushing.
+    /// Returns (0.0, 0.0) if point is not in beam or beam is inactive.
+    ///
+    /// # Arguments
+    /// * `player_pos` - Player position (x, y)
+    /// * `target_pos` - Target position (x, y)
+    pub fn get_beam_force(&self, player_pos: (f32, f32), target_pos: (f32, f32)) -> (f32, f32) {
+        let force = self.get_attraction_force(player_pos, target_pos);
+        match self.polarity {
+            BeamPolarity::Pull => force,
+            BeamPolarity::Push => (-force.0, -force.1),
+        }
+    }
+
+    /// Calculates the recoil acceleration on the ship while pushing against a wall.
+    ///
+    /// The recoil points back along the beam, away from the wall it is aimed
+    /// at, and grows as the ship gets closer to it. Returns (0.0, 0.0) when
+    /// pulling, inactive, or when the wall is out of range.
+    ///
+    /// # Arguments
+    /// * `wall_distance` - Distance from the ship to the wall along the beam
+    pub fn recoil(&self, wall_distance: f32) -> (f32, f32) {
+        if !self.active || self.polarity == BeamPolarity::Pull || wall_distance > Self::MAX_RANGE {
+            return (0.0, 0.0);
+        }
+
+        let strength = crate::constants::TractorBeamConstants::PUSH_RECOIL
+            * (1.0 - wall_distance.max(0.0) / Self::MAX_RANGE);
+        let (dir_x, dir_y) = self.direction();
+
+        (-dir_x * strength, -dir_y * strength)
+    }
 }
 
 impl Default for TractorBeam {
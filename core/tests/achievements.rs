@@ -0,0 +1,195 @@
+// core/tests/achievements.rs
+
+use core::achievements::{Achievement, AchievementCondition, AchievementTracker, ACHIEVEMENTS};
+use core::constants::{AchievementConstants, WorldConstants};
+use core::game_state::DeathCause;
+use core::pickup::PickupType;
+use core::run::RunEvent;
+use core::storage::{MemoryStorage, Storage};
+
+const DT: f32 = 1.0 / 60.0; // 60 FPS
+
+/// One frame of flight.
+fn flight(thrusting: bool) -> RunEvent {
+    RunEvent::Flight {
+        dt: DT,
+        distance: WorldConstants::SCROLL_SPEED * DT,
+        thrusting,
+        speed: WorldConstants::SCROLL_SPEED,
+        wall_clearance: None,
+    }
+}
+
+/// Applies events and returns the ids unlocked by them.
+fn unlocked_by(tracker: &mut AchievementTracker, events: &[RunEvent]) -> Vec<&'static str> {
+    for event in events {
+        tracker.apply(event);
+    }
+    tracker.drain_unlocked().iter().map(|achievement| achievement.id).collect()
+}
+
+/// Tests that every built-in achievement has a unique id.
+#[test]
+fn registry_ids_are_unique() {
+    for (index, achievement) in ACHIEVEMENTS.iter().enumerate() {
+        assert!(!achievement.id.is_empty());
+        assert!(ACHIEVEMENTS[index + 1..].iter().all(|other| other.id != achievement.id));
+    }
+}
+
+/// Tests that reaching level 4 takes three cleared levels.
+#[test]
+fn reach_level_four() {
+    let mut tracker = AchievementTracker::default();
+    let clear = |level_number| RunEvent::LevelCleared {
+        level_number,
+        fuel_ratio: 0.5,
+    };
+
+    assert!(unlocked_by(&mut tracker, &[clear(1), clear(2)]).is_empty());
+    assert_eq!(unlocked_by(&mut tracker, &[clear(3)]), vec!["deep_diver"]);
+}
+
+/// Tests that only fuel caught with the beam counts towards the harvester.
+#[test]
+fn beam_fuel_catches() {
+    let mut tracker = AchievementTracker::default();
+    let catch = |pickup_type, beam_catch| RunEvent::PickupCollected {
+        pickup_type,
+        beam_catch,
+    };
+
+    let mut events = vec![catch(PickupType::Fuel, true); 9];
+    events.push(catch(PickupType::Fuel, false));
+    events.push(catch(PickupType::Shield, true));
+    assert!(unlocked_by(&mut tracker, &events).is_empty());
+    assert_eq!(tracker.progress().beam_catches(PickupType::Fuel), 9);
+
+    assert_eq!(
+        unlocked_by(&mut tracker, &[catch(PickupType::Fuel, true)]),
+        vec!["beam_harvester"]
+    );
+}
+
+/// Tests that thrusting restarts the glide distance.
+#[test]
+fn glide_without_thrust() {
+    let mut tracker = AchievementTracker::default();
+    // 16 seconds of coasting at the base scroll speed covers 1920 px
+    let coast = vec![flight(false); 960];
+
+    let mut events = coast.clone();
+    events.push(flight(true));
+    events.extend(coast.clone());
+    assert!(unlocked_by(&mut tracker, &events).is_empty());
+
+    let more_coast = vec![flight(false); 40];
+    assert_eq!(unlocked_by(&mut tracker, &more_coast), vec!["glider"]);
+}
+
+/// Tests that a lost ship breaks the glide.
+#[test]
+fn ship_lost_breaks_glide() {
+    let mut tracker = AchievementTracker::default();
+    let mut events = vec![flight(false); 240];
+    events.push(RunEvent::ShipLost {
+        cause: DeathCause::Hazard,
+    });
+    events.extend(vec![flight(false); 240]);
+
+    assert!(unlocked_by(&mut tracker, &events).is_empty());
+}
+
+/// Tests finishing a level almost out of fuel.
+#[test]
+fn clear_level_on_fumes() {
+    let mut tracker = AchievementTracker::default();
+    let clear = |fuel_ratio| RunEvent::LevelCleared {
+        level_number: 1,
+        fuel_ratio,
+    };
+
+    assert!(unlocked_by(&mut tracker, &[clear(0.05)]).is_empty());
+    assert_eq!(unlocked_by(&mut tracker, &[clear(0.04)]), vec!["running_on_fumes"]);
+}
+
+/// Tests near-miss and combo achievements.
+#[test]
+fn near_misses_and_combos() {
+    let mut tracker = AchievementTracker::default();
+
+    let near_misses = vec![RunEvent::NearMiss { multiplier: 1 }; 25];
+    assert_eq!(unlocked_by(&mut tracker, &near_misses), vec!["daredevil"]);
+
+    let combo = RunEvent::Combo {
+        count: 10,
+        multiplier: 5,
+        reward: None,
+    };
+    assert_eq!(unlocked_by(&mut tracker, &[combo]), vec!["chain_reaction"]);
+}
+
+/// Tests that progress is per run but unlocks are kept and only announced once.
+#[test]
+fn unlocks_outlive_the_run() {
+    let mut tracker = AchievementTracker::default();
+    let near_misses = vec![RunEvent::NearMiss { multiplier: 1 }; 25];
+    unlocked_by(&mut tracker, &near_misses);
+
+    tracker.start_run();
+    assert_eq!(tracker.progress().near_misses, 0);
+    assert!(tracker.is_unlocked("daredevil"));
+    assert!(unlocked_by(&mut tracker, &near_misses).is_empty());
+    assert_eq!(tracker.unlocked_count(), 1);
+}
+
+/// Tests that custom registries are checked like the built-in one.
+#[test]
+fn custom_registry() {
+    let registry = vec![Achievement {
+        id: "first_catch",
+        title: "First Catch",
+        description: "Catch a shield cell with the beam",
+        condition: AchievementCondition::BeamCatches {
+            pickup_type: PickupType::Shield,
+            count: 1,
+        },
+    }];
+    let mut tracker = AchievementTracker::new(registry);
+
+    let catch = RunEvent::PickupCollected {
+        pickup_type: PickupType::Shield,
+        beam_catch: true,
+    };
+    assert_eq!(unlocked_by(&mut tracker, &[catch]), vec!["first_catch"]);
+    assert_eq!(tracker.achievements().len(), 1);
+}
+
+/// Tests that unlocks survive a round trip through storage.
+#[test]
+fn save_and_load() {
+    let mut storage = MemoryStorage::new();
+    assert_eq!(AchievementTracker::load(&storage).unwrap().unlocked_count(), 0);
+
+    let mut tracker = AchievementTracker::default();
+    unlocked_by(&mut tracker, &[RunEvent::NearMiss { multiplier: 1 }; 25]);
+    tracker.save(&mut storage).unwrap();
+
+    let loaded = AchievementTracker::load(&storage).unwrap();
+    assert!(loaded.is_unlocked("daredevil"));
+    assert_eq!(loaded.unlocked_count(), 1);
+}
+
+/// Tests that ids no longer in the registry are skipped when loading.
+#[test]
+fn unknown_ids_are_skipped() {
+    let mut storage = MemoryStorage::new();
+    storage
+        .save(AchievementConstants::STORAGE_KEY, "glider\nretired_feat\nglider\n")
+        .unwrap();
+
+    let loaded = AchievementTracker::load(&storage).unwrap();
+    assert!(loaded.is_unlocked("glider"));
+    assert!(!loaded.is_unlocked("retired_feat"));
+    assert_eq!(loaded.unlocked_count(), 1);
+}
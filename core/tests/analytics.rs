@@ -0,0 +1,138 @@
+// core/tests/analytics.rs
+
+use core::analytics::{DeathHeatStrip, DeathLog, DeathRecord};
+use core::constants::AnalyticsConstants;
+use core::difficulty::Difficulty;
+use core::game_state::DeathCause;
+use core::mode::GameMode;
+use core::storage::{MemoryStorage, Storage, StorageError};
+
+/// Builds a log with one death per given (x, seed) pair.
+fn log_from(deaths: &[(f32, u32)]) -> DeathLog {
+    let mut log = DeathLog::default();
+    for &(world_x, seed) in deaths {
+        log.record(DeathRecord::new(world_x, 1, seed, DeathCause::FloorCrash));
+    }
+    log
+}
+
+/// Tests that the oldest records are dropped once the log is full.
+#[test]
+fn log_drops_oldest_records() {
+    let mut log = DeathLog::new(2);
+    log.record(DeathRecord::new(100.0, 1, 1, DeathCause::Hazard));
+    log.record(DeathRecord::new(200.0, 1, 2, DeathCause::Hazard));
+    log.record(DeathRecord::new(300.0, 2, 3, DeathCause::CeilingCrash));
+
+    assert_eq!(log.len(), 2);
+    assert_eq!(log.records()[0].world_x, 200.0);
+    assert_eq!(log.records()[1].cause, DeathCause::CeilingCrash);
+}
+
+/// Tests that records can be filtered by seed.
+#[test]
+fn log_filters_by_seed() {
+    let log = log_from(&[(100.0, 42), (200.0, 7), (300.0, 42)]);
+
+    let xs: Vec<f32> = log.records_for_seed(42).map(|record| record.world_x).collect();
+    assert_eq!(xs, vec![100.0, 300.0]);
+}
+
+/// Tests the CSV export and its round trip.
+#[test]
+fn csv_round_trip() {
+    let mut log = DeathLog::default();
+    log.record(DeathRecord::new(1234.5, 2, 42, DeathCause::FuelExhausted));
+    log.record(DeathRecord::new(80.0, 1, 7, DeathCause::Hazard).with_run(GameMode::Zen, Difficulty::Hard));
+
+    let csv = log.to_csv();
+    assert_eq!(
+        csv,
+        "world_x,level,seed,cause,mode,difficulty\n1234.5,2,42,fuel exhaustion,endless,normal\n80.0,1,7,hazard,zen,hard\n"
+    );
+    assert_eq!(DeathLog::from_csv(&csv, AnalyticsConstants::MAX_RECORDS).unwrap(), log);
+}
+
+/// Tests that records can be filtered to one seed, mode and difficulty.
+#[test]
+fn log_filters_by_run() {
+    let mut log = log_from(&[(100.0, 42), (200.0, 7)]);
+    log.record(DeathRecord::new(300.0, 1, 42, DeathCause::Hazard).with_run(GameMode::Campaign, Difficulty::Normal));
+    log.record(DeathRecord::new(400.0, 1, 42, DeathCause::Hazard).with_run(GameMode::Endless, Difficulty::Insane));
+
+    let xs: Vec<f32> = log
+        .records_for_run(42, GameMode::Endless, Difficulty::Normal)
+        .map(|record| record.world_x)
+        .collect();
+    assert_eq!(xs, vec![100.0]);
+}
+
+/// Tests the JSON export.
+#[test]
+fn json_export() {
+    assert_eq!(DeathLog::default().to_json(), "[]\n");
+
+    let log = log_from(&[(150.0, 3)]);
+    assert_eq!(
+        log.to_json(),
+        "[\n  {\"world_x\":150.0,\"level\":1,\"seed\":3,\"cause\":\"floor crash\",\"mode\":\"endless\",\"difficulty\":\"normal\"}\n]\n"
+    );
+}
+
+/// Tests that malformed rows are reported with their line number.
+#[test]
+fn corrupt_rows_are_reported() {
+    let result = DeathLog::from_csv(
+        "world_x,level,seed,cause,mode,difficulty\n10.0,1,1,hazard,endless,normal\n10.0,1,1,boredom,endless,normal\n",
+        10,
+    );
+
+    match result {
+        Err(StorageError::Corrupt { key, reason }) => {
+            assert_eq!(key, AnalyticsConstants::STORAGE_KEY);
+            assert!(reason.starts_with("line 3"));
+        }
+        other => panic!("expected corrupt record error, got {:?}", other),
+    }
+}
+
+/// Tests that the log survives a round trip through storage.
+#[test]
+fn save_and_load() {
+    let mut storage = MemoryStorage::new();
+    assert!(DeathLog::load(&storage).unwrap().is_empty());
+
+    let log = log_from(&[(500.0, 42), (900.0, 42)]);
+    log.save(&mut storage).unwrap();
+
+    assert!(storage.load(AnalyticsConstants::STORAGE_KEY).unwrap().is_some());
+    assert_eq!(DeathLog::load(&storage).unwrap(), log);
+}
+
+/// Tests that deaths are binned by world x.
+#[test]
+fn heat_strip_bins_deaths() {
+    let log = log_from(&[(10.0, 42), (40.0, 42), (60.0, 42), (-5.0, 42)]);
+    let strip = DeathHeatStrip::from_records(log.records(), 50.0);
+
+    assert_eq!(strip.count_at(25.0), 3);
+    assert_eq!(strip.count_at(75.0), 1);
+    assert_eq!(strip.count_at(500.0), 0);
+    assert_eq!(strip.max_count(), 3);
+    assert_eq!(strip.intensity_at(0.0), 1.0);
+    assert!((strip.intensity_at(50.0) - 1.0 / 3.0).abs() < 0.001);
+}
+
+/// Tests that only non-empty buckets overlapping the range are returned.
+#[test]
+fn heat_strip_buckets_in_range() {
+    let mut strip = DeathHeatStrip::new(50.0);
+    strip.add(20.0);
+    strip.add(420.0);
+    strip.add(430.0);
+    strip.add(1000.0);
+
+    assert_eq!(strip.buckets_in_range(30.0, 830.0), vec![(0.0, 1), (400.0, 2)]);
+    assert!(DeathHeatStrip::new(50.0).buckets_in_range(0.0, 800.0).is_empty());
+    assert_eq!(DeathHeatStrip::new(50.0).intensity_at(0.0), 0.0);
+}
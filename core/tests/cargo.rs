@@ -0,0 +1,137 @@
+// core/tests/cargo.rs
+
+use core::cargo::{CargoHold, CargoMission, DropOff, DropOffManager, Tether, TetherStatus};
+use core::constants::CargoConstants;
+use core::player::{Player, Vec2};
+
+const DT: f32 = 1.0 / 60.0; // 60 FPS
+const EPSILON: f32 = 0.001;
+
+/// Gets the distance from the player to the pod.
+fn tether_span(player: &Player, tether: &Tether) -> f32 {
+    let dx = tether.pod_position.0 - player.pos.x;
+    let dy = tether.pod_position.1 - player.pos.y;
+    (dx * dx + dy * dy).sqrt()
+}
+
+/// Tests that a hanging pod settles below the ship at tether length.
+#[test]
+fn pod_hangs_below_ship() {
+    let mut player = Player::new(Vec2::new(100.0, 200.0));
+    let mut tether = Tether::new((100.0, 210.0), (0.0, 0.0));
+
+    for _ in 0..300 {
+        tether.update(&mut player, DT);
+        player.vel = Vec2::ZERO;
+    }
+
+    assert!(tether.pod_position.1 > player.pos.y);
+    assert!((tether_span(&player, &tether) - CargoConstants::TETHER_LENGTH).abs() < 1.0);
+}
+
+/// Tests that a taut tether drags the ship toward the pod.
+#[test]
+fn taut_tether_pulls_ship() {
+    let mut player = Player::new(Vec2::new(100.0, 200.0));
+    let mut tether = Tether::new((100.0, 200.0 + CargoConstants::TETHER_LENGTH + 20.0), (0.0, 100.0));
+
+    tether.update(&mut player, DT);
+
+    assert!(player.pos.y > 200.0);
+    assert!(player.vel.y > 0.0);
+    assert!(tether_span(&player, &tether) <= CargoConstants::TETHER_LENGTH + EPSILON);
+}
+
+/// Tests that touching a wall loses the pod.
+#[test]
+fn pod_is_lost_on_wall_contact() {
+    let tether = Tether::new((100.0, 295.0), (0.0, 0.0));
+
+    assert_eq!(tether.check_contact(50.0, 300.0, &[]), TetherStatus::Lost);
+    assert_eq!(tether.check_contact(50.0, 400.0, &[]), TetherStatus::Holding);
+}
+
+/// Tests that a pod resting over a platform is delivered.
+#[test]
+fn pod_is_delivered_on_platform() {
+    let platform = DropOff {
+        x: 60.0,
+        y: 300.0 - CargoConstants::PLATFORM_HEIGHT,
+        width: CargoConstants::PLATFORM_WIDTH,
+    };
+    let tether = Tether::new((100.0, 290.0), (0.0, 0.0));
+    assert_eq!(tether.check_contact(50.0, 300.0, &[platform]), TetherStatus::Delivered);
+
+    // Hanging off the edge of the platform still hits the floor
+    let tether = Tether::new((58.0, 295.0), (0.0, 0.0));
+    assert_eq!(tether.check_contact(50.0, 300.0, &[platform]), TetherStatus::Lost);
+}
+
+/// Tests that platforms only spawn when enabled and sit on the floor.
+#[test]
+fn drop_offs_spawn_on_floor_when_enabled() {
+    let mut manager = DropOffManager::new(3);
+    assert!(!manager.should_spawn_drop_off(CargoConstants::INITIAL_SPAWN_DELAY));
+
+    manager.set_enabled(true);
+    let mut x = 0.0;
+    while x < 10000.0 {
+        if manager.should_spawn_drop_off(x) {
+            manager.spawn_drop_off(x, 500.0);
+        }
+        x += 50.0;
+    }
+
+    assert!(manager.drop_offs().len() > 1);
+    for platform in manager.drop_offs() {
+        assert!(platform.x >= CargoConstants::INITIAL_SPAWN_DELAY);
+        assert_eq!(platform.y + CargoConstants::PLATFORM_HEIGHT, 500.0);
+    }
+
+    manager.cleanup_old_drop_offs(10000.0);
+    assert!(manager.drop_offs().len() <= 1);
+    manager.clear_all_drop_offs();
+    assert!(manager.drop_offs().is_empty());
+}
+
+/// Tests that the hold carries one pod at a time and adds its mass to the ship.
+#[test]
+fn hold_carries_one_pod() {
+    let mut hold = CargoHold::new();
+    let mut player = Player::new(Vec2::new(100.0, 200.0));
+
+    assert!(hold.attach((100.0, 220.0), (0.0, 0.0)));
+    assert!(!hold.attach((120.0, 220.0), (0.0, 0.0)));
+    assert!(hold.is_carrying());
+
+    hold.update(&mut player, DT);
+    assert_eq!(player.payload_mass, CargoConstants::POD_MASS);
+
+    assert!(hold.drop_pod().is_some());
+    hold.update(&mut player, DT);
+    assert_eq!(player.payload_mass, 0.0);
+}
+
+/// Tests that missions track deliveries per level and points per run.
+#[test]
+fn mission_tracks_goal_and_points() {
+    let mut mission = CargoMission::new(2);
+    assert!(!mission.is_complete());
+
+    assert!(!mission.deliver());
+    mission.lose();
+    assert!(mission.deliver());
+    assert!(mission.is_complete());
+    assert_eq!(mission.lost, 1);
+    let points = 2 * CargoConstants::DELIVERY_POINTS + CargoConstants::GOAL_BONUS;
+    assert_eq!(mission.points, points);
+
+    // Deliveries beyond the goal earn no second bonus
+    assert!(!mission.deliver());
+    assert_eq!(mission.points, points + CargoConstants::DELIVERY_POINTS);
+
+    mission.start_level(3);
+    assert_eq!(mission.delivered, 0);
+    assert_eq!(mission.lost, 0);
+    assert_eq!(mission.points, points + CargoConstants::DELIVERY_POINTS);
+}
@@ -1,4 +1,6 @@
 use core::cave::{Cave, CaveConstants, CaveSegment, SimpleRng};
+use core::collision::Aabb;
+use core::constants::LandingPadConstants;
 
 const EPSILON: f32 = 0.001;
 
@@ -235,6 +237,25 @@ fn cave_level_configuration() {
     assert_float_eq(level10_segment.gap_height(), CaveConstants::MIN_GAP);
 }
 
+/// Tests that the gap scale narrows every level, including the minimum gap.
+#[test]
+fn cave_gap_scale() {
+    let mut cave = Cave::new(111);
+    cave.set_gap_scale(0.8);
+
+    cave.configure_for_level(1);
+    assert_float_eq(cave.segments()[0].gap_height(), 320.0);
+
+    cave.configure_for_level(6);
+    assert_float_eq(cave.segments()[0].gap_height(), CaveConstants::MIN_GAP * 0.8);
+    assert_float_eq(cave.min_gap(), CaveConstants::MIN_GAP * 0.8);
+
+    cave.segments_in_view(0.0, 3000.0, 500.0);
+    for segment in cave.segments() {
+        assert!(segment.gap_height() >= cave.min_gap() - EPSILON);
+    }
+}
+
 /// Tests that cave remains horizontal with small variations.
 #[test]
 fn cave_remains_horizontal() {
@@ -261,3 +282,106 @@ fn cave_remains_horizontal() {
         );
     }
 }
+
+/// Tests that segments can be looked up by x-coordinate.
+#[test]
+fn cave_segment_lookup() {
+    let mut cave = Cave::new(42);
+    cave.segments_in_view(0.0, 400.0, 1000.0);
+
+    let segment = cave.segment_at(120.0).expect("Segment should exist");
+    assert!(segment.x_start <= 120.0 && 120.0 < segment.x_end());
+    assert!(cave.segment_at(-10.0).is_none());
+    assert!(cave.segment_at(10_000.0).is_none());
+}
+
+/// Tests that vertical rays stop at the ceiling and floor.
+#[test]
+fn cave_raycast_vertical() {
+    let mut cave = Cave::new(42);
+    cave.configure_for_level(1);
+    cave.segments_in_view(0.0, 800.0, 1000.0);
+    let segment = *cave.segment_at(200.0).unwrap();
+
+    let up = cave.raycast((200.0, 300.0), (0.0, -1.0), 1000.0).unwrap();
+    let down = cave.raycast((200.0, 300.0), (0.0, 1.0), 1000.0).unwrap();
+
+    assert_float_eq(up, 300.0 - segment.ceiling);
+    assert_float_eq(down, segment.floor - 300.0);
+    assert!(cave.raycast((200.0, 300.0), (0.0, -1.0), 50.0).is_none());
+}
+
+/// Tests that diagonal rays hit the wall of the segment they reach.
+#[test]
+fn cave_raycast_diagonal() {
+    let mut cave = Cave::new(7);
+    cave.configure_for_level(3);
+    cave.segments_in_view(0.0, 800.0, 1000.0);
+    let direction = (0.6, -0.8);
+
+    let distance = cave.raycast((100.0, 300.0), direction, 1000.0).unwrap();
+    let hit = (100.0 + direction.0 * distance, 300.0 + direction.1 * distance);
+    let segment = cave.segment_at(hit.0).unwrap();
+
+    assert!(hit.1 <= segment.ceiling + EPSILON);
+    assert!(hit.1 > segment.ceiling - CaveConstants::MAX_HEIGHT_CHANGE * 2.0);
+}
+
+/// Tests that horizontal rays leave the generated cave without a hit.
+#[test]
+fn cave_raycast_horizontal_misses() {
+    let mut cave = Cave::new(42);
+    cave.configure_for_level(1);
+    cave.segments_in_view(0.0, 800.0, 1000.0);
+
+    assert!(cave.raycast((100.0, 300.0), (1.0, 0.0), 300.0).is_none());
+    assert!(cave.raycast((100.0, 300.0), (1.0, 0.0), 10_000.0).is_none());
+}
+
+/// Tests that landing pads are flat runs of floor at the configured spacing.
+#[test]
+fn cave_generates_flat_landing_pads() {
+    let mut cave = Cave::new(42);
+    let segments = cave.segments_in_view(0.0, 4000.0, 1000.0);
+
+    let pads: Vec<&CaveSegment> = segments.iter().filter(|s| s.landing_pad).collect();
+    assert!(pads.len() >= LandingPadConstants::PAD_SEGMENTS as usize);
+    assert!(pads[0].x_start >= LandingPadConstants::INITIAL_SPAWN_DELAY);
+
+    // The first pad's segments are contiguous and share one floor height
+    let first_pad = &pads[..LandingPadConstants::PAD_SEGMENTS as usize];
+    for pair in first_pad.windows(2) {
+        assert_float_eq(pair[1].x_start, pair[0].x_end());
+        assert_float_eq(pair[1].floor, pair[0].floor);
+    }
+    for pad in first_pad {
+        assert!(pad.gap_height() >= CaveConstants::MIN_GAP);
+    }
+
+    // Every pad segment, even after repair, continues the floor before it
+    for pair in segments.windows(2).filter(|pair| pair[1].landing_pad) {
+        assert_float_eq(pair[1].floor, pair[0].floor);
+    }
+}
+
+/// Tests wall clearance under a box spanning several segments.
+#[test]
+fn cave_wall_clearance() {
+    let mut cave = Cave::new(7);
+    let segments = cave.segments_in_view(0.0, 400.0, 1000.0);
+    let ship = Aabb::new(60.0, 250.0, 30.0, 18.0);
+
+    let under: Vec<&CaveSegment> = segments
+        .iter()
+        .filter(|s| s.x_start < ship.right() && s.x_end() > ship.left())
+        .collect();
+    let lowest_ceiling = under.iter().map(|s| s.ceiling).fold(f32::MIN, f32::max);
+    let highest_floor = under.iter().map(|s| s.floor).fold(f32::MAX, f32::min);
+
+    let (ceiling, floor) = cave.wall_clearance(&ship).unwrap();
+    assert_float_eq(ceiling, ship.top() - lowest_ceiling);
+    assert_float_eq(floor, highest_floor - ship.bottom());
+
+    // Nothing generated that far ahead
+    assert!(cave.wall_clearance(&Aabb::new(1.0e6, 250.0, 30.0, 18.0)).is_none());
+}
@@ -1,4 +1,4 @@
-use core::collision::{aabb_overlap, Aabb};
+use core::collision::{aabb_contact, aabb_gap, aabb_overlap, Aabb};
 
 /// Tests basic AABB creation and properties.
 #[test]
@@ -203,3 +203,59 @@ fn floating_point_precision() {
         (10.0, 10.0)
     ));
 }
+
+/// Tests that separated rectangles produce no contact.
+#[test]
+fn contact_none_when_separated() {
+    assert_eq!(
+        aabb_contact((0.0, 0.0), (10.0, 10.0), (20.0, 0.0), (10.0, 10.0)),
+        None
+    );
+
+    // Touching edges do not count as contact
+    assert_eq!(
+        aabb_contact((0.0, 0.0), (10.0, 10.0), (10.0, 0.0), (10.0, 10.0)),
+        None
+    );
+}
+
+/// Tests contact normal against a ceiling above.
+#[test]
+fn contact_normal_points_down_from_ceiling() {
+    // Ceiling from y=0 to y=100, player top at y=97
+    let contact = aabb_contact((20.0, 97.0), (30.0, 18.0), (0.0, 0.0), (200.0, 100.0)).unwrap();
+
+    assert_eq!(contact.normal, (0.0, 1.0));
+    assert!((contact.depth - 3.0).abs() < 0.001);
+}
+
+/// Tests contact normal against a floor below.
+#[test]
+fn contact_normal_points_up_from_floor() {
+    // Floor from y=400, player bottom at y=404
+    let contact = aabb_contact((20.0, 386.0), (30.0, 18.0), (0.0, 400.0), (200.0, 200.0)).unwrap();
+
+    assert_eq!(contact.normal, (0.0, -1.0));
+    assert!((contact.depth - 4.0).abs() < 0.001);
+}
+
+/// Tests contact normal against the side of an obstacle.
+#[test]
+fn contact_normal_uses_least_penetration_axis() {
+    // Player right edge 2px into a tall wall
+    let contact = aabb_contact((0.0, 50.0), (30.0, 18.0), (28.0, 0.0), (20.0, 200.0)).unwrap();
+
+    assert_eq!(contact.normal, (-1.0, 0.0));
+    assert!((contact.depth - 2.0).abs() < 0.001);
+}
+
+/// Tests the gap between separated, diagonal and overlapping boxes.
+#[test]
+fn aabb_gap_distances() {
+    let a = Aabb::new(0.0, 0.0, 10.0, 10.0);
+
+    assert_eq!(aabb_gap(&a, &Aabb::new(15.0, 0.0, 10.0, 10.0)), 5.0);
+    assert_eq!(aabb_gap(&a, &Aabb::new(0.0, -12.0, 10.0, 10.0)), 2.0);
+    assert_eq!(aabb_gap(&a, &Aabb::new(13.0, 14.0, 10.0, 10.0)), 5.0);
+    assert_eq!(aabb_gap(&a, &Aabb::new(5.0, 5.0, 10.0, 10.0)), 0.0);
+}
@@ -0,0 +1,139 @@
+// core/tests/combo.rs
+
+use core::combo::{ComboReward, ComboTracker};
+use core::constants::{ComboConstants, ScoreConstants};
+use core::pickup::{PickupEvent, PickupType};
+use core::run::RunEvent;
+use core::score::Score;
+
+const DT: f32 = 1.0 / 60.0; // 60 FPS
+
+const FUEL: PickupEvent = PickupEvent::Collected(PickupType::Fuel);
+
+/// Runs the tracker's timer for the given time in frame-sized steps.
+fn wait(tracker: &mut ComboTracker, seconds: f32) {
+    let frames = (seconds / DT).round() as u32;
+    for _ in 0..frames {
+        tracker.update(DT);
+    }
+}
+
+/// Tests that a single pickup starts a combo without scoring one.
+#[test]
+fn first_pickup_starts_timer() {
+    let mut tracker = ComboTracker::new();
+
+    assert_eq!(tracker.record(FUEL), None);
+    assert_eq!(tracker.count(), 1);
+    assert_eq!(tracker.multiplier(), 1);
+    assert!(!tracker.is_active());
+    assert_eq!(tracker.time_ratio(), 1.0);
+}
+
+/// Tests that a pickup inside the window extends the combo.
+#[test]
+fn pickup_inside_window_extends_combo() {
+    let mut tracker = ComboTracker::new();
+    tracker.record(FUEL);
+    wait(&mut tracker, ComboConstants::WINDOW - 0.5);
+
+    let event = tracker.record(PickupEvent::Collected(PickupType::Shield));
+
+    assert_eq!(
+        event,
+        Some(RunEvent::Combo {
+            count: 2,
+            multiplier: 2,
+            reward: None,
+        })
+    );
+    assert!(tracker.is_active());
+    // Each pickup restarts the timer
+    assert_eq!(tracker.time_left(), ComboConstants::WINDOW);
+}
+
+/// Tests that the timer drains and ends the combo when it runs out.
+#[test]
+fn timer_expiry_breaks_combo() {
+    let mut tracker = ComboTracker::new();
+    tracker.record(FUEL);
+    tracker.record(FUEL);
+
+    wait(&mut tracker, ComboConstants::WINDOW / 2.0);
+    assert!((tracker.time_ratio() - 0.5).abs() < 0.01);
+    assert!(tracker.is_active());
+
+    wait(&mut tracker, ComboConstants::WINDOW / 2.0 + 0.1);
+    assert_eq!(tracker.count(), 0);
+    assert_eq!(tracker.time_ratio(), 0.0);
+
+    // The next pickup starts over
+    assert_eq!(tracker.record(FUEL), None);
+    assert_eq!(tracker.count(), 1);
+}
+
+/// Tests that a missed pickup breaks the combo immediately.
+#[test]
+fn missed_pickup_breaks_combo() {
+    let mut tracker = ComboTracker::new();
+    tracker.record(FUEL);
+    tracker.record(FUEL);
+
+    tracker.record(PickupEvent::Missed(PickupType::Shield));
+
+    assert_eq!(tracker.count(), 0);
+    assert!(!tracker.is_active());
+}
+
+/// Tests that cargo pods neither extend nor break a combo.
+#[test]
+fn cargo_pods_are_ignored() {
+    let mut tracker = ComboTracker::new();
+    tracker.record(FUEL);
+    tracker.record(FUEL);
+
+    assert_eq!(tracker.record(PickupEvent::Collected(PickupType::Cargo)), None);
+    assert_eq!(tracker.record(PickupEvent::Missed(PickupType::Cargo)), None);
+    assert_eq!(tracker.count(), 2);
+}
+
+/// Tests that the multiplier is capped.
+#[test]
+fn multiplier_is_capped() {
+    let mut tracker = ComboTracker::new();
+    for _ in 0..ComboConstants::MAX_MULTIPLIER + 3 {
+        tracker.record(FUEL);
+        tracker.update(DT);
+    }
+
+    assert_eq!(tracker.multiplier(), ComboConstants::MAX_MULTIPLIER);
+}
+
+/// Tests that milestones alternate between fuel and points.
+#[test]
+fn milestones_alternate_rewards() {
+    let step = ComboConstants::MILESTONE_STEP;
+
+    assert_eq!(ComboReward::for_count(step - 1), None);
+    assert_eq!(ComboReward::for_count(step), Some(ComboReward::Fuel(ComboConstants::MILESTONE_FUEL)));
+    assert_eq!(ComboReward::for_count(step * 2), Some(ComboReward::Points(ComboConstants::MILESTONE_POINTS)));
+    assert_eq!(ComboReward::for_count(step * 3), Some(ComboReward::Fuel(ComboConstants::MILESTONE_FUEL)));
+}
+
+/// Tests that combo events feed the score breakdown.
+#[test]
+fn combo_events_score_points() {
+    let mut tracker = ComboTracker::new();
+    let mut score = Score::new();
+    for _ in 0..ComboConstants::MILESTONE_STEP * 2 {
+        if let Some(event) = tracker.record(FUEL) {
+            score.apply(&event);
+        }
+    }
+
+    let steps: u32 = (2..=ComboConstants::MILESTONE_STEP * 2)
+        .map(|count| count.min(ComboConstants::MAX_MULTIPLIER) - 1)
+        .sum();
+    let expected = ScoreConstants::COMBO_STEP_POINTS * steps + ComboConstants::MILESTONE_POINTS;
+    assert_eq!(score.breakdown().combos, expected);
+}
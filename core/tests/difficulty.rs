@@ -0,0 +1,65 @@
+// core/tests/difficulty.rs
+
+use core::difficulty::{Difficulty, DifficultySettings};
+use core::tractor::{BeamDir, TractorBeam};
+
+/// Tests that normal leaves the simulation unchanged.
+#[test]
+fn normal_scales_nothing() {
+    let settings = Difficulty::Normal.settings();
+
+    for factor in [
+        settings.scroll_speed,
+        settings.fuel_burn_rate,
+        settings.refill_percentage,
+        settings.cave_gap,
+        settings.pickup_spacing,
+        settings.beam_duration,
+        settings.hazard_density,
+    ] {
+        assert_eq!(factor, 1.0);
+    }
+    assert_eq!(DifficultySettings::default(), settings);
+    assert_eq!(Difficulty::default(), Difficulty::Normal);
+}
+
+/// Tests that every setting gets harder with every step up.
+#[test]
+fn harder_presets_are_harder_on_every_setting() {
+    for pair in Difficulty::ALL.windows(2) {
+        let (easier, harder) = (pair[0].settings(), pair[1].settings());
+
+        assert!(harder.scroll_speed > easier.scroll_speed);
+        assert!(harder.fuel_burn_rate > easier.fuel_burn_rate);
+        assert!(harder.refill_percentage < easier.refill_percentage);
+        assert!(harder.cave_gap < easier.cave_gap);
+        assert!(harder.pickup_spacing > easier.pickup_spacing);
+        assert!(harder.beam_duration < easier.beam_duration);
+        assert!(harder.hazard_density > easier.hazard_density);
+    }
+}
+
+/// Tests that presets cycle and can be looked up by their storage name.
+#[test]
+fn presets_cycle_and_parse() {
+    assert_eq!(Difficulty::Insane.next(), Difficulty::Easy);
+    assert_eq!(Difficulty::Easy.previous(), Difficulty::Insane);
+    for difficulty in Difficulty::ALL {
+        assert_eq!(difficulty.next().previous(), difficulty);
+        assert_eq!(Difficulty::from_name(&difficulty.to_string()), Some(difficulty));
+    }
+    assert_eq!(Difficulty::from_name("nightmare"), None);
+}
+
+/// Tests that a scaled beam duration sets how long one activation lasts.
+#[test]
+fn beam_duration_scales_activation() {
+    let mut beam = TractorBeam::new();
+    beam.max_duration *= Difficulty::Insane.settings().beam_duration;
+
+    beam.activate(BeamDir::Up);
+    assert_eq!(beam.timer, TractorBeam::MAX_DURATION * 0.7);
+
+    beam.tick(TractorBeam::MAX_DURATION * 0.75);
+    assert!(!beam.is_active());
+}
@@ -0,0 +1,125 @@
+// core/tests/endurance.rs
+
+use core::constants::FuelConstants;
+use core::endurance::{EnduranceEstimator, FuelWarning};
+use core::fuel::Fuel;
+
+const DT: f32 = 1.0 / 60.0; // 60 FPS
+const EPSILON: f32 = 0.001;
+
+/// Helper function to assert floating point equality.
+fn assert_float_eq(a: f32, b: f32) {
+    assert!((a - b).abs() < EPSILON, "Expected {}, got {}", b, a);
+}
+
+/// Records the same burn rate for the given number of seconds.
+fn record_for(estimator: &mut EnduranceEstimator, seconds: f32, burn_rate: f32) {
+    let frames = (seconds / DT).round() as usize;
+    for _ in 0..frames {
+        estimator.record(DT, burn_rate);
+    }
+}
+
+/// Tests that an estimator without history predicts unlimited endurance.
+#[test]
+fn no_history_means_no_estimate() {
+    let estimator = EnduranceEstimator::new();
+    let fuel = Fuel::new(100.0, 20.0);
+
+    assert_float_eq(estimator.average_burn_rate(), 0.0);
+    assert_eq!(estimator.seconds_left(&fuel), None);
+}
+
+/// Tests the estimate at a steady burn rate.
+#[test]
+fn steady_burn_estimate() {
+    let mut estimator = EnduranceEstimator::new();
+    let fuel = Fuel::new(100.0, 20.0);
+
+    record_for(&mut estimator, 2.0, 20.0);
+
+    assert_float_eq(estimator.average_burn_rate(), 20.0);
+    assert_float_eq(estimator.seconds_left(&fuel).unwrap(), 5.0);
+}
+
+/// Tests that old samples fall out of the history window.
+#[test]
+fn history_window_forgets_old_burns() {
+    let mut estimator = EnduranceEstimator::new();
+
+    record_for(&mut estimator, FuelConstants::BURN_HISTORY_WINDOW, 40.0);
+    record_for(&mut estimator, FuelConstants::BURN_HISTORY_WINDOW, 10.0);
+
+    assert!((estimator.average_burn_rate() - 10.0).abs() < 0.5);
+}
+
+/// Tests that pad refuelling offsets the burn, down to no estimate once it keeps up.
+#[test]
+fn refuelling_offsets_burn() {
+    let mut estimator = EnduranceEstimator::new();
+    let fuel = Fuel::new(100.0, 20.0);
+
+    record_for(&mut estimator, 1.0, 20.0);
+    record_for(&mut estimator, 1.0, 20.0 - 10.0);
+    assert_float_eq(estimator.average_burn_rate(), 15.0);
+
+    record_for(&mut estimator, FuelConstants::BURN_HISTORY_WINDOW, 20.0 - 30.0);
+    assert!(estimator.average_burn_rate() < 0.0);
+    assert_eq!(estimator.seconds_left(&fuel), None);
+}
+
+/// Tests warning levels against the thresholds.
+#[test]
+fn warning_levels_follow_thresholds() {
+    assert_eq!(FuelWarning::from_seconds_left(None), FuelWarning::Normal);
+    assert_eq!(
+        FuelWarning::from_seconds_left(Some(FuelConstants::LOW_WARNING_SECONDS + 1.0)),
+        FuelWarning::Normal
+    );
+    assert_eq!(
+        FuelWarning::from_seconds_left(Some(FuelConstants::LOW_WARNING_SECONDS)),
+        FuelWarning::Low
+    );
+    assert_eq!(
+        FuelWarning::from_seconds_left(Some(FuelConstants::CRITICAL_WARNING_SECONDS)),
+        FuelWarning::Critical
+    );
+}
+
+/// Tests that each warning fires once per escalation.
+#[test]
+fn warnings_fire_once_when_escalating() {
+    let mut estimator = EnduranceEstimator::new();
+
+    assert_eq!(estimator.update_warning(FuelWarning::Normal), None);
+    assert_eq!(estimator.update_warning(FuelWarning::Low), Some(FuelWarning::Low));
+    assert_eq!(estimator.update_warning(FuelWarning::Low), None);
+    assert_eq!(estimator.update_warning(FuelWarning::Critical), Some(FuelWarning::Critical));
+
+    // Recovering and dropping again fires again
+    assert_eq!(estimator.update_warning(FuelWarning::Normal), None);
+    assert_eq!(estimator.update_warning(FuelWarning::Low), Some(FuelWarning::Low));
+}
+
+/// Tests the "can't make it" check against the next depot.
+#[test]
+fn outlook_flags_unreachable_depot() {
+    let mut estimator = EnduranceEstimator::new();
+    let fuel = Fuel::new(100.0, 20.0);
+    record_for(&mut estimator, 2.0, 20.0); // 5 seconds left
+
+    // 480px at 120px/s takes 4 seconds
+    let reachable = estimator.outlook(&fuel, 100.0, Some(580.0), 120.0);
+    assert!(reachable.can_reach_depot);
+    assert_float_eq(reachable.depot_distance.unwrap(), 480.0);
+
+    // 720px takes 6 seconds
+    let unreachable = estimator.outlook(&fuel, 100.0, Some(820.0), 120.0);
+    assert!(!unreachable.can_reach_depot);
+    assert_eq!(unreachable.warning, FuelWarning::Low);
+
+    // No known depot, nothing to warn about
+    let unknown = estimator.outlook(&fuel, 100.0, None, 120.0);
+    assert!(unknown.can_reach_depot);
+    assert_eq!(unknown.depot_distance, None);
+}
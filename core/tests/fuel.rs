@@ -1,4 +1,5 @@
-use core::fuel::Fuel;
+use core::fuel::{Fuel, FuelConsumption, FuelDemand};
+use core::player::{PlayerInput, Throttle};
 
 const EPSILON: f32 = 0.001;
 const DT: f32 = 1.0 / 60.0; // 60 FPS
@@ -20,24 +21,24 @@ fn fuel_creation() {
     assert_float_eq(fuel.ratio(), 1.0);
 }
 
-/// Tests that no fuel burns when consuming is false.
+/// Tests that no fuel burns without demand.
 #[test]
 fn no_burn_when_not_consuming() {
     let mut fuel = Fuel::new(100.0, 10.0);
     let initial_fuel = fuel.current;
 
-    let became_empty = fuel.burn(DT, false);
+    let became_empty = fuel.burn(DT, 0.0);
 
     assert_float_eq(fuel.current, initial_fuel);
     assert!(!became_empty);
 }
 
-/// Tests fuel consumption when consuming is true.
+/// Tests fuel consumption at full demand.
 #[test]
 fn burns_fuel_when_consuming() {
     let mut fuel = Fuel::new(100.0, 60.0); // Burns 1 unit per frame at 60 FPS
 
-    let became_empty = fuel.burn(DT, true);
+    let became_empty = fuel.burn(DT, 1.0);
 
     assert_float_eq(fuel.current, 99.0);
     assert!(!became_empty);
@@ -48,7 +49,7 @@ fn burns_fuel_when_consuming() {
 fn fuel_cannot_go_negative() {
     let mut fuel = Fuel::new(10.0, 600.0); // Burns 10 units per frame
 
-    let became_empty = fuel.burn(DT, true);
+    let became_empty = fuel.burn(DT, 1.0);
 
     assert_float_eq(fuel.current, 0.0);
     assert!(became_empty);
@@ -61,15 +62,27 @@ fn burn_returns_true_when_becoming_empty() {
     let mut fuel = Fuel::new(1.0, 60.0); // Will become empty in one frame
 
     // First burn - becomes empty
-    let became_empty = fuel.burn(DT, true);
+    let became_empty = fuel.burn(DT, 1.0);
     assert!(became_empty);
     assert!(fuel.is_empty());
 
     // Second burn - already empty, should return false
-    let became_empty_again = fuel.burn(DT, true);
+    let became_empty_again = fuel.burn(DT, 1.0);
     assert!(!became_empty_again);
 }
 
+/// Tests that drawing a fixed amount reports when the tank runs dry.
+#[test]
+fn draw_returns_true_when_becoming_empty() {
+    let mut fuel = Fuel::new(10.0, 60.0);
+
+    assert!(!fuel.draw(4.0));
+    assert_float_eq(fuel.current, 6.0);
+    assert!(fuel.draw(10.0));
+    assert!(fuel.is_empty());
+    assert!(!fuel.draw(1.0));
+}
+
 /// Tests fuel refilling functionality.
 #[test]
 fn fuel_refill_basic() {
@@ -141,7 +154,7 @@ fn multiple_burn_cycles() {
 
     // Burn for 5 frames
     for _ in 0..5 {
-        let became_empty = fuel.burn(DT, true);
+        let became_empty = fuel.burn(DT, 1.0);
         assert!(!became_empty);
     }
 
@@ -149,7 +162,7 @@ fn multiple_burn_cycles() {
 
     // Burn until empty
     for i in 0..5 {
-        let became_empty = fuel.burn(DT, true);
+        let became_empty = fuel.burn(DT, 1.0);
         if i == 4 {
             // Last frame
             assert!(became_empty);
@@ -167,10 +180,10 @@ fn mixed_consumption_cycles() {
     let mut fuel = Fuel::new(10.0, 60.0);
 
     // Consume, skip, consume, skip pattern
-    fuel.burn(DT, true); // 9.0
-    fuel.burn(DT, false); // 9.0 (no change)
-    fuel.burn(DT, true); // 8.0
-    fuel.burn(DT, false); // 8.0 (no change)
+    fuel.burn(DT, 1.0); // 9.0
+    fuel.burn(DT, 0.0); // 9.0 (no change)
+    fuel.burn(DT, 1.0); // 8.0
+    fuel.burn(DT, 0.0); // 8.0 (no change)
 
     assert_float_eq(fuel.current, 8.0);
 }
@@ -181,7 +194,7 @@ fn small_burn_rate() {
     let mut fuel = Fuel::new(10.0, 0.1);
 
     // Should barely consume any fuel
-    fuel.burn(DT, true);
+    fuel.burn(DT, 1.0);
 
     let expected = 10.0 - (0.1 * DT);
     assert_float_eq(fuel.current, expected);
@@ -193,8 +206,93 @@ fn small_burn_rate() {
 fn high_burn_rate() {
     let mut fuel = Fuel::new(1.0, 3600.0); // Burns 60 units per frame
 
-    let became_empty = fuel.burn(DT, true);
+    let became_empty = fuel.burn(DT, 1.0);
 
     assert_float_eq(fuel.current, 0.0);
     assert!(became_empty);
 }
+
+/// Tests that demand scales the burn proportionally.
+#[test]
+fn burn_scales_with_demand() {
+    let mut fuel = Fuel::new(100.0, 60.0);
+
+    fuel.burn(DT, 0.5);
+    assert_float_eq(fuel.current, 99.5);
+
+    fuel.burn(DT, 2.0);
+    assert_float_eq(fuel.current, 97.5);
+}
+
+/// Tests that each action burns at its own rate on top of the idle drain.
+#[test]
+fn consumption_profile_rates() {
+    let profile = FuelConsumption {
+        main_thrust: 1.0,
+        down_thrust: 0.5,
+        forward_boost: 0.75,
+        beam: 0.25,
+        idle: 0.1,
+    };
+
+    assert_float_eq(profile.multiplier(FuelDemand::default()), 0.1);
+
+    let up = FuelDemand { main_thrust: 1.0, ..Default::default() };
+    let down = FuelDemand { down_thrust: 1.0, ..Default::default() };
+    let boost = FuelDemand { forward_boost: 1.0, ..Default::default() };
+    let beam = FuelDemand { beam: 1.0, ..Default::default() };
+    assert_float_eq(profile.multiplier(up), 1.1);
+    assert_float_eq(profile.multiplier(down), 0.6);
+    assert_float_eq(profile.multiplier(boost), 0.85);
+    assert_float_eq(profile.multiplier(beam), 0.35);
+}
+
+/// Tests that analog throttle burns proportionally and is clamped.
+#[test]
+fn consumption_throttle_is_proportional() {
+    let profile = FuelConsumption {
+        idle: 0.0,
+        ..Default::default()
+    };
+
+    let half = FuelDemand { main_thrust: 0.5, ..Default::default() };
+    let over = FuelDemand { main_thrust: 3.0, ..Default::default() };
+    assert_float_eq(profile.multiplier(half), profile.main_thrust * 0.5);
+    assert_float_eq(profile.multiplier(over), profile.main_thrust);
+}
+
+/// Tests demand built from digital input.
+#[test]
+fn demand_from_input() {
+    let input = PlayerInput {
+        up: true,
+        left: true,
+        ..Default::default()
+    };
+
+    let demand = FuelDemand::from_input(input, true);
+    assert_float_eq(demand.main_thrust, 1.0);
+    assert_float_eq(demand.forward_boost, 0.0);
+    assert_float_eq(demand.beam, 1.0);
+    assert!(demand.is_thrusting());
+
+    // Braking and the beam alone are not thrust
+    let braking = PlayerInput { left: true, ..Default::default() };
+    assert!(!FuelDemand::from_input(braking, true).is_thrusting());
+}
+
+/// Tests demand built from analog input follows the throttle.
+#[test]
+fn demand_from_analog_input() {
+    let input = PlayerInput {
+        up: true,
+        throttle: Some(Throttle { up: 0.25, down: 0.0, right: 2.0 }),
+        ..Default::default()
+    };
+
+    let demand = FuelDemand::from_input(input, false);
+    assert_float_eq(demand.main_thrust, 0.25);
+    assert_float_eq(demand.down_thrust, 0.0);
+    assert_float_eq(demand.forward_boost, 1.0);
+    assert_float_eq(demand.beam, 0.0);
+}
@@ -1,6 +1,6 @@
 // Datei: core/tests/game_state.rs (erweitert)
 
-use core::game_state::{GameEvent, GameState, StateMachine};
+use core::game_state::{DeathCause, GameEvent, GameState, StateMachine};
 
 /// Tests for valid state transitions from Menu state.
 #[test]
@@ -17,7 +17,7 @@ fn playing_state_transitions() {
         GameState::Paused
     );
     assert_eq!(
-        GameState::Playing.next(GameEvent::Dead),
+        GameState::Playing.next(GameEvent::Dead(DeathCause::FloorCrash)),
         GameState::GameOver
     );
 }
@@ -72,7 +72,7 @@ fn back_to_menu_from_game_over() {
 
     // Start game and die
     state_machine.handle_event(GameEvent::Start);
-    state_machine.handle_event(GameEvent::Dead);
+    state_machine.handle_event(GameEvent::Dead(DeathCause::FloorCrash));
     assert_eq!(state_machine.current(), GameState::GameOver);
 
     // Go back to menu
@@ -105,7 +105,7 @@ fn complete_game_flow_with_menus() {
     assert_eq!(state_machine.current(), GameState::Playing);
 
     // Die
-    state_machine.handle_event(GameEvent::Dead);
+    state_machine.handle_event(GameEvent::Dead(DeathCause::FloorCrash));
     assert_eq!(state_machine.current(), GameState::GameOver);
 
     // Go back to menu from game over
@@ -134,7 +134,7 @@ fn invalid_transitions_remain_in_current_state() {
         GameState::Menu.next(GameEvent::PauseToggle),
         GameState::Menu
     );
-    assert_eq!(GameState::Menu.next(GameEvent::Dead), GameState::Menu);
+    assert_eq!(GameState::Menu.next(GameEvent::Dead(DeathCause::FloorCrash)), GameState::Menu);
     assert_eq!(GameState::Menu.next(GameEvent::BackToMenu), GameState::Menu);
 
     // Playing state invalid transitions
@@ -153,7 +153,7 @@ fn invalid_transitions_remain_in_current_state() {
 
     // Paused state invalid transitions
     assert_eq!(GameState::Paused.next(GameEvent::Start), GameState::Paused);
-    assert_eq!(GameState::Paused.next(GameEvent::Dead), GameState::Paused);
+    assert_eq!(GameState::Paused.next(GameEvent::Dead(DeathCause::FloorCrash)), GameState::Paused);
 
     // GameOver state invalid transitions
     assert_eq!(
@@ -161,7 +161,7 @@ fn invalid_transitions_remain_in_current_state() {
         GameState::GameOver
     );
     assert_eq!(
-        GameState::GameOver.next(GameEvent::Dead),
+        GameState::GameOver.next(GameEvent::Dead(DeathCause::FloorCrash)),
         GameState::GameOver
     );
 }
@@ -197,7 +197,7 @@ fn rapid_state_changes() {
     state_machine.handle_event(GameEvent::PauseToggle);
     state_machine.handle_event(GameEvent::BackToMenu);
     state_machine.handle_event(GameEvent::Start);
-    state_machine.handle_event(GameEvent::Dead);
+    state_machine.handle_event(GameEvent::Dead(DeathCause::FloorCrash));
     state_machine.handle_event(GameEvent::BackToMenu);
 
     // Should end up in Menu
@@ -248,7 +248,7 @@ fn game_event_enum_properties() {
     let events = [
         GameEvent::Start,
         GameEvent::PauseToggle,
-        GameEvent::Dead,
+        GameEvent::Dead(DeathCause::FloorCrash),
         GameEvent::Reset,
         GameEvent::BackToMenu,
     ];
@@ -277,8 +277,129 @@ fn menu_navigation_scenarios() {
     assert_eq!(state_machine.current(), GameState::Playing);
 
     // Scenario 2: Die, back to menu, start again
-    state_machine.handle_event(GameEvent::Dead);
+    state_machine.handle_event(GameEvent::Dead(DeathCause::FloorCrash));
     state_machine.handle_event(GameEvent::BackToMenu);
     state_machine.handle_event(GameEvent::Start);
     assert_eq!(state_machine.current(), GameState::Playing);
 }
+
+/// Tests the lose-a-life and respawn cycle.
+#[test]
+fn respawn_state_transitions() {
+    assert_eq!(
+        GameState::Playing.next(GameEvent::LifeLost),
+        GameState::Respawning
+    );
+    assert_eq!(
+        GameState::Respawning.next(GameEvent::RespawnComplete),
+        GameState::Playing
+    );
+    assert_eq!(
+        GameState::Respawning.next(GameEvent::BackToMenu),
+        GameState::Menu
+    );
+}
+
+/// Tests that the respawn fly-in cannot be paused or killed.
+#[test]
+fn respawning_ignores_other_events() {
+    assert_eq!(
+        GameState::Respawning.next(GameEvent::PauseToggle),
+        GameState::Respawning
+    );
+    assert_eq!(
+        GameState::Respawning.next(GameEvent::Dead(DeathCause::FloorCrash)),
+        GameState::Respawning
+    );
+    assert_eq!(
+        GameState::Menu.next(GameEvent::RespawnComplete),
+        GameState::Menu
+    );
+}
+
+/// Tests that every death cause ends the run.
+#[test]
+fn every_death_cause_leads_to_game_over() {
+    let causes = [
+        DeathCause::CeilingCrash,
+        DeathCause::FloorCrash,
+        DeathCause::Obstacle,
+        DeathCause::Hazard,
+        DeathCause::FuelExhausted,
+    ];
+
+    for cause in causes {
+        assert_eq!(
+            GameState::Playing.next(GameEvent::Dead(cause)),
+            GameState::GameOver
+        );
+    }
+}
+
+/// Tests that fuel exhaustion is the only non-collision cause.
+#[test]
+fn death_cause_classification() {
+    assert!(DeathCause::CeilingCrash.is_collision());
+    assert!(DeathCause::Hazard.is_collision());
+    assert!(!DeathCause::FuelExhausted.is_collision());
+    assert_eq!(DeathCause::FuelExhausted.message(), "OUT OF FUEL!");
+    assert_eq!(DeathCause::FloorCrash.to_string(), "floor crash");
+}
+
+/// Tests that a qualifying run passes through name entry before game over.
+#[test]
+fn high_score_goes_through_name_entry() {
+    assert_eq!(
+        GameState::Playing.next(GameEvent::NewHighScore),
+        GameState::NameEntry
+    );
+    assert_eq!(
+        GameState::NameEntry.next(GameEvent::Dead(DeathCause::Hazard)),
+        GameState::NameEntry
+    );
+    assert_eq!(
+        GameState::NameEntry.next(GameEvent::NameConfirmed),
+        GameState::GameOver
+    );
+    assert_eq!(
+        GameState::Menu.next(GameEvent::NameConfirmed),
+        GameState::Menu
+    );
+}
+
+/// Tests that a completed run ends in victory, through name entry if it ranks.
+#[test]
+fn completed_run_leads_to_victory() {
+    assert_eq!(
+        GameState::Playing.next(GameEvent::RunComplete),
+        GameState::Victory
+    );
+    assert_eq!(
+        GameState::NameEntry.next(GameEvent::RunComplete),
+        GameState::Victory
+    );
+    // A run without death is ended from the pause menu
+    assert_eq!(
+        GameState::Paused.next(GameEvent::RunComplete),
+        GameState::Victory
+    );
+    assert_eq!(
+        GameState::Paused.next(GameEvent::NewHighScore),
+        GameState::NameEntry
+    );
+
+    assert_eq!(GameState::Victory.next(GameEvent::Start), GameState::Playing);
+    assert_eq!(GameState::Victory.next(GameEvent::Reset), GameState::Menu);
+    assert_eq!(GameState::Victory.next(GameEvent::BackToMenu), GameState::Menu);
+    assert_eq!(GameState::Victory.next(GameEvent::PauseToggle), GameState::Victory);
+    assert_eq!(GameState::Menu.next(GameEvent::RunComplete), GameState::Menu);
+}
+
+/// Tests that death causes can be looked up by their display name.
+#[test]
+fn death_cause_from_name() {
+    for cause in DeathCause::ALL {
+        assert_eq!(DeathCause::from_name(&cause.to_string()), Some(cause));
+    }
+    assert_eq!(DeathCause::from_name("boredom"), None);
+}
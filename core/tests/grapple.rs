@@ -0,0 +1,270 @@
+// core/tests/grapple.rs
+
+use core::constants::{GrappleConstants, WorldConstants};
+use core::grapple::{AnchorManager, Grapple, GrappleStatus};
+use core::player::{Player, Vec2};
+use core::tractor::{BeamDir, BeamPolarity, TractorBeam};
+
+const DT: f32 = 1.0 / 60.0; // 60 FPS
+const EPSILON: f32 = 0.001;
+
+/// Helper function to assert floating point equality.
+fn assert_float_eq(a: f32, b: f32) {
+    assert!((a - b).abs() < EPSILON, "Expected {}, got {}", b, a);
+}
+
+/// Gets the distance from the player to a point.
+fn distance_to(player: &Player, point: (f32, f32)) -> f32 {
+    distance((player.pos.x, player.pos.y), point)
+}
+
+/// Gets the distance between two points.
+fn distance(a: (f32, f32), b: (f32, f32)) -> f32 {
+    let dx = b.0 - a.0;
+    let dy = b.1 - a.1;
+    (dx * dx + dy * dy).sqrt()
+}
+
+/// Tests that anchors spawn on the walls at the configured spacing.
+#[test]
+fn anchors_spawn_on_walls() {
+    let mut manager = AnchorManager::new(42);
+
+    assert!(!manager.should_spawn_anchor(0.0));
+
+    let mut x = 0.0;
+    while x < 5000.0 {
+        if manager.should_spawn_anchor(x) {
+            manager.spawn_anchor(x, 100.0, 500.0);
+        }
+        x += 50.0;
+    }
+
+    assert!(!manager.anchors().is_empty());
+    let half = GrappleConstants::ANCHOR_SIZE / 2.0;
+    for anchor in manager.anchors() {
+        let expected_y = if anchor.on_ceiling { 100.0 + half } else { 500.0 - half };
+        assert_float_eq(anchor.position.1, expected_y);
+        assert!(anchor.position.0 >= GrappleConstants::INITIAL_SPAWN_DELAY);
+    }
+}
+
+/// Tests that the beam finds the nearest anchor it covers.
+#[test]
+fn beam_finds_nearest_anchor() {
+    let mut manager = AnchorManager::new(1);
+    let mut x = GrappleConstants::INITIAL_SPAWN_DELAY;
+    while manager.anchors().len() < 20 {
+        if manager.should_spawn_anchor(x) {
+            manager.spawn_anchor(x, 100.0, 500.0);
+        }
+        x += 50.0;
+    }
+    let anchor = manager.anchors()[0];
+    let player_pos = (anchor.position.0, 300.0);
+
+    let mut beam = TractorBeam::new();
+    assert!(beam.find_anchor(manager.anchors(), player_pos).is_none());
+
+    beam.activate(if anchor.on_ceiling { BeamDir::Up } else { BeamDir::Down });
+    assert_eq!(beam.find_anchor(manager.anchors(), player_pos), Some(anchor));
+}
+
+/// Tests that the rope reels the ship toward the anchor without thrust.
+#[test]
+fn grapple_reels_ship_in() {
+    let anchor = (100.0, 100.0);
+    let mut position = (100.0, 300.0);
+    let mut velocity = (0.0, 0.0);
+    let mut grapple = Grapple::new(anchor, position);
+    let start = distance(position, anchor);
+
+    for _ in 0..30 {
+        assert_eq!(grapple.update(&mut position, &mut velocity, DT), GrappleStatus::Holding);
+        position.0 += velocity.0 * DT;
+        position.1 += velocity.1 * DT;
+    }
+
+    assert!(velocity.1 < 0.0);
+    assert!(distance(position, anchor) < start);
+    assert!(grapple.rope_length < start);
+}
+
+/// Tests that the rope never lets the ship drift beyond its length.
+#[test]
+fn grapple_constrains_ship_to_rope() {
+    let anchor = (100.0, 100.0);
+    let mut position = (100.0, 300.0);
+    let mut velocity = (0.0, 200.0);
+    let mut grapple = Grapple::new(anchor, position);
+
+    // Drift away slightly, less than the break stretch
+    position.1 += 10.0;
+    assert_eq!(grapple.update(&mut position, &mut velocity, DT), GrappleStatus::Holding);
+
+    assert!(distance(position, anchor) <= grapple.rope_length + EPSILON);
+    assert!(velocity.1 <= 0.0);
+}
+
+/// Tests that the rope snaps when stretched too far.
+#[test]
+fn grapple_breaks_when_overstretched() {
+    let anchor = (100.0, 100.0);
+    let mut position = (100.0, 300.0);
+    let mut velocity = (0.0, 0.0);
+    let mut grapple = Grapple::new(anchor, position);
+
+    position.1 += GrappleConstants::BREAK_STRETCH + 1.0;
+
+    assert_eq!(grapple.update(&mut position, &mut velocity, DT), GrappleStatus::Broken);
+}
+
+/// Tests that the rope snaps when the scrolling screen keeps dragging the ship away.
+#[test]
+fn grapple_breaks_when_dragged_by_scroll() {
+    let anchor = (100.0, 300.0);
+    let mut position = (150.0, 300.0);
+    let mut velocity = (0.0, 0.0);
+    let mut grapple = Grapple::new(anchor, position);
+
+    let mut status = GrappleStatus::Holding;
+    for _ in 0..120 {
+        // The screen edge carries the ship forward whatever the rope does
+        position.0 += WorldConstants::SCROLL_SPEED * DT;
+        status = grapple.update(&mut position, &mut velocity, DT);
+        if status != GrappleStatus::Holding {
+            break;
+        }
+        velocity = (0.0, 0.0);
+    }
+
+    assert_eq!(status, GrappleStatus::Broken);
+}
+
+/// Tests that moving back toward the anchor relieves the strain.
+#[test]
+fn grapple_strain_recovers() {
+    let anchor = (100.0, 100.0);
+    let mut position = (100.0, 300.0);
+    let mut velocity = (0.0, 0.0);
+    let mut grapple = Grapple::new(anchor, position);
+
+    position.1 += GrappleConstants::BREAK_STRETCH * 0.75;
+    assert_eq!(grapple.update(&mut position, &mut velocity, DT), GrappleStatus::Holding);
+    position.1 -= GrappleConstants::BREAK_STRETCH;
+    assert_eq!(grapple.update(&mut position, &mut velocity, DT), GrappleStatus::Holding);
+    position.1 += GrappleConstants::BREAK_STRETCH * 0.75;
+
+    assert_eq!(grapple.update(&mut position, &mut velocity, DT), GrappleStatus::Holding);
+}
+
+/// Tests that the rope lets go once the ship reaches the anchor.
+#[test]
+fn grapple_releases_on_arrival() {
+    let anchor = (100.0, 100.0);
+    let mut position = (100.0, 100.0 + GrappleConstants::ARRIVAL_DISTANCE);
+    let mut velocity = (0.0, 0.0);
+    let mut grapple = Grapple::new(anchor, position);
+
+    assert_eq!(grapple.update(&mut position, &mut velocity, DT), GrappleStatus::Arrived);
+}
+
+/// Tests that only an active pulling beam can latch.
+#[test]
+fn beam_latches_only_when_pulling() {
+    let anchor = (100.0, 100.0);
+    let player_pos = (100.0, 300.0);
+
+    let mut beam = TractorBeam::new();
+    beam.latch(anchor, player_pos);
+    assert!(!beam.is_grappling());
+
+    beam.polarity = BeamPolarity::Push;
+    beam.activate(BeamDir::Up);
+    beam.latch(anchor, player_pos);
+    assert!(!beam.is_grappling());
+
+    beam.toggle_polarity();
+    beam.latch(anchor, player_pos);
+    assert!(beam.is_grappling());
+
+    // Switching polarity lets go
+    beam.toggle_polarity();
+    assert!(!beam.is_grappling());
+}
+
+/// Tests that grappling burns beam time twice as fast.
+#[test]
+fn grapple_costs_extra_beam_time() {
+    let mut plain = TractorBeam::new();
+    plain.activate(BeamDir::Up);
+    let mut grappling = TractorBeam::new();
+    grappling.activate(BeamDir::Up);
+    grappling.latch((100.0, 100.0), (100.0, 300.0));
+
+    plain.tick(0.5);
+    grappling.tick(0.5);
+
+    let plain_used = TractorBeam::MAX_DURATION - plain.remaining_time();
+    let grapple_used = TractorBeam::MAX_DURATION - grappling.remaining_time();
+    assert_float_eq(grapple_used, plain_used * GrappleConstants::BEAM_COST_MULTIPLIER);
+    assert!(grappling.energy < plain.energy);
+}
+
+/// Tests that the grapple ends with the beam.
+#[test]
+fn grapple_ends_with_beam() {
+    let mut beam = TractorBeam::new();
+    beam.activate(BeamDir::Up);
+    beam.latch((100.0, 100.0), (100.0, 300.0));
+
+    while beam.is_active() {
+        beam.tick(DT);
+    }
+
+    assert!(!beam.is_grappling());
+}
+
+/// Tests that the beam follows the anchor while reeling in.
+#[test]
+fn beam_tracks_anchor_while_grappling() {
+    let anchor = (200.0, 100.0);
+    let mut player = Player::new(Vec2::new(100.0, 300.0));
+    let mut beam = TractorBeam::new();
+    beam.activate(BeamDir::Up);
+    beam.latch(anchor, (player.pos.x, player.pos.y));
+
+    assert_eq!(beam.update_grapple(&mut player, DT), Some(GrappleStatus::Holding));
+
+    let (dir_x, dir_y) = beam.direction();
+    let to_anchor = (anchor.0 - player.pos.x, anchor.1 - player.pos.y);
+    let length = distance_to(&player, anchor);
+    assert_float_eq(dir_x, to_anchor.0 / length);
+    assert_float_eq(dir_y, to_anchor.1 / length);
+
+    beam.release_grapple();
+    assert_eq!(beam.update_grapple(&mut player, DT), None);
+}
+
+/// Tests that each activation latches at most once.
+#[test]
+fn beam_latches_once_per_activation() {
+    let anchor = (100.0, 100.0);
+    let player_pos = (100.0, 300.0);
+    let mut beam = TractorBeam::new();
+    beam.activate(BeamDir::Up);
+
+    beam.latch(anchor, player_pos);
+    beam.release_grapple();
+    beam.latch(anchor, player_pos);
+    assert!(!beam.is_grappling());
+
+    // A fresh burst may latch again
+    while beam.is_active() {
+        beam.tick(DT);
+    }
+    beam.energy = beam.energy.max(50.0);
+    beam.activate(BeamDir::Up);
+    beam.latch(anchor, player_pos);
+    assert!(beam.is_grappling());
+}
@@ -0,0 +1,283 @@
+use core::cave::Cave;
+use core::collision::Aabb;
+use core::constants::HazardConstants;
+use core::game_state::DeathCause;
+use core::hazard::{HazardHit, HazardKind, HazardManager};
+use core::tractor::{BeamDir, BeamPolarity, TractorBeam};
+
+const PLAYER_SIZE: (f32, f32) = (30.0, 18.0);
+
+/// Helper function to get the top-left player position from its centre.
+fn player_box(center: (f32, f32)) -> (f32, f32) {
+    (center.0 - PLAYER_SIZE.0 / 2.0, center.1 - PLAYER_SIZE.1 / 2.0)
+}
+
+/// Tests that no hazards spawn while density is zero.
+#[test]
+fn zero_density_spawns_nothing() {
+    let mut manager = HazardManager::new(7);
+
+    for i in 0..200 {
+        assert!(!manager.should_spawn_hazard(i as f32 * 50.0));
+    }
+}
+
+/// Tests that hazard spawning is deterministic for a seed.
+#[test]
+fn spawning_is_deterministic() {
+    let generate = |seed| {
+        let mut cave = Cave::new(seed);
+        cave.hazard_manager_mut().set_density(2.0);
+        for _ in 0..200 {
+            cave.generate_next(300.0);
+        }
+        cave.hazard_manager().hazards().to_vec()
+    };
+
+    let first = generate(99);
+    assert!(!first.is_empty());
+    assert_eq!(first, generate(99));
+    assert_ne!(first, generate(100));
+}
+
+/// Tests that higher density spawns more hazards.
+#[test]
+fn density_controls_spawn_count() {
+    let count = |density| {
+        let mut manager = HazardManager::new(3);
+        manager.set_density(density);
+        (0..400)
+            .filter(|i| manager.should_spawn_hazard(*i as f32 * 50.0))
+            .count()
+    };
+
+    assert!(count(2.0) > count(0.5));
+}
+
+/// Tests that held spawns do not appear before the given x.
+#[test]
+fn held_spawns_wait_for_position() {
+    let mut manager = HazardManager::new(3);
+    manager.set_density(5.0);
+    manager.hold_spawns_until(5000.0);
+
+    assert!(!manager.should_spawn_hazard(4999.0));
+    assert!(manager.should_spawn_hazard(5000.0));
+}
+
+/// Tests that idle mines drift and stay inside the gap.
+#[test]
+fn mines_drift_within_gap() {
+    let mut manager = HazardManager::new(1);
+    manager.spawn_hazard(500.0, 100.0, 300.0, HazardKind::Mine);
+    let start = manager.hazards()[0].position;
+
+    for _ in 0..600 {
+        manager.update(0.05, (0.0, 200.0));
+        let mine = manager.hazards()[0];
+        assert!(mine.position.1 - HazardConstants::MINE_SIZE / 2.0 >= 100.0);
+        assert!(mine.position.1 + HazardConstants::MINE_SIZE / 2.0 <= 300.0);
+    }
+
+    assert_ne!(manager.hazards()[0].position, start);
+}
+
+/// Tests that a mine steers toward a nearby ship.
+#[test]
+fn mine_chases_nearby_ship() {
+    let mut manager = HazardManager::new(1);
+    manager.spawn_hazard(500.0, 0.0, 600.0, HazardKind::Mine);
+    let mine_y = manager.hazards()[0].position.1;
+    let ship = (440.0, mine_y);
+
+    manager.update(0.1, ship);
+
+    let mine = manager.hazards()[0];
+    assert!(mine.triggered);
+    assert!(mine.position.0 < 500.0);
+}
+
+/// Tests that rocks fall once the ship passes underneath.
+#[test]
+fn rock_falls_when_ship_passes_underneath() {
+    let mut manager = HazardManager::new(1);
+    manager.spawn_hazard(500.0, 100.0, 400.0, HazardKind::Rock);
+    let hanging_y = manager.hazards()[0].position.1;
+
+    // Ship far away: rock stays attached
+    manager.update(0.1, (100.0, 300.0));
+    assert!(!manager.hazards()[0].triggered);
+    assert_eq!(manager.hazards()[0].position.1, hanging_y);
+
+    // Ship approaching underneath: rock falls
+    manager.update(0.1, (460.0, 300.0));
+    manager.update(0.1, (470.0, 300.0));
+    let rock = manager.hazards()[0];
+    assert!(rock.triggered);
+    assert!(rock.position.1 > hanging_y);
+}
+
+/// Tests that a falling rock shatters on the floor.
+#[test]
+fn rock_shatters_on_floor() {
+    let mut manager = HazardManager::new(1);
+    manager.spawn_hazard(500.0, 100.0, 400.0, HazardKind::Rock);
+
+    for _ in 0..100 {
+        manager.update(0.05, (480.0, 300.0));
+    }
+
+    assert_eq!(manager.active_hazard_count(), 0);
+}
+
+/// Tests that turrets fire projectiles toward a ship in range.
+#[test]
+fn turret_fires_at_ship_in_range() {
+    let mut manager = HazardManager::new(1);
+    manager.spawn_hazard(500.0, 100.0, 400.0, HazardKind::Turret);
+
+    manager.update(0.1, (1000.0, 250.0));
+    assert!(manager.projectiles().is_empty());
+
+    manager.update(HazardConstants::TURRET_FIRE_INTERVAL, (300.0, 250.0));
+    assert_eq!(manager.projectiles().len(), 1);
+    assert!(manager.projectiles()[0].velocity.0 < 0.0);
+
+    // Reloading prevents an immediate second shot
+    manager.update(0.1, (300.0, 250.0));
+    assert_eq!(manager.projectiles().len(), 1);
+}
+
+/// Tests that projectiles expire after their lifetime.
+#[test]
+fn projectiles_expire() {
+    let mut manager = HazardManager::new(1);
+    manager.spawn_hazard(500.0, 100.0, 400.0, HazardKind::Turret);
+    manager.update(HazardConstants::TURRET_FIRE_INTERVAL, (300.0, 250.0));
+
+    // Ship out of range so no new shots are fired
+    manager.update(HazardConstants::PROJECTILE_LIFETIME, (5000.0, 250.0));
+
+    assert!(manager.projectiles().is_empty());
+}
+
+/// Tests that colliding with a mine destroys it and reports a hazard.
+#[test]
+fn mine_collision_destroys_mine() {
+    let mut manager = HazardManager::new(1);
+    manager.spawn_hazard(500.0, 0.0, 600.0, HazardKind::Mine);
+    let mine = manager.hazards()[0].position;
+
+    let hit = manager.check_collision(player_box(mine), PLAYER_SIZE);
+
+    assert_eq!(hit, Some(HazardHit::Mine));
+    assert_eq!(hit.unwrap().death_cause(), DeathCause::Hazard);
+    assert_eq!(manager.active_hazard_count(), 0);
+}
+
+/// Tests that turrets remain after a collision and count as obstacles.
+#[test]
+fn turret_collision_is_obstacle() {
+    let mut manager = HazardManager::new(1);
+    manager.spawn_hazard(500.0, 100.0, 400.0, HazardKind::Turret);
+    let turret = manager.hazards()[0].position;
+
+    let hit = manager.check_collision(player_box(turret), PLAYER_SIZE);
+
+    assert_eq!(hit, Some(HazardHit::Turret));
+    assert_eq!(hit.unwrap().death_cause(), DeathCause::Obstacle);
+    assert_eq!(manager.active_hazard_count(), 1);
+}
+
+/// Tests that a distant ship does not collide.
+#[test]
+fn no_collision_when_apart() {
+    let mut manager = HazardManager::new(1);
+    manager.spawn_hazard(500.0, 100.0, 400.0, HazardKind::Rock);
+
+    assert_eq!(manager.check_collision(player_box((100.0, 300.0)), PLAYER_SIZE), None);
+}
+
+/// Tests that a pushing tractor beam shoves mines away from the ship.
+#[test]
+fn tractor_beam_pushes_mines_away() {
+    let mut manager = HazardManager::new(1);
+    manager.spawn_hazard(100.0, 0.0, 600.0, HazardKind::Mine);
+    let start = manager.hazards()[0].position;
+    let player = (start.0, start.1 + 100.0);
+
+    let mut beam = TractorBeam::new();
+    beam.polarity = BeamPolarity::Push;
+    beam.activate(BeamDir::Up);
+    manager.update_tractor_beam(&beam, player, 0.1);
+
+    assert!(manager.hazards()[0].position.1 < start.1);
+}
+
+/// Tests that a pulling tractor beam draws mines toward the ship.
+#[test]
+fn tractor_beam_pulls_mines_closer() {
+    let mut manager = HazardManager::new(1);
+    manager.spawn_hazard(100.0, 0.0, 600.0, HazardKind::Mine);
+    let start = manager.hazards()[0].position;
+    let player = (start.0, start.1 + 100.0);
+
+    let mut beam = TractorBeam::new();
+    beam.activate(BeamDir::Up);
+    manager.update_tractor_beam(&beam, player, 0.1);
+
+    assert!(manager.hazards()[0].position.1 > start.1);
+}
+
+/// Tests that a pushing beam deflects incoming projectiles.
+#[test]
+fn push_beam_deflects_projectiles() {
+    let mut manager = HazardManager::new(1);
+    manager.spawn_hazard(300.0, 100.0, 400.0, HazardKind::Turret);
+    let turret = manager.hazards()[0].position;
+    let player = (turret.0, if turret.1 < 250.0 { 350.0 } else { 150.0 });
+    let dir = if turret.1 < player.1 { BeamDir::Up } else { BeamDir::Down };
+
+    manager.update(HazardConstants::TURRET_FIRE_INTERVAL, player);
+    let incoming = manager.projectiles()[0].velocity;
+
+    let mut beam = TractorBeam::new();
+    beam.polarity = BeamPolarity::Push;
+    beam.activate(dir);
+    manager.update_tractor_beam(&beam, player, 0.016);
+
+    let deflected = manager.projectiles()[0].velocity;
+    assert!(incoming.1 * deflected.1 < 0.0, "Projectile should reverse vertically");
+}
+
+/// Tests that level changes clear hazards.
+#[test]
+fn configure_for_level_clears_hazards() {
+    let mut cave = Cave::new(5);
+    cave.hazard_manager_mut().set_density(3.0);
+    for _ in 0..100 {
+        cave.generate_next(300.0);
+    }
+    assert!(cave.hazard_manager().active_hazard_count() > 0);
+
+    cave.configure_for_level(2);
+
+    assert_eq!(cave.hazard_manager().active_hazard_count(), 0);
+}
+
+/// Tests the clearance from the ship to the nearest hazard.
+#[test]
+fn nearest_clearance_measures_gap() {
+    let mut manager = HazardManager::new(1);
+    let ship = Aabb::new(100.0, 200.0, PLAYER_SIZE.0, PLAYER_SIZE.1);
+    assert_eq!(manager.nearest_clearance(&ship), None);
+
+    // Rock directly above the ship, 20px clear of its top
+    let half = HazardKind::Rock.size() / 2.0;
+    manager.spawn_hazard(115.0, 50.0, 400.0, HazardKind::Rock);
+    let rock = manager.hazards()[0];
+    let expected = ship.top() - (rock.position.1 + half);
+
+    let clearance = manager.nearest_clearance(&ship).unwrap();
+    assert!((clearance - expected).abs() < 0.001);
+}
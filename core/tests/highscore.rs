@@ -0,0 +1,226 @@
+// core/tests/highscore.rs
+
+use core::constants::HighScoreConstants;
+use core::difficulty::Difficulty;
+use core::distance::DistanceTracker;
+use core::game_state::DeathCause;
+use core::highscore::{HighScoreEntry, HighScoreTable};
+use core::run::RunResult;
+use core::score::ScoreBreakdown;
+use core::storage::{MemoryStorage, Storage, StorageError};
+
+/// Entry with the given score and otherwise fixed fields.
+fn entry(score: u32) -> HighScoreEntry {
+    HighScoreEntry {
+        name: "ACE".to_string(),
+        score,
+        distance: score * 2,
+        level_number: 1,
+        seed: 42,
+        date: 1_700_000_000,
+        death_cause: Some(DeathCause::FloorCrash),
+        elapsed_time: 12.5,
+        difficulty: Difficulty::Normal,
+    }
+}
+
+/// Gets the scores in table order.
+fn scores(table: &HighScoreTable) -> Vec<u32> {
+    table.entries().iter().map(|entry| entry.score).collect()
+}
+
+/// Tests that an entry is built from a finished run.
+#[test]
+fn entry_from_run() {
+    let mut tracker = DistanceTracker::new();
+    tracker.update(120.0, 2.5);
+    let run = RunResult::new(DeathCause::Hazard, &tracker, 3).with_score(ScoreBreakdown {
+        distance: 30,
+        pickups: 100,
+        ..Default::default()
+    });
+
+    let entry = HighScoreEntry::from_run(&run, 7, 1_700_000_000).with_name("Rainer");
+
+    assert_eq!(entry.name, "Rainer");
+    assert_eq!(entry.score, 130);
+    assert_eq!(entry.distance, 300);
+    assert_eq!(entry.level_number, 3);
+    assert_eq!(entry.seed, 7);
+    assert_eq!(entry.death_cause, Some(DeathCause::Hazard));
+    assert_eq!(entry.elapsed_time, 2.5);
+}
+
+/// Tests that entries are kept highest first and the rank is reported.
+#[test]
+fn insert_keeps_entries_sorted() {
+    let mut table = HighScoreTable::new(5);
+
+    assert_eq!(table.insert(entry(100)), Some(0));
+    assert_eq!(table.insert(entry(300)), Some(0));
+    assert_eq!(table.insert(entry(200)), Some(1));
+    // Ties rank below the existing run
+    assert_eq!(table.insert(entry(200)), Some(2));
+
+    assert_eq!(scores(&table), vec![300, 200, 200, 100]);
+    assert_eq!(table.best().map(|best| best.score), Some(300));
+}
+
+/// Tests that a full table only accepts better runs and drops the lowest.
+#[test]
+fn full_table_drops_lowest() {
+    let mut table = HighScoreTable::new(3);
+    for score in [100, 200, 300] {
+        table.insert(entry(score));
+    }
+
+    assert!(!table.qualifies(100));
+    assert_eq!(table.insert(entry(50)), None);
+    assert_eq!(table.insert(entry(250)), Some(1));
+
+    assert_eq!(scores(&table), vec![300, 250, 200]);
+}
+
+/// Tests that the default table holds the configured number of runs.
+#[test]
+fn default_capacity() {
+    let mut table = HighScoreTable::default();
+    for score in 0..HighScoreConstants::TABLE_SIZE as u32 + 5 {
+        table.insert(entry(score + 1));
+    }
+
+    assert_eq!(table.entries().len(), HighScoreConstants::TABLE_SIZE);
+}
+
+/// Tests that a table survives a round trip through text.
+#[test]
+fn text_round_trip() {
+    let mut table = HighScoreTable::new(5);
+    table.insert(entry(100));
+    table.insert(HighScoreEntry {
+        death_cause: Some(DeathCause::FuelExhausted),
+        ..entry(200)
+    });
+    table.insert(HighScoreEntry {
+        death_cause: None,
+        elapsed_time: 41.25,
+        difficulty: Difficulty::Insane,
+        ..entry(250)
+    });
+    table.insert(entry(300).with_name("Two Words"));
+
+    let restored = HighScoreTable::from_text(&table.to_text(), 5).unwrap();
+
+    assert_eq!(restored, table);
+}
+
+/// Tests that a damaged record is reported instead of silently dropped.
+#[test]
+fn corrupt_text_is_an_error() {
+    let result = HighScoreTable::from_text("100,200,1,42,0,floor crash,12.50,normal,ACE\nnot a score\n", 5);
+
+    match result {
+        Err(StorageError::Corrupt { key, reason }) => {
+            assert_eq!(key, HighScoreConstants::STORAGE_KEY);
+            assert!(reason.starts_with("line 2"));
+        }
+        other => panic!("expected corrupt record error, got {:?}", other),
+    }
+}
+
+/// Tests that numbers too large for their field are rejected rather than truncated.
+#[test]
+fn out_of_range_numbers_are_an_error() {
+    let result = HighScoreTable::from_text("4294967296,200,1,42,0,floor crash,12.50,normal,ACE\n", 5);
+
+    assert!(matches!(result, Err(StorageError::Corrupt { .. })));
+}
+
+/// Tests that the difficulty preset is recorded and survives a round trip.
+#[test]
+fn difficulty_is_recorded() {
+    let run = RunResult::new(DeathCause::Hazard, &DistanceTracker::new(), 2);
+    let hard = HighScoreEntry::from_run(&run, 7, 0).with_difficulty(Difficulty::Hard);
+    assert_eq!(hard.difficulty, Difficulty::Hard);
+
+    let mut table = HighScoreTable::new(5);
+    table.insert(hard);
+    let restored = HighScoreTable::from_text(&table.to_text(), 5).unwrap();
+    assert_eq!(restored.entries()[0].difficulty, Difficulty::Hard);
+
+    let unknown = HighScoreTable::from_text("100,200,1,42,0,hazard,12.50,nightmare,ACE\n", 5);
+    assert!(matches!(unknown, Err(StorageError::Corrupt { .. })));
+}
+
+/// Tests that completed runs are shown as finished rather than by a death cause.
+#[test]
+fn completed_runs_are_finished() {
+    let run = RunResult::completed(&DistanceTracker::new(), 6);
+
+    let finished = HighScoreEntry::from_run(&run, 7, 0);
+
+    assert_eq!(finished.death_cause, None);
+    assert_eq!(finished.outcome(), "finished");
+    assert_eq!(entry(1).outcome(), DeathCause::FloorCrash.to_string());
+}
+
+/// Tests that commas in a name cannot break the saved line.
+#[test]
+fn commas_are_dropped_from_names() {
+    let mut table = HighScoreTable::new(5);
+    table.insert(entry(100).with_name("A,B"));
+
+    let restored = HighScoreTable::from_text(&table.to_text(), 5).unwrap();
+
+    assert_eq!(restored.entries()[0].name, "AB");
+}
+
+/// Tests that an unknown death cause is rejected.
+#[test]
+fn unknown_death_cause_is_an_error() {
+    let result = HighScoreTable::from_text("100,200,1,42,0,alien abduction,12.50,normal,ACE\n", 5);
+
+    assert!(matches!(result, Err(StorageError::Corrupt { .. })));
+}
+
+/// Tests that the table is saved to and loaded from storage.
+#[test]
+fn save_and_load_through_storage() {
+    let mut storage = MemoryStorage::new();
+    assert_eq!(HighScoreTable::load(&storage).unwrap(), HighScoreTable::default());
+
+    let mut table = HighScoreTable::default();
+    table.insert(entry(150));
+    table.save(&mut storage).unwrap();
+
+    assert!(storage.load(HighScoreConstants::STORAGE_KEY).unwrap().is_some());
+    assert_eq!(HighScoreTable::load(&storage).unwrap(), table);
+}
+
+/// Tests that tables under different keys are kept apart.
+#[test]
+fn tables_are_kept_per_key() {
+    let mut storage = MemoryStorage::new();
+    let mut table = HighScoreTable::default();
+    table.insert(entry(150));
+    table.save_to(&mut storage, "highscores_zen").unwrap();
+
+    assert_eq!(HighScoreTable::load_from(&storage, "highscores_zen").unwrap(), table);
+    assert_eq!(HighScoreTable::load(&storage).unwrap(), HighScoreTable::default());
+
+    storage.save("highscores_zen", "garbage\n").unwrap();
+    match HighScoreTable::load_from(&storage, "highscores_zen") {
+        Err(StorageError::Corrupt { key, .. }) => assert_eq!(key, "highscores_zen"),
+        other => panic!("expected corrupt record error, got {:?}", other),
+    }
+}
+
+/// Tests that dates are shown as UTC calendar days.
+#[test]
+fn date_formatting() {
+    let at = |date| HighScoreEntry { date, ..entry(1) };
+
+    assert_eq!(at(0).date_formatted(), "1970-01-01");
+    assert_eq!(at(951_782_400).date_formatted(), "2000-02-29");
+    assert_eq!(at(1_700_000_000).date_formatted(), "2023-11-14");
+}
@@ -0,0 +1,97 @@
+use core::constants::HullConstants;
+use core::hull::{DamageModel, Hull, ImpactOutcome};
+
+/// Tests that instant death stays the default damage model.
+#[test]
+fn default_damage_model_is_instant_death() {
+    assert_eq!(DamageModel::default(), DamageModel::InstantDeath);
+}
+
+/// Tests that a new hull starts at full points without shields.
+#[test]
+fn new_hull_is_undamaged() {
+    let hull = Hull::default();
+
+    assert_eq!(hull.points, HullConstants::MAX_POINTS);
+    assert_eq!(hull.shield_charges, 0);
+    assert_eq!(hull.ratio(), 1.0);
+    assert!(!hull.is_invulnerable());
+}
+
+/// Tests that damage scales with impact speed.
+#[test]
+fn damage_scales_with_impact_speed() {
+    let slow = Hull::damage_for_speed(100.0);
+    let fast = Hull::damage_for_speed(300.0);
+
+    assert!(fast > slow);
+    assert_eq!(fast, 300.0 * HullConstants::DAMAGE_PER_IMPACT_SPEED);
+}
+
+/// Tests that gentle scrapes still cost the minimum damage.
+#[test]
+fn scrape_costs_minimum_damage() {
+    let mut hull = Hull::default();
+
+    let outcome = hull.take_impact(1.0);
+
+    assert_eq!(outcome, ImpactOutcome::Damaged(HullConstants::MIN_DAMAGE));
+    assert_eq!(hull.points, HullConstants::MAX_POINTS - HullConstants::MIN_DAMAGE);
+}
+
+/// Tests that hits during invulnerability are ignored.
+#[test]
+fn invulnerability_ignores_hits() {
+    let mut hull = Hull::default();
+    hull.take_impact(200.0);
+    let points_after_hit = hull.points;
+
+    assert!(hull.is_invulnerable());
+    assert_eq!(hull.take_impact(200.0), ImpactOutcome::Ignored);
+    assert_eq!(hull.points, points_after_hit);
+}
+
+/// Tests that invulnerability expires over time.
+#[test]
+fn invulnerability_expires_after_tick() {
+    let mut hull = Hull::default();
+    hull.take_impact(200.0);
+
+    hull.tick(HullConstants::INVULNERABILITY_DURATION + 0.1);
+
+    assert!(!hull.is_invulnerable());
+    assert!(matches!(hull.take_impact(200.0), ImpactOutcome::Damaged(_)));
+}
+
+/// Tests that a shield absorbs a hit without hull damage.
+#[test]
+fn shield_absorbs_hit() {
+    let mut hull = Hull::default();
+    hull.add_shield();
+
+    assert_eq!(hull.take_impact(500.0), ImpactOutcome::Absorbed);
+    assert_eq!(hull.points, HullConstants::MAX_POINTS);
+    assert_eq!(hull.shield_charges, 0);
+}
+
+/// Tests that shield charges are capped.
+#[test]
+fn shield_charges_are_capped() {
+    let mut hull = Hull::default();
+
+    for _ in 0..10 {
+        hull.add_shield();
+    }
+
+    assert_eq!(hull.shield_charges, HullConstants::MAX_SHIELD_CHARGES);
+}
+
+/// Tests that the hull is destroyed when points reach zero.
+#[test]
+fn hull_destroyed_at_zero_points() {
+    let mut hull = Hull::new(10.0);
+
+    assert_eq!(hull.take_impact(1000.0), ImpactOutcome::Destroyed);
+    assert!(hull.is_destroyed());
+    assert_eq!(hull.points, 0.0);
+}
@@ -2,7 +2,7 @@
 
 use core::cave::{Cave, CaveSegment};
 use core::collision::aabb_overlap;
-use core::game_state::{GameEvent, GameState, StateMachine};
+use core::game_state::{DeathCause, GameEvent, GameState, StateMachine};
 use core::player::{Player, PlayerInput, Vec2};
 use core::tractor::{BeamDir, TractorBeam};
 
@@ -130,7 +130,7 @@ fn game_state_transitions_on_death() {
     assert_eq!(state_machine.current(), GameState::Playing);
 
     // Simulate collision death
-    state_machine.handle_event(GameEvent::Dead);
+    state_machine.handle_event(GameEvent::Dead(DeathCause::CeilingCrash));
     assert_eq!(state_machine.current(), GameState::GameOver);
 
     // Restart game
@@ -0,0 +1,66 @@
+// core/tests/landing.rs
+
+use core::cave::CaveSegment;
+use core::collision::Contact;
+use core::constants::LandingPadConstants;
+use core::landing::{is_resting_on_pad, Touchdown};
+
+/// Creates a pad segment with its floor at y = 400.
+fn pad_segment() -> CaveSegment {
+    let mut segment = CaveSegment::new(100.0, 400.0, 0.0, 50.0);
+    segment.landing_pad = true;
+    segment
+}
+
+/// Contact with the top of a floor.
+const FLOOR_CONTACT: Contact = Contact {
+    normal: (0.0, -1.0),
+    depth: 1.0,
+};
+
+/// Tests that a slow touchdown on a pad is a landing.
+#[test]
+fn slow_touchdown_on_pad_lands() {
+    let velocity = (0.0, LandingPadConstants::MAX_LANDING_SPEED * 0.5);
+
+    assert_eq!(Touchdown::classify(&FLOOR_CONTACT, &pad_segment(), velocity), Touchdown::Landed);
+}
+
+/// Tests that a fast touchdown on a pad is still a crash.
+#[test]
+fn fast_touchdown_on_pad_crashes() {
+    let velocity = (0.0, LandingPadConstants::MAX_LANDING_SPEED + 1.0);
+
+    assert_eq!(Touchdown::classify(&FLOOR_CONTACT, &pad_segment(), velocity), Touchdown::Crashed);
+}
+
+/// Tests that only the top of a pad segment can be landed on.
+#[test]
+fn touchdown_off_pad_crashes() {
+    let velocity = (0.0, 10.0);
+    let plain_floor = CaveSegment::new(100.0, 400.0, 0.0, 50.0);
+    let ceiling_contact = Contact {
+        normal: (0.0, 1.0),
+        depth: 1.0,
+    };
+
+    assert_eq!(Touchdown::classify(&FLOOR_CONTACT, &plain_floor, velocity), Touchdown::Crashed);
+    assert_eq!(Touchdown::classify(&ceiling_contact, &pad_segment(), velocity), Touchdown::Crashed);
+}
+
+/// Tests resting detection on the pad surface.
+#[test]
+fn resting_requires_pad_surface_and_low_speed() {
+    let pad = pad_segment();
+
+    assert!(is_resting_on_pad(&pad, 400.0, (0.0, 0.0)));
+    assert!(is_resting_on_pad(&pad, 400.0 - LandingPadConstants::REST_TOLERANCE, (0.0, 0.0)));
+
+    // Hovering above the pad or moving too fast
+    assert!(!is_resting_on_pad(&pad, 390.0, (0.0, 0.0)));
+    assert!(!is_resting_on_pad(&pad, 400.0, (LandingPadConstants::MAX_LANDING_SPEED + 1.0, 0.0)));
+
+    // Plain floor never refuels
+    let plain_floor = CaveSegment::new(100.0, 400.0, 0.0, 50.0);
+    assert!(!is_resting_on_pad(&plain_floor, 400.0, (0.0, 0.0)));
+}
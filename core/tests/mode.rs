@@ -0,0 +1,164 @@
+// core/tests/mode.rs
+
+use core::constants::{GameModeConstants, HighScoreConstants, ScoreConstants, WorldConstants};
+use core::difficulty::Difficulty;
+use core::mode::GameMode;
+use core::pickup::PickupType;
+use core::run::RunEvent;
+use core::score::Score;
+
+/// Tests that modes cycle through the menu order in both directions.
+#[test]
+fn modes_cycle_in_menu_order() {
+    let mut mode = GameMode::Campaign;
+    for expected in [GameMode::Endless, GameMode::TimeAttack, GameMode::Zen, GameMode::Campaign] {
+        mode = mode.next();
+        assert_eq!(mode, expected);
+    }
+    for current in GameMode::ALL {
+        assert_eq!(current.next().previous(), current);
+    }
+    assert_eq!(GameMode::default(), GameMode::Endless);
+}
+
+/// Tests that modes can be looked up by their storage name.
+#[test]
+fn mode_from_name() {
+    for mode in GameMode::ALL {
+        assert_eq!(GameMode::from_name(&mode.to_string()), Some(mode));
+    }
+    assert_eq!(GameMode::from_name("time_attack"), Some(GameMode::TimeAttack));
+    assert_eq!(GameMode::from_name("hardcore"), None);
+}
+
+/// Tests that every mode and difficulty keeps its own high-score table.
+#[test]
+fn high_score_keys_are_per_mode_and_difficulty() {
+    assert_eq!(
+        GameMode::TimeAttack.high_score_key(Difficulty::Hard),
+        format!("{}_time_attack_hard", HighScoreConstants::STORAGE_KEY)
+    );
+
+    let mut keys: Vec<String> = GameMode::ALL
+        .iter()
+        .flat_map(|mode| Difficulty::ALL.iter().map(move |difficulty| mode.high_score_key(*difficulty)))
+        .collect();
+    keys.sort();
+    keys.dedup();
+    assert_eq!(keys.len(), GameMode::ALL.len() * Difficulty::ALL.len());
+}
+
+/// Tests that only zen turns off fuel drain, death and progress tracking.
+#[test]
+fn zen_has_no_fuel_drain_or_death() {
+    assert!(!GameMode::Zen.burns_fuel());
+    assert!(!GameMode::Zen.is_lethal());
+    assert!(!GameMode::Zen.tracks_progress());
+    for mode in [GameMode::Campaign, GameMode::Endless, GameMode::TimeAttack] {
+        assert!(mode.burns_fuel());
+        assert!(mode.is_lethal());
+        assert!(mode.tracks_progress());
+    }
+}
+
+/// Tests that only time attack boosts the scroll speed and has a finish line.
+#[test]
+fn time_attack_boost_and_target() {
+    let boosted = GameMode::TimeAttack.scroll_speed(true);
+    assert_eq!(boosted, WorldConstants::SCROLL_SPEED * GameModeConstants::TIME_ATTACK_BOOST_MULTIPLIER);
+    assert_eq!(GameMode::TimeAttack.scroll_speed(false), WorldConstants::SCROLL_SPEED);
+    assert_eq!(GameMode::Endless.scroll_speed(true), WorldConstants::SCROLL_SPEED);
+
+    assert!(!GameMode::TimeAttack.reached_target(GameModeConstants::TIME_ATTACK_DISTANCE - 1.0));
+    assert!(GameMode::TimeAttack.reached_target(GameModeConstants::TIME_ATTACK_DISTANCE));
+    assert_eq!(GameMode::Endless.target_distance(), None);
+    assert!(!GameMode::Zen.reached_target(f32::MAX));
+}
+
+/// Tests that the campaign ends on clearing its last level and endless never does.
+#[test]
+fn campaign_completes_on_last_level() {
+    let campaign = GameMode::Campaign.level_manager();
+    assert!(!GameMode::Campaign.completes_on_clear(&campaign));
+
+    let mut manager = GameMode::Campaign.level_manager();
+    let mut time = 0.0;
+    while !manager.is_last_level() {
+        time += manager.current_level().unwrap().duration_seconds;
+        manager.update(time).unwrap();
+    }
+    assert!(GameMode::Campaign.completes_on_clear(&manager));
+    assert!(!GameMode::Endless.completes_on_clear(&manager));
+}
+
+/// Tests that endless keeps adding harder levels past the configured ones.
+#[test]
+fn endless_levels_keep_escalating() {
+    let mut manager = GameMode::Endless.level_manager();
+    let configured = manager.levels().len() as u32;
+
+    let mut time = 0.0;
+    for _ in 0..configured + 2 {
+        time += manager.current_level().unwrap().duration_seconds;
+        manager.update(time).unwrap();
+    }
+
+    assert_eq!(manager.current_level_number(), configured + 3);
+    let levels = manager.levels();
+    let last = &levels[levels.len() - 1];
+    let before = &levels[levels.len() - 2];
+    assert!(last.hazard_density >= before.hazard_density);
+    assert!(last.refuel_rate <= before.refuel_rate);
+}
+
+/// Tests that only completed time attacks make the table.
+#[test]
+fn time_attack_ranks_only_finished_runs() {
+    assert!(!GameMode::TimeAttack.ranks_run(false));
+    assert!(GameMode::TimeAttack.ranks_run(true));
+    assert!(GameMode::Endless.ranks_run(false));
+    assert!(GameMode::Zen.ranks_run(true));
+}
+
+/// Tests that time attack scores the finish time only, faster runs scoring more.
+#[test]
+fn time_attack_scores_finish_time() {
+    let finish = |time| {
+        let mut score = Score::for_mode(GameMode::TimeAttack);
+        score.apply(&RunEvent::PickupCollected { pickup_type: PickupType::Fuel, beam_catch: true });
+        score.record_distance(GameModeConstants::TIME_ATTACK_DISTANCE);
+        score.finish(time);
+        score
+    };
+
+    let par = finish(GameModeConstants::TIME_ATTACK_PAR_SECONDS);
+    assert_eq!(par.total(), ScoreConstants::TIME_ATTACK_PAR_POINTS as u32);
+    assert_eq!(par.breakdown().finish_bonus, par.total());
+    assert!(finish(30.0).total() > par.total());
+}
+
+/// Tests that campaign victory adds a fixed bonus on top of endless-style scoring.
+#[test]
+fn campaign_victory_bonus() {
+    let mut score = Score::for_mode(GameMode::Campaign);
+    score.record_distance(1000.0);
+    let before = score.total();
+
+    score.finish(120.0);
+
+    assert_eq!(score.total(), before + ScoreConstants::CAMPAIGN_VICTORY_BONUS);
+    assert_eq!(Score::new().mode(), GameMode::Endless);
+}
+
+/// Tests that zen scores pickups but not distance or level clears.
+#[test]
+fn zen_scores_skill_only() {
+    let mut score = Score::for_mode(GameMode::Zen);
+    score.record_distance(5000.0);
+    score.apply(&RunEvent::LevelCleared { level_number: 1, fuel_ratio: 1.0 });
+    assert_eq!(score.total(), 0);
+
+    score.apply(&RunEvent::PickupCollected { pickup_type: PickupType::Fuel, beam_catch: false });
+    score.finish(60.0);
+    assert_eq!(score.total(), ScoreConstants::PICKUP_POINTS);
+}
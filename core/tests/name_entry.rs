@@ -0,0 +1,100 @@
+// core/tests/name_entry.rs
+
+use core::constants::HighScoreConstants;
+use core::name_entry::{NameEntry, NameEntryMode};
+
+/// Tests that entry starts with the last name, cleaned up and uppercased like typed letters.
+#[test]
+fn prefills_last_name() {
+    let entry = NameEntry::new("  Ace,Pilot!  ");
+
+    assert_eq!(entry.text(), "ACEPILOT");
+    assert_eq!(entry.mode(), NameEntryMode::Keyboard);
+}
+
+/// Tests that typing appends uppercase letters up to the length limit.
+#[test]
+fn typing_appends_until_full() {
+    let mut entry = NameEntry::new("");
+
+    assert!(entry.type_char('a'));
+    assert!(entry.type_char('7'));
+    assert!(!entry.type_char(','));
+    assert_eq!(entry.text(), "A7");
+
+    for _ in 0..HighScoreConstants::MAX_NAME_LENGTH {
+        entry.type_char('z');
+    }
+    assert_eq!(entry.text().len(), HighScoreConstants::MAX_NAME_LENGTH);
+}
+
+/// Tests that backspace removes the last character.
+#[test]
+fn backspace_removes_last_char() {
+    let mut entry = NameEntry::new("ACE");
+
+    entry.backspace();
+    assert_eq!(entry.text(), "AC");
+
+    entry.backspace();
+    entry.backspace();
+    entry.backspace();
+    assert_eq!(entry.text(), "");
+}
+
+/// Tests that cycling switches to three-letter arcade entry.
+#[test]
+fn cycling_switches_to_arcade_entry() {
+    let mut long = NameEntry::new("Rainer");
+    long.cycle_up();
+    assert_eq!(long.mode(), NameEntryMode::Arcade);
+    assert_eq!(long.text(), "SAI");
+
+    let mut short = NameEntry::new("");
+    short.cycle_down();
+    assert_eq!(short.text().len(), HighScoreConstants::ARCADE_NAME_LENGTH);
+    // Cycling back from the first letter wraps to the end of the alphabet
+    assert_eq!(short.text(), " AA");
+}
+
+/// Tests that cycling walks the whole alphabet and wraps around.
+#[test]
+fn cycling_wraps_around_alphabet() {
+    let mut entry = NameEntry::new("AAA");
+    let alphabet_length = HighScoreConstants::ARCADE_ALPHABET.chars().count();
+
+    for _ in 0..alphabet_length {
+        entry.cycle_up();
+    }
+
+    assert_eq!(entry.text(), "AAA");
+}
+
+/// Tests that the arcade cursor moves between slots and stops at the ends.
+#[test]
+fn arcade_slots_are_bounded() {
+    let mut entry = NameEntry::new("AAA");
+
+    entry.previous_slot();
+    assert_eq!(entry.cursor(), 0);
+
+    entry.next_slot();
+    entry.cycle_up();
+    entry.next_slot();
+    entry.next_slot();
+    entry.cycle_up();
+    entry.cycle_up();
+
+    assert_eq!(entry.cursor(), HighScoreConstants::ARCADE_NAME_LENGTH - 1);
+    assert_eq!(entry.text(), "ABC");
+}
+
+/// Tests that a blank name falls back to the placeholder.
+#[test]
+fn blank_name_uses_placeholder() {
+    let mut entry = NameEntry::new("");
+    entry.type_char(' ');
+
+    assert_eq!(entry.name(), HighScoreConstants::DEFAULT_NAME);
+    assert_eq!(NameEntry::new(" Ace ").name(), "ACE");
+}
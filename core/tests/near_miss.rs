@@ -0,0 +1,95 @@
+// core/tests/near_miss.rs
+
+use core::constants::NearMissConstants;
+use core::near_miss::{Clearance, NearMissDetector};
+use core::run::RunEvent;
+
+const DT: f32 = 1.0 / 60.0; // 60 FPS
+
+/// Clearance with only the floor at the given distance.
+fn floor_at(distance: f32) -> Clearance {
+    Clearance {
+        ceiling: 200.0,
+        floor: distance,
+        obstacle: None,
+    }
+}
+
+/// Flies one close pass and returns the event from leaving the zone.
+fn close_pass(detector: &mut NearMissDetector) -> Option<RunEvent> {
+    assert_eq!(detector.update(floor_at(NearMissConstants::THRESHOLD / 2.0), false, DT), None);
+    detector.update(floor_at(100.0), false, DT)
+}
+
+/// Tests that the closest of the distances counts.
+#[test]
+fn clearance_uses_closest_distance() {
+    let clearance = Clearance {
+        ceiling: 30.0,
+        floor: 40.0,
+        obstacle: Some(8.0),
+    };
+    assert_eq!(clearance.min(), 8.0);
+    assert_eq!(floor_at(50.0).min(), 50.0);
+}
+
+/// Tests that a clean close pass emits a near-miss on leaving the zone.
+#[test]
+fn clean_pass_is_a_near_miss() {
+    let mut detector = NearMissDetector::new();
+
+    assert_eq!(detector.update(floor_at(100.0), false, DT), None);
+    assert_eq!(close_pass(&mut detector), Some(RunEvent::NearMiss { multiplier: 1 }));
+    assert_eq!(detector.closest(), Some(100.0));
+}
+
+/// Tests that hovering across the threshold scores once, until the clearance recovers.
+#[test]
+fn pass_ends_only_after_recovering() {
+    let mut detector = NearMissDetector::new();
+    let just_outside = floor_at(NearMissConstants::THRESHOLD + 1.0);
+
+    for _ in 0..5 {
+        assert_eq!(detector.update(floor_at(NearMissConstants::THRESHOLD), false, DT), None);
+        assert_eq!(detector.update(just_outside, false, DT), None);
+    }
+    assert_eq!(
+        detector.update(floor_at(NearMissConstants::RECOVERY_CLEARANCE + 1.0), false, DT),
+        Some(RunEvent::NearMiss { multiplier: 1 })
+    );
+}
+
+/// Tests that touching during the pass cancels it and breaks the streak.
+#[test]
+fn touching_cancels_the_pass() {
+    let mut detector = NearMissDetector::new();
+    close_pass(&mut detector);
+
+    detector.update(floor_at(0.0), true, DT);
+    assert_eq!(detector.update(floor_at(100.0), false, DT), None);
+    assert_eq!(detector.streak(), 0);
+}
+
+/// Tests that consecutive passes build the multiplier up to its cap.
+#[test]
+fn streak_builds_multiplier() {
+    let mut detector = NearMissDetector::new();
+
+    for expected in 1..=NearMissConstants::MAX_MULTIPLIER + 2 {
+        let multiplier = expected.min(NearMissConstants::MAX_MULTIPLIER);
+        assert_eq!(close_pass(&mut detector), Some(RunEvent::NearMiss { multiplier }));
+    }
+}
+
+/// Tests that the streak lapses after the window without near-misses.
+#[test]
+fn streak_lapses_after_window() {
+    let mut detector = NearMissDetector::new();
+    close_pass(&mut detector);
+    close_pass(&mut detector);
+    assert_eq!(detector.streak(), 2);
+
+    detector.update(floor_at(100.0), false, NearMissConstants::STREAK_WINDOW + 0.1);
+    assert_eq!(detector.streak(), 0);
+    assert_eq!(close_pass(&mut detector), Some(RunEvent::NearMiss { multiplier: 1 }));
+}
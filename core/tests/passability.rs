@@ -0,0 +1,208 @@
+use core::cave::{Cave, CaveSegment};
+use core::collision::Aabb;
+use core::passability::{PassabilityError, PassabilityValidator, ShipLimits};
+use core::player::PlayerConstants;
+
+/// Helper function to build a straight corridor of equal segments.
+fn corridor(ceiling: f32, floor: f32, count: usize) -> Vec<CaveSegment> {
+    (0..count)
+        .map(|i| CaveSegment::new(ceiling, floor, i as f32 * 50.0, 50.0))
+        .collect()
+}
+
+/// Tests that default limits mirror the player physics.
+#[test]
+fn default_limits_match_player() {
+    let limits = ShipLimits::default();
+
+    assert_eq!(limits.width, PlayerConstants::WIDTH);
+    assert_eq!(limits.height, PlayerConstants::HEIGHT);
+    assert_eq!(limits.thrust, PlayerConstants::THRUST);
+    assert!(limits.down_thrust > 0.0);
+}
+
+/// Tests that an empty stretch is rejected.
+#[test]
+fn empty_stretch_is_an_error() {
+    let validator = PassabilityValidator::default();
+
+    assert_eq!(
+        validator.find_path(&[], &[]),
+        Err(PassabilityError::EmptyStretch)
+    );
+}
+
+/// Tests that a straight open corridor is passable.
+#[test]
+fn open_corridor_is_passable() {
+    let validator = PassabilityValidator::default();
+    let segments = corridor(100.0, 400.0, 10);
+
+    let path = validator.find_path(&segments, &[]).unwrap();
+
+    assert!(path.len() > 1);
+    let (last_x, _) = *path.last().unwrap();
+    assert!(last_x >= 500.0 - PlayerConstants::WIDTH / 2.0 - 10.0);
+}
+
+/// Tests that every path point keeps the ship inside the corridor.
+#[test]
+fn path_stays_inside_corridor() {
+    let validator = PassabilityValidator::default();
+    let segments = corridor(200.0, 300.0, 8);
+
+    let path = validator.find_path(&segments, &[]).unwrap();
+
+    for (_, y) in path {
+        assert!(y - PlayerConstants::HEIGHT / 2.0 >= 200.0);
+        assert!(y + PlayerConstants::HEIGHT / 2.0 <= 300.0);
+    }
+}
+
+/// Tests that a gap smaller than the ship is impassable.
+#[test]
+fn gap_narrower_than_ship_is_blocked() {
+    let validator = PassabilityValidator::default();
+    let segments = corridor(200.0, 210.0, 5);
+
+    assert!(matches!(
+        validator.find_path(&segments, &[]),
+        Err(PassabilityError::Blocked { .. })
+    ));
+}
+
+/// Tests that consecutive gaps without vertical overlap are impassable.
+#[test]
+fn disjoint_gaps_are_blocked() {
+    let validator = PassabilityValidator::default();
+    let mut segments = corridor(350.0, 550.0, 4);
+    segments.extend((4..8).map(|i| CaveSegment::new(50.0, 90.0, i as f32 * 50.0, 50.0)));
+
+    match validator.find_path(&segments, &[]) {
+        Err(PassabilityError::Blocked { furthest_x }) => assert!(furthest_x < 200.0),
+        other => panic!("Expected blocked stretch, got {:?}", other),
+    }
+}
+
+/// Tests that a reachable step in the cave is passable.
+#[test]
+fn overlapping_step_is_passable() {
+    let validator = PassabilityValidator::default();
+    let mut segments = corridor(300.0, 500.0, 4);
+    segments.extend((4..10).map(|i| CaveSegment::new(150.0, 350.0, i as f32 * 50.0, 50.0)));
+
+    assert!(validator.is_passable(&segments, &[]));
+}
+
+/// Tests that an obstacle filling the gap blocks the stretch.
+#[test]
+fn full_height_obstacle_blocks() {
+    let validator = PassabilityValidator::default();
+    let segments = corridor(100.0, 400.0, 10);
+    let wall = Aabb::new(240.0, 100.0, 20.0, 300.0);
+
+    assert!(!validator.is_passable(&segments, &[wall]));
+}
+
+/// Tests that the path steers around a partial obstacle.
+#[test]
+fn path_avoids_partial_obstacle() {
+    let validator = PassabilityValidator::default();
+    let segments = corridor(100.0, 400.0, 12);
+    let block = Aabb::new(300.0, 100.0, 20.0, 200.0);
+
+    let path = validator.find_path(&segments, &[block]).unwrap();
+
+    for (x, y) in path {
+        let overlaps_x = x + PlayerConstants::WIDTH / 2.0 > 300.0
+            && x - PlayerConstants::WIDTH / 2.0 < 320.0;
+        if overlaps_x {
+            assert!(y - PlayerConstants::HEIGHT / 2.0 >= 300.0);
+        }
+    }
+}
+
+/// Tests that a slower ship cannot follow a climb a faster one can.
+#[test]
+fn ship_limits_affect_result() {
+    // Narrow passage climbing 25px per segment
+    let segments: Vec<_> = (0..12)
+        .map(|i| {
+            let ceiling = 400.0 - 25.0 * i as f32;
+            CaveSegment::new(ceiling, ceiling + 60.0, i as f32 * 50.0, 50.0)
+        })
+        .collect();
+
+    let agile = PassabilityValidator::default();
+    let sluggish = PassabilityValidator::new(ShipLimits {
+        thrust: -10.0,
+        ..ShipLimits::default()
+    });
+
+    assert!(agile.is_passable(&segments, &[]));
+    assert!(!sluggish.is_passable(&segments, &[]));
+}
+
+/// Tests that generated caves are passable with the default ship.
+#[test]
+fn generated_caves_are_passable() {
+    let validator = PassabilityValidator::default();
+
+    for seed in 0..5 {
+        for level in 1..=6 {
+            let mut cave = Cave::new(seed);
+            cave.configure_for_level(level);
+            for _ in 0..60 {
+                cave.generate_next(300.0);
+            }
+
+            let segments: Vec<_> = cave.segments().iter().copied().collect();
+            assert!(
+                validator.is_passable(&segments, &[]),
+                "Seed {} level {} should be passable",
+                seed,
+                level
+            );
+        }
+    }
+}
+
+/// Tests that the generator repairs sections too tight for the ship.
+#[test]
+fn generator_repairs_impassable_sections() {
+    let tall_ship = PassabilityValidator::new(ShipLimits {
+        height: 144.0,
+        ..ShipLimits::default()
+    });
+
+    let mut cave = Cave::new(2024);
+    cave.set_validator(Some(tall_ship));
+    cave.configure_for_level(6);
+    for _ in 0..40 {
+        cave.generate_next(300.0);
+    }
+
+    assert!(cave.repair_count() > 0);
+    let segments: Vec<_> = cave.segments().iter().copied().collect();
+    assert!(tall_ship.is_passable(&segments, &[]));
+}
+
+/// Tests that disabling the validator keeps raw generator output.
+#[test]
+fn disabled_validator_skips_repair() {
+    let tall_ship = PassabilityValidator::new(ShipLimits {
+        height: 144.0,
+        ..ShipLimits::default()
+    });
+
+    let mut cave = Cave::new(2024);
+    cave.set_validator(None);
+    cave.configure_for_level(6);
+    for _ in 0..40 {
+        cave.generate_next(300.0);
+    }
+
+    assert_eq!(cave.repair_count(), 0);
+    let segments: Vec<_> = cave.segments().iter().copied().collect();
+    assert!(!tall_ship.is_passable(&segments, &[]));
+}
@@ -1,4 +1,4 @@
-use core::player::{Player, PlayerConstants, PlayerInput, Vec2};
+use core::player::{Player, PlayerConstants, PlayerInput, Throttle, Vec2};
 
 const DT: f32 = 1.0 / 60.0; // 60 FPS
 const EPSILON: f32 = 0.001;
@@ -47,6 +47,21 @@ fn up_thrust_affects_velocity_y() {
     );
 }
 
+/// Tests that analog throttle scales thrust.
+#[test]
+fn analog_throttle_scales_thrust() {
+    let mut player = create_test_player();
+    let initial_vel_y = player.vel.y;
+
+    let input = PlayerInput {
+        throttle: Some(Throttle { up: 0.5, ..Default::default() }),
+        ..Default::default()
+    };
+    player.tick(DT, input, 0.0, 0.0);
+
+    assert_float_eq(player.vel.y, initial_vel_y + PlayerConstants::THRUST * 0.5 * DT);
+}
+
 /// Tests that downward thrust increases downward velocity.
 #[test]
 fn down_thrust_increases_velocity_y() {
@@ -184,3 +199,29 @@ fn combined_physics_interactions() {
     assert!(player.vel.x > initial_vel.x); // Right thrust
     assert!(player.vel.y != initial_vel.y); // Up thrust
 }
+
+/// Tests that bouncing off a ceiling pushes the player down and reverses velocity.
+#[test]
+fn bounce_off_ceiling_reverses_velocity() {
+    let mut player = create_test_player();
+    player.vel = Vec2::new(20.0, -200.0); // Moving up into the ceiling
+
+    let impact_speed = player.bounce((0.0, 1.0), 3.0, 0.5, 10.0);
+
+    assert_float_eq(impact_speed, 200.0);
+    assert_float_eq(player.pos.y, 303.0);
+    assert_float_eq(player.vel.y, 100.0);
+    assert_float_eq(player.vel.x, 20.0); // Tangential velocity unchanged
+}
+
+/// Tests that a gentle scrape still separates the player from the wall.
+#[test]
+fn bounce_enforces_minimum_separation_speed() {
+    let mut player = create_test_player();
+    player.vel = Vec2::new(0.0, 5.0); // Drifting slowly into the floor
+
+    let impact_speed = player.bounce((0.0, -1.0), 1.0, 0.5, 60.0);
+
+    assert_float_eq(impact_speed, 5.0);
+    assert_float_eq(player.vel.y, -60.0);
+}
@@ -0,0 +1,144 @@
+use core::constants::RespawnConstants;
+use core::distance::DistanceTracker;
+use core::level::LevelManager;
+use core::respawn::{Checkpoint, CheckpointTracker, FlyIn, Lives};
+
+/// Helper function to build a checkpoint at the given distance.
+fn checkpoint_at(distance: f32, fuel: f32) -> Checkpoint {
+    let mut tracker = DistanceTracker::new();
+    tracker.update(distance, 1.0);
+
+    Checkpoint {
+        camera_offset_x: distance,
+        player_pos: (distance + 100.0, 300.0),
+        fuel,
+        level_number: 1,
+        level_start_time: 0.0,
+        distance: tracker,
+    }
+}
+
+/// Tests that a run starts with the configured number of lives.
+#[test]
+fn lives_start_at_default() {
+    let lives = Lives::default();
+
+    assert_eq!(lives.remaining(), RespawnConstants::STARTING_LIVES);
+    assert!(!lives.is_exhausted());
+}
+
+/// Tests that losing the last ship ends the run.
+#[test]
+fn losing_last_life_exhausts_lives() {
+    let mut lives = Lives::new(2);
+
+    assert!(lives.lose_life());
+    assert_eq!(lives.remaining(), 1);
+    assert!(!lives.lose_life());
+    assert!(lives.is_exhausted());
+
+    // Further losses stay at zero
+    assert!(!lives.lose_life());
+    assert_eq!(lives.remaining(), 0);
+}
+
+/// Tests that the first checkpoint is due immediately.
+#[test]
+fn first_checkpoint_due_at_start() {
+    let tracker = CheckpointTracker::default();
+
+    assert!(tracker.is_due(0.0));
+    assert!(tracker.last().is_none());
+}
+
+/// Tests that checkpoints are due every interval after the last one.
+#[test]
+fn checkpoint_due_after_interval() {
+    let mut tracker = CheckpointTracker::new(500.0);
+    tracker.record(checkpoint_at(0.0, 80.0));
+
+    assert!(!tracker.is_due(499.0));
+    assert!(tracker.is_due(500.0));
+}
+
+/// Tests that a level boundary checkpoint restarts the interval.
+#[test]
+fn recorded_checkpoint_restarts_interval() {
+    let mut tracker = CheckpointTracker::new(500.0);
+    tracker.record(checkpoint_at(0.0, 80.0));
+    tracker.record(checkpoint_at(320.0, 60.0));
+
+    assert!(!tracker.is_due(500.0));
+    assert!(tracker.is_due(820.0));
+    assert_eq!(tracker.last().unwrap().fuel, 60.0);
+}
+
+/// Tests that respawning at a checkpoint from before a level boundary rewinds the level.
+#[test]
+fn respawn_across_level_boundary_rewinds_level() {
+    let mut levels = LevelManager::new();
+    let checkpoint = Checkpoint {
+        level_number: levels.current_level_number(),
+        level_start_time: levels.level_start_time(),
+        ..checkpoint_at(300.0, 80.0)
+    };
+    let duration = levels.current_level().unwrap().duration_seconds;
+
+    // The ship is lost after crossing into level 2
+    assert!(levels.update(duration + 1.0).unwrap());
+    assert_eq!(levels.current_level_number(), 2);
+
+    let elapsed = checkpoint.distance.elapsed_time();
+    assert!(levels.rewind_to(checkpoint.level_number, checkpoint.level_start_time).unwrap());
+    assert_eq!(levels.current_level_number(), 1);
+    assert!(levels.level_progress(elapsed).unwrap() >= 0.0);
+
+    // Level 1 is cleared again on its original schedule
+    assert!(!levels.update(duration - 0.5).unwrap());
+    assert!(levels.update(duration + 0.1).unwrap());
+    assert_eq!(levels.current_level_number(), 2);
+
+    // A checkpoint taken at the boundary keeps the new level
+    let start = levels.level_start_time();
+    assert!(!levels.rewind_to(2, start).unwrap());
+    assert_eq!(levels.level_progress(start).unwrap(), 0.0);
+    assert!(levels.rewind_to(0, 0.0).is_err());
+}
+
+/// Tests that reset clears checkpoints.
+#[test]
+fn reset_clears_checkpoints() {
+    let mut tracker = CheckpointTracker::default();
+    tracker.record(checkpoint_at(1500.0, 40.0));
+
+    tracker.reset();
+
+    assert!(tracker.last().is_none());
+    assert!(tracker.is_due(0.0));
+}
+
+/// Tests that the fly-in moves from start to target.
+#[test]
+fn fly_in_reaches_target() {
+    let mut fly_in = FlyIn::new((0.0, 100.0), (200.0, 300.0), 1.0);
+
+    assert_eq!(fly_in.position(), (0.0, 100.0));
+    assert!(!fly_in.tick(0.5));
+
+    let (x, y) = fly_in.position();
+    assert!(x > 100.0 && x < 200.0, "Fly-in should ease out, got x={}", x);
+    assert!(y > 200.0 && y < 300.0);
+
+    assert!(fly_in.tick(0.6));
+    assert_eq!(fly_in.position(), (200.0, 300.0));
+    assert_eq!(fly_in.progress(), 1.0);
+}
+
+/// Tests that a zero-length fly-in finishes immediately.
+#[test]
+fn zero_duration_fly_in_is_finished() {
+    let fly_in = FlyIn::new((0.0, 0.0), (50.0, 50.0), 0.0);
+
+    assert!(fly_in.is_finished());
+    assert_eq!(fly_in.position(), (50.0, 50.0));
+}
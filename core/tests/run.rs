@@ -0,0 +1,49 @@
+use core::distance::DistanceTracker;
+use core::game_state::DeathCause;
+use core::run::RunResult;
+use core::score::ScoreBreakdown;
+
+/// Tests that a run result captures the final tracker state.
+#[test]
+fn run_result_captures_tracker() {
+    let mut tracker = DistanceTracker::new();
+    tracker.update(120.0, 2.5);
+
+    let result = RunResult::new(DeathCause::FloorCrash, &tracker, 2);
+
+    assert_eq!(result.death_cause, Some(DeathCause::FloorCrash));
+    assert!(!result.is_completed());
+    assert_eq!(result.distance, 300.0);
+    assert_eq!(result.elapsed_time, 2.5);
+    assert_eq!(result.level_number, 2);
+    assert_eq!(result.distance_as_int(), 300);
+}
+
+/// Tests that a crash with an empty tank keeps the crash as its cause.
+#[test]
+fn crash_with_empty_tank_reports_crash() {
+    let tracker = DistanceTracker::new();
+
+    let result = RunResult::new(DeathCause::CeilingCrash, &tracker, 1);
+
+    let cause = result.death_cause.unwrap();
+    assert!(cause.is_collision());
+    assert_ne!(cause.message(), DeathCause::FuelExhausted.message());
+}
+
+/// Tests that the final score can be attached to a run result.
+#[test]
+fn run_result_carries_score() {
+    let tracker = DistanceTracker::new();
+    let score = ScoreBreakdown {
+        distance: 30,
+        pickups: 50,
+        ..Default::default()
+    };
+
+    let result = RunResult::new(DeathCause::Hazard, &tracker, 1);
+    assert_eq!(result.score.total(), 0);
+
+    let result = result.with_score(score);
+    assert_eq!(result.score.total(), 80);
+}
@@ -0,0 +1,126 @@
+// core/tests/score.rs
+
+use core::constants::{CargoConstants, ScoreConstants};
+use core::pickup::PickupType;
+use core::run::{RunEvent, RunEventQueue};
+use core::score::{Score, ScoreBreakdown};
+
+/// Feeds a list of events into a fresh score.
+fn score_events(events: &[RunEvent]) -> Score {
+    let mut score = Score::new();
+    for event in events {
+        score.apply(event);
+    }
+    score
+}
+
+/// Tests that two runs of the same length score differently by what happened in them.
+#[test]
+fn equal_distance_scores_differ_by_events() {
+    let mut plain = Score::new();
+    plain.record_distance(1000.0);
+
+    let mut busy = score_events(&[RunEvent::PickupCollected {
+        pickup_type: PickupType::Fuel,
+        beam_catch: false,
+    }]);
+    busy.record_distance(1000.0);
+
+    assert_eq!(plain.breakdown().distance, busy.breakdown().distance);
+    assert!(busy.total() > plain.total());
+}
+
+/// Tests that distance only counts the furthest point reached.
+#[test]
+fn distance_points_keep_best_distance() {
+    let mut score = Score::new();
+
+    score.record_distance(1000.0);
+    score.record_distance(600.0); // Rewound to a checkpoint
+
+    assert_eq!(score.breakdown().distance, (1000.0 * ScoreConstants::POINTS_PER_PIXEL) as u32);
+}
+
+/// Tests that beam catches earn a bonus on top of the pickup points.
+#[test]
+fn beam_catch_earns_bonus() {
+    let score = score_events(&[
+        RunEvent::PickupCollected { pickup_type: PickupType::Fuel, beam_catch: true },
+        RunEvent::PickupCollected { pickup_type: PickupType::Shield, beam_catch: false },
+    ]);
+
+    let breakdown = score.breakdown();
+    assert_eq!(breakdown.pickups, 2 * ScoreConstants::PICKUP_POINTS);
+    assert_eq!(breakdown.beam_catches, ScoreConstants::BEAM_CATCH_BONUS);
+}
+
+/// Tests that near-misses scale with their streak multiplier.
+#[test]
+fn near_misses_scale_with_multiplier() {
+    let score = score_events(&[
+        RunEvent::NearMiss { multiplier: 1 },
+        RunEvent::NearMiss { multiplier: 3 },
+    ]);
+
+    assert_eq!(score.breakdown().near_misses, 4 * ScoreConstants::NEAR_MISS_POINTS);
+}
+
+/// Tests level-clear and leftover fuel bonuses.
+#[test]
+fn level_clear_bonuses() {
+    let score = score_events(&[
+        RunEvent::LevelCleared { level_number: 1, fuel_ratio: 0.5 },
+        RunEvent::LevelCleared { level_number: 2, fuel_ratio: 0.0 },
+    ]);
+
+    let breakdown = score.breakdown();
+    assert_eq!(breakdown.level_bonus, 3 * ScoreConstants::LEVEL_CLEAR_BONUS);
+    assert_eq!(breakdown.fuel_bonus, (ScoreConstants::FUEL_BONUS * 0.5) as u32);
+}
+
+/// Tests that deliveries score points and meeting the goal adds its bonus.
+#[test]
+fn cargo_delivery_points() {
+    let score = score_events(&[
+        RunEvent::CargoDelivered { goal_met: false },
+        RunEvent::CargoDelivered { goal_met: true },
+    ]);
+
+    assert_eq!(
+        score.breakdown().cargo,
+        2 * CargoConstants::DELIVERY_POINTS + CargoConstants::GOAL_BONUS
+    );
+}
+
+/// Tests that the breakdown lines add up to the total.
+#[test]
+fn breakdown_lines_sum_to_total() {
+    let breakdown = ScoreBreakdown {
+        distance: 1,
+        pickups: 2,
+        beam_catches: 3,
+        near_misses: 4,
+        fuel_bonus: 5,
+        level_bonus: 6,
+        combos: 7,
+        cargo: 9,
+        finish_bonus: 8,
+    };
+
+    let sum: u32 = breakdown.lines().iter().map(|(_, points)| points).sum();
+    assert_eq!(sum, breakdown.total());
+    assert_eq!(breakdown.total(), 45);
+}
+
+/// Tests that the run event queue hands out events once.
+#[test]
+fn run_event_queue_drains() {
+    let mut queue = RunEventQueue::new();
+    assert!(queue.is_empty());
+
+    queue.push(RunEvent::NearMiss { multiplier: 1 });
+    queue.push(RunEvent::NearMiss { multiplier: 2 });
+
+    assert_eq!(queue.drain().len(), 2);
+    assert!(queue.is_empty());
+}
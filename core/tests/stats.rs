@@ -0,0 +1,193 @@
+// core/tests/stats.rs
+
+use core::constants::StatsConstants;
+use core::game_state::DeathCause;
+use core::pickup::PickupType;
+use core::run::RunEvent;
+use core::stats::{LifetimeStats, RunStats};
+use core::storage::{MemoryStorage, Storage, StorageError};
+
+const DT: f32 = 1.0 / 60.0; // 60 FPS
+
+/// Flight sample at the given speed and wall clearance.
+fn flight(speed: f32, wall_clearance: Option<f32>) -> RunEvent {
+    RunEvent::Flight {
+        dt: DT,
+        distance: 120.0 * DT,
+        thrusting: false,
+        speed,
+        wall_clearance,
+    }
+}
+
+/// Builds run statistics from a list of events.
+fn run_from(events: &[RunEvent]) -> RunStats {
+    let mut stats = RunStats::new();
+    for event in events {
+        stats.apply(event);
+    }
+    stats
+}
+
+/// Tests that fuel, pickup and beam events are counted.
+#[test]
+fn counts_fuel_pickups_and_beams() {
+    let stats = run_from(&[
+        RunEvent::FuelBurned { amount: 2.5 },
+        RunEvent::FuelBurned { amount: 1.5 },
+        RunEvent::Refuelled { amount: 30.0 },
+        RunEvent::PickupCollected {
+            pickup_type: PickupType::Fuel,
+            beam_catch: true,
+        },
+        RunEvent::PickupCollected {
+            pickup_type: PickupType::Shield,
+            beam_catch: false,
+        },
+        RunEvent::PickupMissed {
+            pickup_type: PickupType::Fuel,
+        },
+        RunEvent::BeamFired,
+        RunEvent::NearMiss { multiplier: 1 },
+    ]);
+
+    assert_eq!(stats.fuel_burned, 4.0);
+    assert_eq!(stats.fuel_collected, 30.0);
+    assert_eq!(stats.pickups_collected, 2);
+    assert_eq!(stats.pickups_seen(), 3);
+    assert_eq!(stats.beams_fired, 1);
+    assert_eq!(stats.beam_catches, 1);
+}
+
+/// Tests that flight time is split per level.
+#[test]
+fn level_times_split_at_level_clears() {
+    let mut events = vec![flight(0.0, None); 60];
+    events.push(RunEvent::LevelCleared {
+        level_number: 1,
+        fuel_ratio: 0.5,
+    });
+    events.extend(vec![flight(0.0, None); 30]);
+
+    let stats = run_from(&events);
+
+    assert_eq!(stats.level_times.len(), 2);
+    assert!((stats.level_times[0] - 1.0).abs() < 0.001);
+    assert!((stats.level_times[1] - 0.5).abs() < 0.001);
+    assert_eq!(stats.levels_cleared(), 1);
+    assert!((stats.elapsed_time() - 1.5).abs() < 0.001);
+}
+
+/// Tests that the fastest speed and closest wall pass are kept.
+#[test]
+fn tracks_extremes() {
+    let stats = run_from(&[
+        flight(100.0, Some(40.0)),
+        flight(250.0, Some(8.0)),
+        flight(180.0, None),
+        flight(90.0, Some(20.0)),
+    ]);
+
+    assert_eq!(stats.max_speed, 250.0);
+    assert_eq!(stats.closest_wall, Some(8.0));
+}
+
+/// Tests that the latest lost ship gives the cause of death.
+#[test]
+fn records_cause_of_death() {
+    let stats = run_from(&[
+        RunEvent::ShipLost {
+            cause: DeathCause::Hazard,
+        },
+        RunEvent::ShipLost {
+            cause: DeathCause::FloorCrash,
+        },
+    ]);
+
+    assert_eq!(stats.ships_lost, 2);
+    assert_eq!(stats.death_cause, Some(DeathCause::FloorCrash));
+    assert!(stats.report().contains("Cause of death: floor crash"));
+}
+
+/// Tests that lifetime totals add up finished runs.
+#[test]
+fn lifetime_adds_runs() {
+    let first = run_from(&[
+        flight(200.0, Some(15.0)),
+        RunEvent::FuelBurned { amount: 10.0 },
+        RunEvent::ShipLost {
+            cause: DeathCause::FuelExhausted,
+        },
+    ]);
+    let second = run_from(&[
+        flight(150.0, Some(5.0)),
+        RunEvent::FuelBurned { amount: 5.0 },
+        RunEvent::ShipLost {
+            cause: DeathCause::FuelExhausted,
+        },
+    ]);
+
+    let mut lifetime = LifetimeStats::new();
+    lifetime.add_run(&first);
+    lifetime.add_run(&second);
+
+    assert_eq!(lifetime.runs, 2);
+    assert_eq!(lifetime.fuel_burned, 15.0);
+    assert_eq!(lifetime.max_speed, 200.0);
+    assert_eq!(lifetime.closest_wall, Some(5.0));
+    assert_eq!(lifetime.deaths(DeathCause::FuelExhausted), 2);
+    assert_eq!(lifetime.deaths(DeathCause::Hazard), 0);
+}
+
+/// Tests that lifetime totals survive a round trip through storage.
+#[test]
+fn lifetime_save_and_load() {
+    let mut storage = MemoryStorage::new();
+    assert_eq!(LifetimeStats::load(&storage).unwrap(), LifetimeStats::default());
+
+    let mut lifetime = LifetimeStats::new();
+    lifetime.add_run(&run_from(&[
+        flight(120.0, Some(12.5)),
+        RunEvent::BeamFired,
+        RunEvent::Refuelled { amount: 20.0 },
+        RunEvent::LevelCleared {
+            level_number: 1,
+            fuel_ratio: 1.0,
+        },
+        RunEvent::ShipLost {
+            cause: DeathCause::CeilingCrash,
+        },
+    ]));
+    lifetime.save(&mut storage).unwrap();
+
+    assert!(storage.load(StatsConstants::STORAGE_KEY).unwrap().is_some());
+    assert_eq!(LifetimeStats::load(&storage).unwrap(), lifetime);
+}
+
+/// Tests that unknown keys are skipped but bad values are reported.
+#[test]
+fn lifetime_parsing() {
+    let lifetime = LifetimeStats::from_text("runs=3\nfuture_stat=7\ndeaths.hazard=2\n").unwrap();
+    assert_eq!(lifetime.runs, 3);
+    assert_eq!(lifetime.deaths(DeathCause::Hazard), 2);
+
+    let result = LifetimeStats::from_text("runs=3\nbeams_fired=lots\n");
+    match result {
+        Err(StorageError::Corrupt { key, reason }) => {
+            assert_eq!(key, StatsConstants::STORAGE_KEY);
+            assert!(reason.starts_with("line 2"));
+        }
+        other => panic!("expected corrupt record error, got {:?}", other),
+    }
+}
+
+/// Tests that the lifetime lines list every death cause.
+#[test]
+fn lifetime_lines_cover_death_causes() {
+    let lines = LifetimeStats::new().lines();
+
+    for cause in DeathCause::ALL {
+        let label = format!("Deaths: {}", cause);
+        assert!(lines.iter().any(|(line_label, _)| *line_label == label));
+    }
+}
@@ -0,0 +1,111 @@
+// core/tests/storage.rs
+
+use core::highscore::HighScoreTable;
+use core::storage::{FileStorage, GuardedStorage, MemoryStorage, Storage, StorageError, StorageResult};
+
+/// Creates an empty scratch directory for one test.
+fn scratch_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("fuel-drift-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+/// Tests that memory storage returns what was saved.
+#[test]
+fn memory_storage_round_trip() {
+    let mut storage = MemoryStorage::new();
+
+    assert_eq!(storage.load("scores").unwrap(), None);
+    storage.save("scores", "first").unwrap();
+    storage.save("scores", "second").unwrap();
+
+    assert_eq!(storage.load("scores").unwrap(), Some("second".to_string()));
+}
+
+/// Tests that file storage creates its directory and reads records back.
+#[test]
+fn file_storage_round_trip() {
+    let dir = scratch_dir("round-trip");
+    let mut storage = FileStorage::new(&dir);
+
+    assert_eq!(storage.load("scores").unwrap(), None);
+    storage.save("scores", "100,200\n").unwrap();
+
+    assert!(storage.path("scores").starts_with(&dir));
+    // A fresh backend on the same directory sees the record
+    assert_eq!(
+        FileStorage::new(&dir).load("scores").unwrap(),
+        Some("100,200\n".to_string())
+    );
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+/// Tests that file storage keeps one record per key.
+#[test]
+fn file_storage_keeps_keys_apart() {
+    let dir = scratch_dir("keys");
+    let mut storage = FileStorage::new(&dir);
+
+    storage.save("a", "alpha").unwrap();
+    storage.save("b", "beta").unwrap();
+
+    assert_eq!(storage.load("a").unwrap(), Some("alpha".to_string()));
+    assert_eq!(storage.load("b").unwrap(), Some("beta".to_string()));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+/// High-score record with one valid row and one corrupt row.
+const CORRUPT_TABLE: &str = "500,1000,2,42,1700000000,floor_crash,30.0,normal,ACE\nnot,a,row\n";
+
+/// Storage whose backup records can't be written.
+struct NoBackupStorage(MemoryStorage);
+
+impl Storage for NoBackupStorage {
+    fn load(&self, key: &str) -> StorageResult<Option<String>> {
+        self.0.load(key)
+    }
+
+    fn save(&mut self, key: &str, data: &str) -> StorageResult<()> {
+        if key.ends_with(".bak") {
+            return Err(StorageError::Io("disk full".to_string()));
+        }
+        self.0.save(key, data)
+    }
+}
+
+/// Tests that protecting an unreadable record keeps a copy before it is replaced.
+#[test]
+fn guarded_storage_backs_up_corrupt_table() {
+    let mut memory = MemoryStorage::new();
+    memory.save("highscores", CORRUPT_TABLE).unwrap();
+    let mut storage = GuardedStorage::new(Box::new(memory));
+
+    assert!(HighScoreTable::load_from(&storage, "highscores").is_err());
+    storage.protect("highscores").unwrap();
+    assert!(!storage.is_blocked("highscores"));
+
+    HighScoreTable::default().save_to(&mut storage, "highscores").unwrap();
+
+    assert_eq!(
+        storage.load(&GuardedStorage::backup_key("highscores")).unwrap(),
+        Some(CORRUPT_TABLE.to_string())
+    );
+}
+
+/// Tests that a corrupt table is never overwritten when it can't be backed up.
+#[test]
+fn guarded_storage_refuses_to_overwrite_without_backup() {
+    let mut memory = MemoryStorage::new();
+    memory.save("highscores", CORRUPT_TABLE).unwrap();
+    let mut storage = GuardedStorage::new(Box::new(NoBackupStorage(memory)));
+
+    assert!(storage.protect("highscores").is_err());
+    assert!(storage.is_blocked("highscores"));
+
+    assert!(HighScoreTable::default().save_to(&mut storage, "highscores").is_err());
+    assert_eq!(storage.load("highscores").unwrap(), Some(CORRUPT_TABLE.to_string()));
+    // Other keys still save normally
+    storage.save("player_name", "ACE").unwrap();
+}
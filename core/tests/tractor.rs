@@ -1,6 +1,10 @@
 // core/tests/tractor.rs
 
-use core::tractor::{BeamDir, TractorBeam};
+use core::constants::TractorBeamConstants;
+use core::fuel::Fuel;
+use std::f32::consts::FRAC_PI_4;
+
+use core::tractor::{BeamDir, BeamPolarity, TractorBeam};
 
 const DT: f32 = 1.0 / 60.0; // 60 FPS
 const EPSILON: f32 = 0.001;
@@ -237,3 +241,306 @@ fn beam_dir_properties() {
     let dir_clone = dir;
     assert_eq!(dir, dir_clone);
 }
+
+/// Tests that the beam pulls by default and toggles to push.
+#[test]
+fn polarity_defaults_to_pull_and_toggles() {
+    let mut beam = TractorBeam::new();
+    assert_eq!(beam.polarity, BeamPolarity::Pull);
+
+    beam.toggle_polarity();
+    assert_eq!(beam.polarity, BeamPolarity::Push);
+
+    beam.toggle_polarity();
+    assert_eq!(beam.polarity, BeamPolarity::Pull);
+}
+
+/// Tests that toggling polarity keeps an active beam running.
+#[test]
+fn toggle_polarity_while_active() {
+    let mut beam = TractorBeam::new();
+    beam.activate(BeamDir::Up);
+
+    beam.toggle_polarity();
+
+    assert!(beam.is_active());
+    assert_eq!(beam.polarity, BeamPolarity::Push);
+}
+
+/// Tests that push mode reverses the beam force.
+#[test]
+fn push_force_points_away_from_player() {
+    let mut beam = TractorBeam::new();
+    beam.activate(BeamDir::Up);
+    let player_pos = (100.0, 200.0);
+    let target_pos = (100.0, 150.0);
+
+    assert_eq!(beam.get_beam_force(player_pos, target_pos), (0.0, 1.0));
+
+    beam.toggle_polarity();
+    assert_eq!(beam.get_beam_force(player_pos, target_pos), (0.0, -1.0));
+}
+
+/// Tests that only an active push beam produces recoil.
+#[test]
+fn recoil_only_when_pushing() {
+    let mut beam = TractorBeam::new();
+    assert_eq!(beam.recoil(10.0), (0.0, 0.0));
+
+    beam.activate(BeamDir::Up);
+    assert_eq!(beam.recoil(10.0), (0.0, 0.0));
+
+    beam.toggle_polarity();
+    assert!(beam.recoil(10.0).1 > 0.0);
+}
+
+/// Tests that recoil pushes away from the wall and fades with distance.
+#[test]
+fn recoil_direction_and_falloff() {
+    let mut up = TractorBeam::new();
+    up.polarity = BeamPolarity::Push;
+    up.activate(BeamDir::Up);
+
+    let mut down = TractorBeam::new();
+    down.polarity = BeamPolarity::Push;
+    down.activate(BeamDir::Down);
+
+    // Ceiling pushes down (positive y), floor pushes up
+    assert!(up.recoil(20.0).1 > 0.0);
+    assert!(down.recoil(20.0).1 < 0.0);
+
+    // Closer walls push harder, walls out of range not at all
+    assert!(up.recoil(20.0).1 > up.recoil(200.0).1);
+    assert_eq!(up.recoil(TractorBeam::MAX_RANGE + 1.0), (0.0, 0.0));
+}
+
+/// Tests that a new beam starts with a full energy pool.
+#[test]
+fn beam_starts_with_full_energy() {
+    let beam = TractorBeam::new();
+
+    assert_float_eq(beam.energy, TractorBeamConstants::MAX_ENERGY);
+    assert_float_eq(beam.energy_ratio(), 1.0);
+    assert!(beam.can_activate());
+    assert!(!beam.is_overheated());
+}
+
+/// Tests that energy drains while active and recharges while off.
+#[test]
+fn energy_drains_and_recharges() {
+    let mut beam = TractorBeam::new();
+    beam.activate(BeamDir::Up);
+
+    beam.tick(1.0);
+    let drained = TractorBeamConstants::MAX_ENERGY - TractorBeamConstants::ENERGY_DRAIN_RATE;
+    assert_float_eq(beam.energy, drained);
+
+    // Let the rest of the burst run out, then recharge
+    beam.tick(TractorBeam::MAX_DURATION - 1.0);
+    assert!(!beam.is_active());
+    let after_burst = beam.energy;
+
+    beam.tick(1.0);
+    assert_float_eq(beam.energy, after_burst + TractorBeamConstants::ENERGY_RECHARGE_RATE);
+}
+
+/// Tests that back-to-back bursts drain the pool and overheat the beam.
+#[test]
+fn repeated_bursts_overheat_beam() {
+    let mut beam = TractorBeam::new();
+
+    for _ in 0..10 {
+        beam.activate(BeamDir::Up);
+        while beam.is_active() {
+            beam.tick(DT);
+        }
+        if beam.is_overheated() {
+            break;
+        }
+    }
+
+    assert!(beam.is_overheated());
+    assert_float_eq(beam.energy, 0.0);
+    assert!(!beam.can_activate());
+
+    // Activation is refused during the lockout
+    beam.activate(BeamDir::Up);
+    assert!(!beam.is_active());
+}
+
+/// Tests that energy does not recharge until the lockout ends.
+#[test]
+fn overheat_lockout_blocks_recharge() {
+    let mut beam = TractorBeam::new();
+    beam.energy = TractorBeamConstants::MIN_ACTIVATION_ENERGY;
+    beam.activate(BeamDir::Up);
+    beam.tick(1.0);
+    assert!(beam.is_overheated());
+
+    beam.tick(TractorBeamConstants::OVERHEAT_DURATION / 2.0);
+    assert_float_eq(beam.energy, 0.0);
+
+    beam.tick(TractorBeamConstants::OVERHEAT_DURATION);
+    assert!(!beam.is_overheated());
+    beam.tick(1.0);
+    assert!(beam.can_activate());
+}
+
+/// Tests that activation needs the minimum energy.
+#[test]
+fn low_energy_blocks_activation() {
+    let mut beam = TractorBeam::new();
+    beam.energy = TractorBeamConstants::MIN_ACTIVATION_ENERGY - 1.0;
+
+    beam.activate(BeamDir::Down);
+
+    assert!(!beam.is_active());
+}
+
+/// Tests that the fuel fallback keeps the beam running on fuel.
+#[test]
+fn fuel_fallback_covers_shortfall() {
+    let mut beam = TractorBeam::new();
+    beam.fuel_fallback = true;
+    beam.energy = TractorBeamConstants::MIN_ACTIVATION_ENERGY;
+    beam.activate(BeamDir::Up);
+    let mut fuel = Fuel::new(100.0, 10.0);
+
+    for _ in 0..30 {
+        beam.tick_with_fuel(DT, &mut fuel);
+    }
+
+    assert!(beam.is_active());
+    assert!(!beam.is_overheated());
+    assert!(beam.is_running_on_fuel());
+    assert!(fuel.current < 100.0);
+}
+
+/// Tests that a beam with energy to spare is not flagged as running on fuel.
+#[test]
+fn fuel_fallback_idle_while_energy_lasts() {
+    let mut beam = TractorBeam::new();
+    beam.fuel_fallback = true;
+    beam.activate(BeamDir::Up);
+    let mut fuel = Fuel::new(100.0, 10.0);
+
+    beam.tick_with_fuel(DT, &mut fuel);
+
+    assert!(!beam.is_running_on_fuel());
+    assert_float_eq(fuel.current, 100.0);
+}
+
+/// Tests that the fuel fallback never empties the tank.
+#[test]
+fn fuel_fallback_never_empties_tank() {
+    let mut beam = TractorBeam::new();
+    beam.fuel_fallback = true;
+    beam.energy = TractorBeamConstants::MIN_ACTIVATION_ENERGY;
+    beam.activate(BeamDir::Up);
+    let mut fuel = Fuel::new(100.0, 10.0);
+    fuel.current = 0.1;
+
+    while beam.is_active() {
+        beam.tick_with_fuel(DT, &mut fuel);
+    }
+
+    assert!(beam.is_overheated());
+    assert!(!fuel.is_empty());
+}
+
+/// Tests that without the fallback fuel is untouched.
+#[test]
+fn no_fuel_drawn_without_fallback() {
+    let mut beam = TractorBeam::new();
+    beam.energy = TractorBeamConstants::MIN_ACTIVATION_ENERGY;
+    beam.activate(BeamDir::Up);
+    let mut fuel = Fuel::new(100.0, 10.0);
+
+    while beam.is_active() {
+        beam.tick_with_fuel(DT, &mut fuel);
+    }
+
+    assert!(beam.is_overheated());
+    assert_float_eq(fuel.current, 100.0);
+}
+
+/// Tests that cardinal directions map to beam angles and back.
+#[test]
+fn beam_dir_angle_round_trip() {
+    assert_eq!(BeamDir::from_angle(BeamDir::Up.angle()), BeamDir::Up);
+    assert_eq!(BeamDir::from_angle(BeamDir::Down.angle()), BeamDir::Down);
+
+    let beam = TractorBeam::new();
+    let (dir_x, dir_y) = beam.direction();
+    assert_float_eq(dir_x, 0.0);
+    assert_float_eq(dir_y, -1.0);
+}
+
+/// Tests that a beam aimed to the right covers points ahead of the ship.
+#[test]
+fn horizontal_beam_covers_points_ahead() {
+    let mut beam = TractorBeam::new();
+    beam.activate_at(0.0);
+    let player_pos = (100.0, 200.0);
+
+    assert!(beam.is_point_in_beam(player_pos, (250.0, 205.0)));
+    assert!(!beam.is_point_in_beam(player_pos, (50.0, 200.0))); // Behind
+    assert!(!beam.is_point_in_beam(player_pos, (250.0, 230.0))); // Too wide
+    assert!(!beam.is_point_in_beam(player_pos, (100.0 + TractorBeam::MAX_RANGE + 1.0, 200.0)));
+}
+
+/// Tests that a diagonal beam is an oriented rectangle.
+#[test]
+fn diagonal_beam_uses_oriented_rectangle() {
+    let mut beam = TractorBeam::new();
+    beam.activate_at(FRAC_PI_4); // Down and to the right
+    let player_pos = (0.0, 0.0);
+
+    assert!(beam.is_point_in_beam(player_pos, (100.0, 100.0)));
+    assert!(beam.is_point_in_beam(player_pos, (105.0, 95.0)));
+    // Inside an axis-aligned box around the beam but off its axis
+    assert!(!beam.is_point_in_beam(player_pos, (100.0, 60.0)));
+    assert!(!beam.is_point_in_beam(player_pos, (0.0, 100.0)));
+}
+
+/// Tests that re-aiming an active beam keeps it running.
+#[test]
+fn aim_sweeps_active_beam() {
+    let mut beam = TractorBeam::new();
+    beam.activate(BeamDir::Up);
+    beam.tick(DT);
+
+    beam.aim(BeamDir::Down.angle());
+
+    assert!(beam.is_active());
+    assert_eq!(beam.dir, BeamDir::Down);
+    assert!(beam.is_point_in_beam((100.0, 200.0), (100.0, 250.0)));
+}
+
+/// Tests that the beam strength falls off with range.
+#[test]
+fn strength_falls_off_with_range() {
+    let mut beam = TractorBeam::new();
+    beam.activate_at(0.0);
+    let player_pos = (0.0, 0.0);
+
+    let near = beam.strength_at(player_pos, (1.0, 0.0));
+    let far = beam.strength_at(player_pos, (TractorBeam::MAX_RANGE, 0.0));
+
+    assert!(near > far);
+    assert_float_eq(far, TractorBeamConstants::FALLOFF_MIN_STRENGTH);
+    assert_eq!(beam.strength_at(player_pos, (-10.0, 0.0)), 0.0);
+}
+
+/// Tests that recoil points back along an aimed beam.
+#[test]
+fn recoil_points_back_along_beam() {
+    let mut beam = TractorBeam::new();
+    beam.polarity = BeamPolarity::Push;
+    beam.activate_at(0.0);
+
+    let (recoil_x, recoil_y) = beam.recoil(20.0);
+
+    assert!(recoil_x < 0.0);
+    assert_float_eq(recoil_y, 0.0);
+}
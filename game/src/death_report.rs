@@ -0,0 +1,223 @@
+//! Bot death report for level designers.
+//!
+//! Flies a simple autopilot through a range of seeds and reports where
+//! it was lost, to find unfair spots in cave generation.
+use core::analytics::{DeathLog, DeathRecord};
+use core::cave::Cave;
+use core::collision::{aabb_overlap, Aabb};
+use core::constants::{FuelConstants, PickupConstants, WorldConstants};
+use core::difficulty::Difficulty;
+use core::distance::DistanceTracker;
+use core::fuel::{Fuel, FuelConsumption, FuelDemand};
+use core::game_state::DeathCause;
+use core::level::LevelManager;
+use core::mode::GameMode;
+use core::pickup::PickupType;
+use core::player::{Player, PlayerConstants, PlayerInput, Vec2};
+
+/// Simulation time step in seconds (60 FPS).
+const DT: f32 = 1.0 / 60.0;
+
+/// Longest run flown per seed, in seconds.
+const MAX_RUN_TIME: f32 = 600.0;
+
+/// How far ahead of the ship the bot reads the cave, in pixels.
+const LOOKAHEAD: f32 = 120.0;
+
+/// Delay before the bot's thrust takes effect, in seconds.
+const REACTION_TIME: f32 = 0.1;
+
+/// Height error the bot tolerates before thrusting, in pixels.
+const DEADBAND: f32 = 8.0;
+
+/// Share of the gap the bot keeps clear of each wall.
+const WALL_MARGIN_RATIO: f32 = 0.25;
+
+/// Fuel ratio below which the bot steers for the next fuel depot.
+const REFUEL_RATIO: f32 = 0.5;
+
+/// Screen height the walls extend to, in pixels.
+const SCREEN_HEIGHT: f32 = 600.0;
+
+/// Output format of the death report.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReportFormat {
+    Csv,
+    Json,
+}
+
+/// Flies the bot through seeds `0..seed_count` and prints its deaths.
+///
+/// The report goes to stdout; progress and a summary go to stderr.
+pub fn run_death_report(seed_count: u32, format: ReportFormat) -> Result<(), Box<dyn std::error::Error>> {
+    eprintln!("Flying bot through {} seeds...", seed_count);
+
+    let mut log = DeathLog::new(seed_count as usize);
+    for seed in 0..seed_count {
+        match fly_seed(seed) {
+            Some(record) => {
+                eprintln!(
+                    "Seed {}: {} at x {:.0} (level {})",
+                    seed, record.cause, record.world_x, record.level_number
+                );
+                log.record(record);
+            }
+            None => eprintln!("Seed {}: survived {:.0} seconds", seed, MAX_RUN_TIME),
+        }
+    }
+
+    match format {
+        ReportFormat::Csv => print!("{}", log.to_csv()),
+        ReportFormat::Json => print!("{}", log.to_json()),
+    }
+
+    eprintln!(
+        "Death report completed: {} deaths in {} seeds",
+        log.len(),
+        seed_count
+    );
+    Ok(())
+}
+
+/// Flies one run on the given seed.
+///
+/// Returns where the ship was lost, or `None` if it survived `MAX_RUN_TIME`.
+fn fly_seed(seed: u32) -> Option<DeathRecord> {
+    let mut cave = Cave::new(seed);
+    let mut level_manager = LevelManager::new();
+    let mut distance_tracker = DistanceTracker::new();
+    let mut player = Player::new(Vec2::new(100.0, 300.0));
+    let mut fuel = Fuel::new(100.0, 20.0);
+    let consumption = FuelConsumption::default();
+    let mut camera_offset_x = 0.0;
+
+    let mut fuel_spawn_distance = apply_level(&mut cave, &level_manager);
+    let record = |player: &Player, level_manager: &LevelManager, cause| {
+        DeathRecord::new(player.pos.x, level_manager.current_level_number(), seed, cause)
+            .with_run(GameMode::Campaign, Difficulty::Normal)
+    };
+
+    while distance_tracker.elapsed_time() < MAX_RUN_TIME {
+        camera_offset_x += WorldConstants::SCROLL_SPEED * DT;
+        distance_tracker.update(WorldConstants::SCROLL_SPEED, DT);
+
+        if let Ok(true) = level_manager.update(distance_tracker.elapsed_time()) {
+            let level_number = level_manager.current_level_number();
+            cave.configure_for_level(level_number);
+            fuel_spawn_distance = apply_level(&mut cave, &level_manager);
+            cave.hazard_manager_mut()
+                .hold_spawns_until(camera_offset_x + 800.0);
+        }
+        cave.segments_in_view(camera_offset_x, camera_offset_x + 800.0, fuel_spawn_distance);
+
+        let input = bot_input(&player, &fuel, &cave);
+        let demand = FuelDemand::from_input(input, false);
+        if fuel.burn(DT, consumption.multiplier(demand)) {
+            return Some(record(&player, &level_manager, DeathCause::FuelExhausted));
+        }
+        player.load_fuel(&fuel);
+        player.tick(DT, input, WorldConstants::SCROLL_SPEED, camera_offset_x);
+
+        if let Some(cause) = wall_hit(&player, &cave) {
+            return Some(record(&player, &level_manager, cause));
+        }
+
+        let player_pos = (player.pos.x, player.pos.y);
+        let player_box = (
+            player.pos.x - PlayerConstants::WIDTH / 2.0,
+            player.pos.y - PlayerConstants::HEIGHT / 2.0,
+        );
+        let player_size = (PlayerConstants::WIDTH, PlayerConstants::HEIGHT);
+        cave.hazard_manager_mut().update(DT, player_pos);
+        if let Some(hit) = cave.hazard_manager_mut().check_collision(player_box, player_size) {
+            return Some(record(&player, &level_manager, hit.death_cause()));
+        }
+
+        if let Some(index) = cave.pickup_manager().check_collision(player_box, player_size) {
+            if cave.pickup_manager_mut().collect_pickup(index) == Some(PickupType::Fuel) {
+                fuel.refill(fuel.max * FuelConstants::REFILL_PERCENTAGE);
+            }
+        }
+        cave.pickup_manager_mut().cleanup_old_pickups(camera_offset_x);
+        cave.hazard_manager_mut().cleanup_old_hazards(camera_offset_x);
+    }
+
+    None
+}
+
+/// Applies the current level's hazard density and returns its depot spacing.
+fn apply_level(cave: &mut Cave, level_manager: &LevelManager) -> f32 {
+    let level = level_manager.current_level().ok();
+    cave.hazard_manager_mut()
+        .set_density(level.map(|level| level.hazard_density).unwrap_or(0.0));
+    level
+        .map(|level| level.fuel_spawn_distance)
+        .unwrap_or(PickupConstants::DEFAULT_FUEL_SPAWN_DISTANCE)
+}
+
+/// Holds height inside the safe band of the gap ahead, or steers for the next depot when low on fuel.
+fn bot_input(player: &Player, fuel: &Fuel, cave: &Cave) -> PlayerInput {
+    let Some(segment) = cave.segment_at(player.pos.x + LOOKAHEAD) else {
+        return PlayerInput::default();
+    };
+
+    // Staying put where it is safe saves fuel
+    let margin = (segment.gap_height() * WALL_MARGIN_RATIO).max(PlayerConstants::HEIGHT);
+    let mut target_y = player.pos.y.clamp(segment.ceiling + margin, segment.floor - margin);
+    if fuel.ratio() < REFUEL_RATIO {
+        let margin = PlayerConstants::HEIGHT;
+        let depot = cave
+            .pickup_manager()
+            .get_pickups_in_range(player.pos.x, player.pos.x + LOOKAHEAD * 2.0)
+            .into_iter()
+            .find(|pickup| pickup.pickup_type == PickupType::Fuel);
+        if let Some(depot) = depot {
+            let depot_y = depot.position.1 + PickupConstants::SIZE / 2.0;
+            target_y = depot_y.clamp(segment.ceiling + margin, segment.floor - margin);
+        }
+    }
+
+    // Where the ship comes to rest if it starts braking now
+    let braking = if player.vel.y < 0.0 {
+        -PlayerConstants::THRUST * PlayerConstants::DOWN_THRUST_MULTIPLIER
+    } else {
+        -PlayerConstants::THRUST
+    };
+    let stopping_distance = player.vel.y * player.vel.y.abs() / (2.0 * braking);
+    let predicted_y = player.pos.y + player.vel.y * REACTION_TIME + stopping_distance;
+    PlayerInput {
+        up: predicted_y > target_y + DEADBAND,
+        down: predicted_y < target_y - DEADBAND,
+        ..PlayerInput::default()
+    }
+}
+
+/// Checks the ship against the ceiling and floor of the segments it overlaps.
+fn wall_hit(player: &Player, cave: &Cave) -> Option<DeathCause> {
+    let ship = Aabb::new(
+        player.pos.x - PlayerConstants::WIDTH / 2.0,
+        player.pos.y - PlayerConstants::HEIGHT / 2.0,
+        PlayerConstants::WIDTH,
+        PlayerConstants::HEIGHT,
+    );
+
+    cave.segments()
+        .iter()
+        .filter(|segment| segment.x_end() > ship.left() && segment.x_start < ship.right())
+        .find_map(|segment| {
+            let ship_pos = (ship.x, ship.y);
+            let ship_size = (ship.width, ship.height);
+            if aabb_overlap(ship_pos, ship_size, (segment.x_start, 0.0), (segment.width, segment.ceiling)) {
+                Some(DeathCause::CeilingCrash)
+            } else if aabb_overlap(
+                ship_pos,
+                ship_size,
+                (segment.x_start, segment.floor),
+                (segment.width, SCREEN_HEIGHT - segment.floor),
+            ) {
+                Some(DeathCause::FloorCrash)
+            } else {
+                None
+            }
+        })
+}
@@ -1,14 +1,19 @@
 // game/src/headless_test.rs
+use core::achievements::AchievementTracker;
 use core::audio::{AudioEventQueue, AudioState};
 use core::cave::Cave;
+use core::collision::Aabb;
+use core::constants::WorldConstants;
 use core::distance::DistanceTracker;
-use core::fuel::Fuel;
+use core::fuel::{Fuel, FuelConsumption, FuelDemand};
 /// Headless test runner for CI smoke testing.
 ///
 /// Runs core game logic without graphics for 5 seconds to verify
 /// basic functionality and catch runtime panics.
-use core::game_state::{GameEvent, StateMachine};
-use core::player::{Player, PlayerInput, Vec2};
+use core::game_state::{DeathCause, GameEvent, StateMachine};
+use core::player::{Player, PlayerConstants, PlayerInput, Vec2};
+use core::run::{RunEvent, RunEventQueue};
+use core::stats::{LifetimeStats, RunStats};
 use core::tractor::{BeamDir, TractorBeam};
 
 /// Headless game world for testing core logic.
@@ -21,6 +26,10 @@ struct HeadlessGameWorld {
     distance_tracker: DistanceTracker,
     audio_queue: AudioEventQueue,
     audio_state: AudioState,
+    run_events: RunEventQueue,
+    run_stats: RunStats,
+    session_stats: LifetimeStats,
+    achievements: AchievementTracker,
 }
 
 impl HeadlessGameWorld {
@@ -34,6 +43,10 @@ impl HeadlessGameWorld {
             distance_tracker: DistanceTracker::new(),
             audio_queue: AudioEventQueue::new(),
             audio_state: AudioState::new(),
+            run_events: RunEventQueue::new(),
+            run_stats: RunStats::new(),
+            session_stats: LifetimeStats::new(),
+            achievements: AchievementTracker::default(),
         }
     }
 
@@ -43,6 +56,29 @@ impl HeadlessGameWorld {
         self.tractor_beam = TractorBeam::new();
         self.distance_tracker.reset();
         self.audio_state.stop_all();
+        self.run_events.drain();
+        self.run_stats = RunStats::new();
+        self.achievements.start_run();
+    }
+
+    /// Feeds this frame's run events into the run statistics and achievements.
+    fn apply_run_events(&mut self) {
+        for event in self.run_events.drain() {
+            self.run_stats.apply(&event);
+            self.achievements.apply(&event);
+        }
+        for achievement in self.achievements.drain_unlocked() {
+            println!("Achievement unlocked: {}", achievement.title);
+        }
+    }
+
+    /// Ends the run and prints its statistics.
+    fn end_run(&mut self, cause: DeathCause) {
+        self.run_events.push(RunEvent::ShipLost { cause });
+        self.apply_run_events();
+        self.session_stats.add_run(&self.run_stats);
+        println!("Run ended ({}):\n{}", cause, self.run_stats.report());
+        self.state_machine.handle_event(GameEvent::Dead(cause));
     }
 
     fn update(&mut self, dt: f32) {
@@ -65,25 +101,38 @@ impl HeadlessGameWorld {
                     right: false,
                     tractor_up: (dt * 1000.0) as i32 % 180 < 10, // Beam occasionally
                     tractor_down: false,
+                    tractor_polarity: false,
+                    tractor_aim: None,
+                    tractor_fire: false,
+                    throttle: None,
                 };
 
                 // Update tractor beam
-                if input.tractor_up {
+                if input.tractor_up && !self.tractor_beam.is_active() {
                     self.tractor_beam.activate(BeamDir::Up);
+                    if self.tractor_beam.is_active() {
+                        self.run_events.push(RunEvent::BeamFired);
+                    }
                 }
                 self.tractor_beam.tick(dt);
 
                 // Update fuel
-                let consuming = input.up || input.down || input.left || input.right;
-                let fuel_became_empty = self.fuel.burn(dt, consuming);
+                let demand = FuelDemand::from_input(input, self.tractor_beam.is_active());
+                let consuming = demand.is_thrusting();
+                let fuel_before = self.fuel.current;
+                let fuel_became_empty = self.fuel.burn(dt, FuelConsumption::default().multiplier(demand));
+                self.run_events.push(RunEvent::FuelBurned {
+                    amount: fuel_before - self.fuel.current,
+                });
 
                 if fuel_became_empty {
-                    self.state_machine.handle_event(GameEvent::Dead);
+                    self.end_run(DeathCause::FuelExhausted);
                     return;
                 }
 
                 // Update player physics
                 if !self.fuel.is_empty() {
+                    self.player.load_fuel(&self.fuel);
                     self.player.tick(dt, input, 120.0, 0.0); // Using SCROLL_SPEED constant value, camera_offset = 0
                 }
 
@@ -100,6 +149,25 @@ impl HeadlessGameWorld {
 
                 // Clear audio events
                 self.audio_queue.drain();
+
+                // Sample the flight for the statistics
+                let ship = Aabb::new(
+                    self.player.pos.x - PlayerConstants::WIDTH / 2.0,
+                    self.player.pos.y - PlayerConstants::HEIGHT / 2.0,
+                    PlayerConstants::WIDTH,
+                    PlayerConstants::HEIGHT,
+                );
+                self.run_events.push(RunEvent::Flight {
+                    dt,
+                    distance: WorldConstants::SCROLL_SPEED * dt,
+                    thrusting: consuming,
+                    speed: self.player.vel.x.hypot(self.player.vel.y),
+                    wall_clearance: self
+                        .cave
+                        .wall_clearance(&ship)
+                        .map(|(ceiling, floor)| ceiling.min(floor)),
+                });
+                self.apply_run_events();
             }
             core::game_state::GameState::GameOver => {
                 // Restart after a moment
@@ -152,6 +220,10 @@ pub fn run_headless_test(duration_seconds: f32) -> Result<(), Box<dyn std::error
     println!("Elapsed time: {:.2}s", elapsed.as_secs_f32());
     println!("Final distance: {}m", final_distance);
     println!("Final fuel: {:.1}%", world.fuel.ratio() * 100.0);
+    println!("Current run stats:\n{}", world.run_stats.report());
+    if world.session_stats.runs > 0 {
+        println!("Finished runs:\n{}", world.session_stats.report());
+    }
 
     Ok(())
 }
@@ -10,21 +10,48 @@ pub fn wasm_main() {
     //web_sys::console::log_1(&"🚀 Fuel Drift WASM starting...".into());
 }
 
+use core::achievements::{Achievement, AchievementTracker};
+use core::analytics::{DeathHeatStrip, DeathLog, DeathRecord};
 use core::audio::{AudioEvent, AudioEventQueue, AudioState};
-use core::cave::Cave;
-use core::collision::aabb_overlap;
-use core::constants::{FuelConstants, PickupConstants};
+use core::cargo::{CargoHold, CargoMission, TetherStatus};
+use core::cave::{Cave, CaveSegment};
+use core::collision::{aabb_contact, Aabb, Contact};
+use core::combo::{ComboReward, ComboTracker};
+use core::constants::{
+    AchievementConstants, AnalyticsConstants, CargoConstants, FuelConstants, GrappleConstants, HazardConstants, HighScoreConstants, HullConstants,
+    NearMissConstants,
+    PickupConstants, RespawnConstants, StatsConstants,
+    TractorBeamConstants, WorldConstants,
+};
+use core::difficulty::Difficulty;
 use core::distance::DistanceTracker;
-use core::fuel::Fuel;
-use core::game_state::{GameEvent, StateMachine};
+use core::endurance::{EnduranceEstimator, FuelOutlook, FuelWarning};
+use core::fuel::{Fuel, FuelConsumption, FuelDemand};
+use core::grapple::GrappleStatus;
+use core::highscore::{HighScoreEntry, HighScoreTable};
+use core::game_state::{DeathCause, GameEvent, StateMachine};
+use core::hazard::HazardKind;
+use core::hull::{DamageModel, Hull, ImpactOutcome};
+use core::landing::{is_resting_on_pad, Touchdown};
 use core::level::LevelManager;
-use core::pickup::PickupType;
-use core::player::{Player, PlayerInput, Vec2};
-use core::tractor::{BeamDir, TractorBeam};
+use core::mode::GameMode;
+use core::name_entry::{NameEntry, NameEntryMode};
+use core::near_miss::{Clearance, NearMissDetector};
+use core::pickup::{PickupEvent, PickupType};
+use core::player::{Player, PlayerConstants, PlayerInput, Vec2};
+use core::respawn::{Checkpoint, CheckpointTracker, FlyIn, Lives};
+use core::run::{RunEvent, RunEventQueue, RunResult};
+use core::score::{Score, ScoreBreakdown};
+use core::stats::{LifetimeStats, RunStats};
+use core::storage::{GuardedStorage, Storage, StorageResult};
+use core::tractor::{BeamDir, BeamPolarity, TractorBeam};
 use macroquad::prelude::*;
 use macroquad::ui::{root_ui, widgets};
 
+mod death_report;
 mod headless_test;
+mod seed_check;
+mod storage;
 mod ui;
 mod menu;
 
@@ -34,12 +61,12 @@ const WINDOW_HEIGHT: i32 = 600;
 const WINDOW_TITLE: &str = "Fuel Drift";
 
 /// Game constants
-const SCROLL_SPEED: f32 = 120.0; // pixels/second
-const PLAYER_SIZE: (f32, f32) = (30.0, 18.0);
+const SCROLL_SPEED: f32 = WorldConstants::SCROLL_SPEED; // pixels/second
+const PLAYER_SIZE: (f32, f32) = (PlayerConstants::WIDTH, PlayerConstants::HEIGHT);
 
 /// Fuel constants
 const INITIAL_FUEL: f32 = 100.0;
-const FUEL_BURN_RATE: f32 = 20.0; // fuel per second when consuming
+const FUEL_BURN_RATE: f32 = 20.0; // fuel per second at full main thrust
 
 /// Collision flash constants
 const COLLISION_FLASH_DURATION: f32 = 0.3; // seconds
@@ -51,6 +78,12 @@ const FUEL_BAR_MARGIN: f32 = 10.0;
 const LOW_FUEL_THRESHOLD: f32 = 0.2;
 const MEDIUM_FUEL_THRESHOLD: f32 = 0.5;
 const BEAM_ICON_SIZE: f32 = 16.0;
+const BEAM_ENERGY_BAR_WIDTH: f32 = 60.0;
+const BEAM_ENERGY_BAR_HEIGHT: f32 = 4.0;
+const COMBO_METER_WIDTH: f32 = 60.0;
+const COMBO_METER_HEIGHT: f32 = 4.0;
+const LANDING_PAD_MARK_HEIGHT: f32 = 4.0;
+const DEATH_OVERLAY_MAX_ALPHA: f32 = 0.5;
 
 /// Menu UI constants
 const BUTTON_WIDTH: f32 = 200.0;
@@ -100,6 +133,70 @@ impl AudioSystem {
                 #[cfg(debug_assertions)]
                 println!("🔊 Playing button click sound");
             }
+            AudioEvent::HullHit => {
+                #[cfg(debug_assertions)]
+                println!("🔊 Playing hull hit sound");
+            }
+            AudioEvent::ShieldPickup => {
+                #[cfg(debug_assertions)]
+                println!("🔊 Playing shield pickup sound");
+            }
+            AudioEvent::BeamPolarityToggle => {
+                #[cfg(debug_assertions)]
+                println!("🔊 Playing beam polarity toggle sound");
+            }
+            AudioEvent::BeamOverheat => {
+                #[cfg(debug_assertions)]
+                println!("🔊 Playing beam overheat sound");
+            }
+            AudioEvent::GrappleLatch => {
+                #[cfg(debug_assertions)]
+                println!("🔊 Playing grapple latch sound");
+            }
+            AudioEvent::GrappleBreak => {
+                #[cfg(debug_assertions)]
+                println!("🔊 Playing grapple break sound");
+            }
+            AudioEvent::CargoAttach => {
+                #[cfg(debug_assertions)]
+                println!("🔊 Playing cargo attach sound");
+            }
+            AudioEvent::CargoDelivered => {
+                #[cfg(debug_assertions)]
+                println!("🔊 Playing cargo delivered sound");
+            }
+            AudioEvent::CargoLost => {
+                #[cfg(debug_assertions)]
+                println!("🔊 Playing cargo lost sound");
+            }
+            AudioEvent::PadTouchdown => {
+                #[cfg(debug_assertions)]
+                println!("🔊 Playing pad touchdown sound");
+            }
+            AudioEvent::LowFuelBeep => {
+                #[cfg(debug_assertions)]
+                println!("🔊 Playing low fuel beep");
+            }
+            AudioEvent::CriticalFuelAlarm => {
+                #[cfg(debug_assertions)]
+                println!("🔊 Playing critical fuel alarm");
+            }
+            AudioEvent::NearMiss => {
+                #[cfg(debug_assertions)]
+                println!("🔊 Playing near miss whoosh");
+            }
+            AudioEvent::ComboMilestone => {
+                #[cfg(debug_assertions)]
+                println!("🔊 Playing combo milestone fanfare");
+            }
+            AudioEvent::AchievementUnlocked => {
+                #[cfg(debug_assertions)]
+                println!("🔊 Playing achievement jingle");
+            }
+            AudioEvent::RunComplete => {
+                #[cfg(debug_assertions)]
+                println!("🔊 Playing victory fanfare");
+            }
         }
     }
 
@@ -129,14 +226,51 @@ impl AudioSystem {
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum MenuSelection {
     Start,
+    HighScores,
+    Stats,
+    Achievements,
     Quit,
 }
 
+impl MenuSelection {
+    /// Gets the entry below this one, wrapping around
+    fn next(self) -> Self {
+        match self {
+            MenuSelection::Start => MenuSelection::HighScores,
+            MenuSelection::HighScores => MenuSelection::Stats,
+            MenuSelection::Stats => MenuSelection::Achievements,
+            MenuSelection::Achievements => MenuSelection::Quit,
+            MenuSelection::Quit => MenuSelection::Start,
+        }
+    }
+
+    /// Gets the entry above this one, wrapping around
+    fn previous(self) -> Self {
+        match self {
+            MenuSelection::Start => MenuSelection::Quit,
+            MenuSelection::HighScores => MenuSelection::Start,
+            MenuSelection::Stats => MenuSelection::HighScores,
+            MenuSelection::Achievements => MenuSelection::Stats,
+            MenuSelection::Quit => MenuSelection::Achievements,
+        }
+    }
+}
+
+/// Screen shown while in the menu state
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MenuScreen {
+    Main,
+    HighScores,
+    Stats,
+    Achievements,
+}
+
 /// Menu state for keyboard navigation
 struct MenuState {
     main_menu_selection: MenuSelection,
     pause_menu_selection: usize, // 0 = Resume, 1 = Back to Menu
     game_over_menu_selection: usize, // 0 = Replay, 1 = Back to Menu
+    screen: MenuScreen,
 }
 
 impl MenuState {
@@ -145,6 +279,7 @@ impl MenuState {
             main_menu_selection: MenuSelection::Start,
             pause_menu_selection: 0,
             game_over_menu_selection: 0,
+            screen: MenuScreen::Main,
         }
     }
 }
@@ -154,8 +289,46 @@ struct GameWorld {
     state_machine: StateMachine,
     player: Player,
     fuel: Fuel,
+    fuel_consumption: FuelConsumption,
+    endurance: EnduranceEstimator,
+    fuel_outlook: Option<FuelOutlook>,
+    hull: Hull,
+    damage_model: DamageModel,
+    lives: Lives,
+    checkpoints: CheckpointTracker,
+    refuelling: bool,
+    thrusting: bool,
+    fly_in: Option<FlyIn>,
+    last_run: Option<RunResult>,
+    seed: u32,
+    mode: GameMode,
+    difficulty: Difficulty,
+    scroll_speed: f32,
+    storage: GuardedStorage,
+    high_scores: HighScoreTable,
+    high_score_rank: Option<usize>,
+    viewed_scores: (GameMode, Difficulty),
+    viewed_high_scores: HighScoreTable,
+    name_entry: Option<NameEntry>,
+    last_name: String,
+    score: Score,
+    run_stats: RunStats,
+    lifetime_stats: LifetimeStats,
+    achievements: AchievementTracker,
+    achievement_toasts: Vec<(Achievement, f32)>,
+    death_log: DeathLog,
+    death_heat_strip: DeathHeatStrip,
+    show_death_overlay: bool,
+    run_events: RunEventQueue,
+    near_miss: NearMissDetector,
+    near_miss_cue: Option<(u32, f32)>,
+    combo: ComboTracker,
     cave: Cave,
     tractor_beam: TractorBeam,
+    beam_fuel_fallback: bool,
+    cargo_mode: bool,
+    cargo_mission: CargoMission,
+    cargo_hold: CargoHold,
     distance_tracker: DistanceTracker,
     level_manager: LevelManager,
     audio_queue: AudioEventQueue,
@@ -167,12 +340,84 @@ struct GameWorld {
 
 impl GameWorld {
     fn new() -> Self {
+        let mut storage = GuardedStorage::new(storage::open_storage());
+        let mode = GameMode::default();
+        let high_scores = load_high_scores(&mut storage, mode, Difficulty::default());
+        let viewed_high_scores = high_scores.clone();
+        let lifetime_stats = load_or_protect(
+            &mut storage,
+            StatsConstants::STORAGE_KEY,
+            "lifetime stats",
+            LifetimeStats::load,
+        );
+        let achievements = load_or_protect(
+            &mut storage,
+            AchievementConstants::STORAGE_KEY,
+            "achievements",
+            AchievementTracker::load,
+        );
+        let death_log = load_or_protect(
+            &mut storage,
+            AnalyticsConstants::STORAGE_KEY,
+            "death log",
+            DeathLog::load,
+        );
+        let death_heat_strip = DeathHeatStrip::from_records(
+            death_log.records_for_run(WorldConstants::CAVE_SEED, mode, Difficulty::default()),
+            AnalyticsConstants::HEAT_BUCKET_WIDTH,
+        );
+        let last_name = storage
+            .load(HighScoreConstants::NAME_STORAGE_KEY)
+            .unwrap_or_else(|e| {
+                eprintln!("Failed to load player name: {}", e);
+                None
+            })
+            .unwrap_or_default();
+
         Self {
             state_machine: StateMachine::new(),
             player: Player::new(Vec2::new(100.0, 300.0)),
             fuel: Fuel::new(INITIAL_FUEL, FUEL_BURN_RATE),
-            cave: Cave::new(42), // Fixed seed for consistent cave
+            fuel_consumption: FuelConsumption::default(),
+            endurance: EnduranceEstimator::new(),
+            fuel_outlook: None,
+            hull: Hull::default(),
+            damage_model: DamageModel::default(),
+            lives: Lives::default(),
+            checkpoints: CheckpointTracker::default(),
+            refuelling: false,
+            thrusting: false,
+            fly_in: None,
+            last_run: None,
+            seed: WorldConstants::CAVE_SEED,
+            mode,
+            difficulty: Difficulty::default(),
+            scroll_speed: SCROLL_SPEED,
+            storage,
+            high_scores,
+            high_score_rank: None,
+            viewed_scores: (mode, Difficulty::default()),
+            viewed_high_scores,
+            name_entry: None,
+            last_name,
+            score: Score::new(),
+            run_stats: RunStats::new(),
+            lifetime_stats,
+            achievements,
+            achievement_toasts: Vec::new(),
+            death_log,
+            death_heat_strip,
+            show_death_overlay: false,
+            run_events: RunEventQueue::new(),
+            near_miss: NearMissDetector::new(),
+            near_miss_cue: None,
+            combo: ComboTracker::new(),
+            cave: Cave::new(WorldConstants::CAVE_SEED),
             tractor_beam: TractorBeam::new(),
+            beam_fuel_fallback: false,
+            cargo_mode: false,
+            cargo_mission: CargoMission::default(),
+            cargo_hold: CargoHold::new(),
             distance_tracker: DistanceTracker::new(),
             level_manager: LevelManager::new(),
             audio_queue: AudioEventQueue::new(),
@@ -188,20 +433,139 @@ impl GameWorld {
         self.level_manager.current_level()
             .map(|level| level.fuel_spawn_distance)
             .unwrap_or(PickupConstants::DEFAULT_FUEL_SPAWN_DISTANCE)
+            * self.difficulty.settings().pickup_spacing
+    }
+
+    /// Creates an idle tractor beam with the run's fuel fallback and beam duration
+    fn fresh_tractor_beam(&self) -> TractorBeam {
+        let mut tractor_beam = TractorBeam::new();
+        tractor_beam.fuel_fallback = self.beam_fuel_fallback;
+        tractor_beam.max_duration *= self.difficulty.settings().beam_duration;
+        tractor_beam
     }
 
     /// Resets the game world for a new game.
     fn reset(&mut self) {
+        let settings = self.difficulty.settings();
         self.player = Player::new(Vec2::new(100.0, 300.0));
-        self.fuel = Fuel::new(INITIAL_FUEL, FUEL_BURN_RATE);
-        self.tractor_beam = TractorBeam::new();
+        self.fuel = Fuel::new(INITIAL_FUEL, FUEL_BURN_RATE * settings.fuel_burn_rate);
+        self.endurance.reset();
+        self.fuel_outlook = None;
+        self.hull = Hull::default();
+        self.lives = Lives::default();
+        self.checkpoints.reset();
+        self.refuelling = false;
+        self.thrusting = false;
+        self.fly_in = None;
+        self.last_run = None;
+        self.high_score_rank = None;
+        self.name_entry = None;
+        self.score = Score::for_mode(self.mode);
+        self.run_stats = RunStats::new();
+        self.achievements.start_run();
+        self.run_events.drain();
+        self.death_heat_strip = DeathHeatStrip::from_records(
+            self.death_log.records_for_run(self.seed, self.mode, self.difficulty),
+            AnalyticsConstants::HEAT_BUCKET_WIDTH,
+        );
+        self.near_miss.reset();
+        self.near_miss_cue = None;
+        self.combo.reset();
+        self.tractor_beam = self.fresh_tractor_beam();
         self.distance_tracker.reset();
-        self.level_manager.reset();
+        self.level_manager = self.mode.level_manager();
+        self.scroll_speed = self.mode.scroll_speed(false) * settings.scroll_speed;
         self.camera_offset_x = 0.0;
         self.collision_flash_timer = 0.0;
         // Reset cave with new pickup manager and configure for level 1
-        self.cave = Cave::new(42);
+        self.cave = Cave::new(self.seed);
+        self.cave.set_gap_scale(settings.cave_gap);
+        if self.damage_model == DamageModel::Hull {
+            self.cave
+                .pickup_manager_mut()
+                .set_shield_spawn_chance(HullConstants::SHIELD_SPAWN_CHANCE);
+        }
+        if self.cargo_mode {
+            self.cave
+                .pickup_manager_mut()
+                .set_cargo_spawn_chance(CargoConstants::POD_SPAWN_CHANCE);
+            self.cave.drop_off_manager_mut().set_enabled(true);
+        }
         self.cave.configure_for_level(1);
+        self.apply_level_hazards();
+        self.cargo_mission = CargoMission::new(self.current_cargo_goal());
+        self.cargo_hold = CargoHold::new();
+    }
+
+    /// Switches the game mode and loads its high-score table
+    fn select_mode(&mut self, mode: GameMode) {
+        self.mode = mode;
+        self.high_scores = load_high_scores(&mut self.storage, mode, self.difficulty);
+    }
+
+    /// Switches the difficulty preset and loads its high-score table
+    fn select_difficulty(&mut self, difficulty: Difficulty) {
+        self.difficulty = difficulty;
+        self.high_scores = load_high_scores(&mut self.storage, self.mode, difficulty);
+    }
+
+    /// Loads a table for the high-score screen without changing the run's mode or difficulty
+    fn view_high_scores(&mut self, mode: GameMode, difficulty: Difficulty) {
+        self.viewed_scores = (mode, difficulty);
+        self.viewed_high_scores = load_high_scores(&mut self.storage, mode, difficulty);
+    }
+
+    /// Gets the current level's landing pad refuel rate
+    fn current_refuel_rate(&self) -> f32 {
+        self.level_manager.current_level()
+            .map(|level| level.refuel_rate)
+            .unwrap_or(0.0)
+    }
+
+    /// Gets the current level's cargo delivery goal
+    fn current_cargo_goal(&self) -> u32 {
+        self.level_manager.current_level()
+            .map(|level| level.cargo_goal)
+            .unwrap_or(0)
+    }
+
+    /// Applies the current level's hazard density, scaled by difficulty, to the cave.
+    fn apply_level_hazards(&mut self) {
+        let density = self.level_manager.current_level()
+            .map(|level| level.hazard_density)
+            .unwrap_or(0.0)
+            * self.difficulty.settings().hazard_density;
+        self.cave.hazard_manager_mut().set_density(density);
+    }
+}
+
+/// Loads a mode and difficulty's high-score table, or an empty one if it can't be read
+fn load_high_scores(storage: &mut GuardedStorage, mode: GameMode, difficulty: Difficulty) -> HighScoreTable {
+    let key = mode.high_score_key(difficulty);
+    load_or_protect(storage, &key, "high scores", |storage| {
+        HighScoreTable::load_from(storage, &key)
+    })
+}
+
+/// Loads a stored record, falling back to defaults if it can't be read.
+///
+/// The unreadable record is backed up first so the next save doesn't
+/// silently replace the player's data; if that fails, saves to it are refused.
+fn load_or_protect<T: Default>(
+    storage: &mut GuardedStorage,
+    key: &str,
+    what: &str,
+    load: impl FnOnce(&dyn Storage) -> StorageResult<T>,
+) -> T {
+    match load(storage) {
+        Ok(value) => value,
+        Err(e) => {
+            eprintln!("Failed to load {}: {}", what, e);
+            if let Err(e) = storage.protect(key) {
+                eprintln!("Failed to back up {}, it won't be saved this session: {}", what, e);
+            }
+            T::default()
+        }
     }
 }
 
@@ -217,15 +581,49 @@ fn window_conf() -> Conf {
 
 /// Handles main menu UI and interactions
 fn handle_main_menu(world: &mut GameWorld, _audio_system: &mut AudioSystem) {
+    match world.menu_state.screen {
+        MenuScreen::Main => {}
+        MenuScreen::HighScores => {
+            handle_high_scores_screen(world);
+            return;
+        }
+        MenuScreen::Stats => {
+            handle_stats_screen(world);
+            return;
+        }
+        MenuScreen::Achievements => {
+            handle_achievements_screen(world);
+            return;
+        }
+    }
+
     let center_x = WINDOW_WIDTH as f32 / 2.0 - BUTTON_WIDTH / 2.0;
-    let center_y = WINDOW_HEIGHT as f32 / 2.0;
+    // Raised so all five buttons fit on screen
+    let center_y = WINDOW_HEIGHT as f32 / 2.0 - 60.0;
 
     // Handle keyboard navigation
-    if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::Down) {
-        world.menu_state.main_menu_selection = match world.menu_state.main_menu_selection {
-            MenuSelection::Start => MenuSelection::Quit,
-            MenuSelection::Quit => MenuSelection::Start,
-        };
+    if is_key_pressed(KeyCode::Up) {
+        world.menu_state.main_menu_selection = world.menu_state.main_menu_selection.previous();
+        world.audio_queue.push(AudioEvent::ButtonClick);
+    }
+    if is_key_pressed(KeyCode::Down) {
+        world.menu_state.main_menu_selection = world.menu_state.main_menu_selection.next();
+        world.audio_queue.push(AudioEvent::ButtonClick);
+    }
+
+    // Cycle the game mode with Left/Right
+    if is_key_pressed(KeyCode::Left) {
+        world.select_mode(world.mode.previous());
+        world.audio_queue.push(AudioEvent::ButtonClick);
+    }
+    if is_key_pressed(KeyCode::Right) {
+        world.select_mode(world.mode.next());
+        world.audio_queue.push(AudioEvent::ButtonClick);
+    }
+
+    // Cycle the difficulty preset with D
+    if is_key_pressed(KeyCode::D) {
+        world.select_difficulty(world.difficulty.next());
         world.audio_queue.push(AudioEvent::ButtonClick);
     }
 
@@ -237,6 +635,19 @@ fn handle_main_menu(world: &mut GameWorld, _audio_system: &mut AudioSystem) {
                 world.state_machine.handle_event(GameEvent::Start);
                 world.reset();
             }
+            MenuSelection::HighScores => {
+                world.audio_queue.push(AudioEvent::ButtonClick);
+                world.view_high_scores(world.mode, world.difficulty);
+                world.menu_state.screen = MenuScreen::HighScores;
+            }
+            MenuSelection::Stats => {
+                world.audio_queue.push(AudioEvent::ButtonClick);
+                world.menu_state.screen = MenuScreen::Stats;
+            }
+            MenuSelection::Achievements => {
+                world.audio_queue.push(AudioEvent::ButtonClick);
+                world.menu_state.screen = MenuScreen::Achievements;
+            }
             MenuSelection::Quit => {
                 world.audio_queue.push(AudioEvent::ButtonClick);
                 world.should_quit = true;
@@ -248,15 +659,18 @@ fn handle_main_menu(world: &mut GameWorld, _audio_system: &mut AudioSystem) {
     draw_text(
         "FUEL DRIFT",
         WINDOW_WIDTH as f32 / 2.0 - 80.0,
-        center_y - 100.0,
+        center_y - 170.0,
         40.0,
         WHITE,
     );
 
+    render_mode_selector(world, center_y - 125.0);
+    render_difficulty_selector(world, center_y - 75.0);
+
     // Instructions
     draw_text(
-        "Arrow keys: Move | W/S: Tractor Beam | Watch your fuel!",
-        WINDOW_WIDTH as f32 / 2.0 - 180.0,
+        "Arrows: Move | W/S: Beam | E: Push/Pull | Mouse: Aim & Fire | Watch your fuel!",
+        WINDOW_WIDTH as f32 / 2.0 - 255.0,
         center_y - 50.0,
         16.0,
         GRAY,
@@ -264,8 +678,8 @@ fn handle_main_menu(world: &mut GameWorld, _audio_system: &mut AudioSystem) {
     
     // Keyboard instructions
     draw_text(
-        "Press SPACE or ENTER to select",
-        WINDOW_WIDTH as f32 / 2.0 - 120.0,
+        "LEFT/RIGHT: mode | D: difficulty | SPACE or ENTER to select",
+        WINDOW_WIDTH as f32 / 2.0 - 205.0,
         center_y - 30.0,
         14.0,
         GRAY,
@@ -297,6 +711,82 @@ fn handle_main_menu(world: &mut GameWorld, _audio_system: &mut AudioSystem) {
         world.reset();
     }
 
+    // High scores button with selection highlight
+    let high_scores_color = if world.menu_state.main_menu_selection == MenuSelection::HighScores {
+        YELLOW
+    } else {
+        WHITE
+    };
+
+    draw_rectangle_lines(
+        center_x - 5.0,
+        center_y + BUTTON_HEIGHT + MENU_SPACING - 5.0,
+        BUTTON_WIDTH + 10.0,
+        BUTTON_HEIGHT + 10.0,
+        2.0,
+        high_scores_color,
+    );
+
+    if widgets::Button::new("High Scores")
+        .position(vec2(center_x, center_y + BUTTON_HEIGHT + MENU_SPACING))
+        .size(vec2(BUTTON_WIDTH, BUTTON_HEIGHT))
+        .ui(&mut root_ui())
+    {
+        world.audio_queue.push(AudioEvent::ButtonClick);
+        world.view_high_scores(world.mode, world.difficulty);
+        world.menu_state.screen = MenuScreen::HighScores;
+    }
+
+    // Stats button with selection highlight
+    let stats_color = if world.menu_state.main_menu_selection == MenuSelection::Stats {
+        YELLOW
+    } else {
+        WHITE
+    };
+
+    draw_rectangle_lines(
+        center_x - 5.0,
+        center_y + 2.0 * (BUTTON_HEIGHT + MENU_SPACING) - 5.0,
+        BUTTON_WIDTH + 10.0,
+        BUTTON_HEIGHT + 10.0,
+        2.0,
+        stats_color,
+    );
+
+    if widgets::Button::new("Stats")
+        .position(vec2(center_x, center_y + 2.0 * (BUTTON_HEIGHT + MENU_SPACING)))
+        .size(vec2(BUTTON_WIDTH, BUTTON_HEIGHT))
+        .ui(&mut root_ui())
+    {
+        world.audio_queue.push(AudioEvent::ButtonClick);
+        world.menu_state.screen = MenuScreen::Stats;
+    }
+
+    // Achievements button with selection highlight
+    let achievements_color = if world.menu_state.main_menu_selection == MenuSelection::Achievements {
+        YELLOW
+    } else {
+        WHITE
+    };
+
+    draw_rectangle_lines(
+        center_x - 5.0,
+        center_y + 3.0 * (BUTTON_HEIGHT + MENU_SPACING) - 5.0,
+        BUTTON_WIDTH + 10.0,
+        BUTTON_HEIGHT + 10.0,
+        2.0,
+        achievements_color,
+    );
+
+    if widgets::Button::new("Achievements")
+        .position(vec2(center_x, center_y + 3.0 * (BUTTON_HEIGHT + MENU_SPACING)))
+        .size(vec2(BUTTON_WIDTH, BUTTON_HEIGHT))
+        .ui(&mut root_ui())
+    {
+        world.audio_queue.push(AudioEvent::ButtonClick);
+        world.menu_state.screen = MenuScreen::Achievements;
+    }
+
     // Quit button with selection highlight
     let quit_color = if world.menu_state.main_menu_selection == MenuSelection::Quit {
         YELLOW
@@ -306,7 +796,7 @@ fn handle_main_menu(world: &mut GameWorld, _audio_system: &mut AudioSystem) {
     
     draw_rectangle_lines(
         center_x - 5.0,
-        center_y + BUTTON_HEIGHT + MENU_SPACING - 5.0,
+        center_y + 4.0 * (BUTTON_HEIGHT + MENU_SPACING) - 5.0,
         BUTTON_WIDTH + 10.0,
         BUTTON_HEIGHT + 10.0,
         2.0,
@@ -314,12 +804,365 @@ fn handle_main_menu(world: &mut GameWorld, _audio_system: &mut AudioSystem) {
     );
     
     if widgets::Button::new("Quit")
-        .position(vec2(center_x, center_y + BUTTON_HEIGHT + MENU_SPACING))
+        .position(vec2(center_x, center_y + 4.0 * (BUTTON_HEIGHT + MENU_SPACING)))
         .size(vec2(BUTTON_WIDTH, BUTTON_HEIGHT))
         .ui(&mut root_ui())
     {
         world.audio_queue.push(AudioEvent::ButtonClick);
-        world.should_quit = true;
+        world.should_quit = true;
+    }
+}
+
+/// Renders the game mode picker with its description and arrow buttons
+fn render_mode_selector(world: &mut GameWorld, y: f32) {
+    let arrow_size = 30.0;
+    let mode_text = world.mode.title().to_uppercase();
+    let mode_width = measure_text(&mode_text, None, 28, 1.0).width;
+    draw_text(&mode_text, (WINDOW_WIDTH as f32 - mode_width) / 2.0, y, 28.0, YELLOW);
+
+    let description = world.mode.description();
+    let description_width = measure_text(description, None, 16, 1.0).width;
+    draw_text(
+        description,
+        (WINDOW_WIDTH as f32 - description_width) / 2.0,
+        y + 22.0,
+        16.0,
+        LIGHTGRAY,
+    );
+
+    if widgets::Button::new("<")
+        .position(vec2(WINDOW_WIDTH as f32 / 2.0 - 150.0, y - 24.0))
+        .size(vec2(arrow_size, arrow_size))
+        .ui(&mut root_ui())
+    {
+        world.audio_queue.push(AudioEvent::ButtonClick);
+        world.select_mode(world.mode.previous());
+    }
+    if widgets::Button::new(">")
+        .position(vec2(WINDOW_WIDTH as f32 / 2.0 + 150.0 - arrow_size, y - 24.0))
+        .size(vec2(arrow_size, arrow_size))
+        .ui(&mut root_ui())
+    {
+        world.audio_queue.push(AudioEvent::ButtonClick);
+        world.select_mode(world.mode.next());
+    }
+}
+
+/// Renders the difficulty picker with its arrow buttons
+fn render_difficulty_selector(world: &mut GameWorld, y: f32) {
+    let arrow_size = 24.0;
+    let text = format!("DIFFICULTY: {}", world.difficulty.title().to_uppercase());
+    let text_width = measure_text(&text, None, 20, 1.0).width;
+    let color = match world.difficulty {
+        Difficulty::Easy => GREEN,
+        Difficulty::Normal => WHITE,
+        Difficulty::Hard => ORANGE,
+        Difficulty::Insane => RED,
+    };
+    draw_text(&text, (WINDOW_WIDTH as f32 - text_width) / 2.0, y, 20.0, color);
+
+    if widgets::Button::new("-")
+        .position(vec2(WINDOW_WIDTH as f32 / 2.0 - 150.0, y - 18.0))
+        .size(vec2(arrow_size, arrow_size))
+        .ui(&mut root_ui())
+    {
+        world.audio_queue.push(AudioEvent::ButtonClick);
+        world.select_difficulty(world.difficulty.previous());
+    }
+    if widgets::Button::new("+")
+        .position(vec2(WINDOW_WIDTH as f32 / 2.0 + 150.0 - arrow_size, y - 18.0))
+        .size(vec2(arrow_size, arrow_size))
+        .ui(&mut root_ui())
+    {
+        world.audio_queue.push(AudioEvent::ButtonClick);
+        world.select_difficulty(world.difficulty.next());
+    }
+}
+
+/// Handles the high-score table screen opened from the main menu
+fn handle_high_scores_screen(world: &mut GameWorld) {
+    let back_pressed = is_key_pressed(KeyCode::Escape)
+        || is_key_pressed(KeyCode::Enter)
+        || is_key_pressed(KeyCode::Space);
+
+    // Each game mode and difficulty keeps its own table
+    let (mode, difficulty) = world.viewed_scores;
+    if is_key_pressed(KeyCode::Left) {
+        world.view_high_scores(mode.previous(), difficulty);
+        world.audio_queue.push(AudioEvent::ButtonClick);
+    }
+    if is_key_pressed(KeyCode::Right) {
+        world.view_high_scores(mode.next(), difficulty);
+        world.audio_queue.push(AudioEvent::ButtonClick);
+    }
+    if is_key_pressed(KeyCode::Up) {
+        world.view_high_scores(mode, difficulty.previous());
+        world.audio_queue.push(AudioEvent::ButtonClick);
+    }
+    if is_key_pressed(KeyCode::Down) {
+        world.view_high_scores(mode, difficulty.next());
+        world.audio_queue.push(AudioEvent::ButtonClick);
+    }
+    let (mode, difficulty) = world.viewed_scores;
+
+    draw_text(
+        "HIGH SCORES",
+        WINDOW_WIDTH as f32 / 2.0 - 95.0,
+        60.0,
+        36.0,
+        WHITE,
+    );
+
+    let mode_text = format!(
+        "< {} - {} >",
+        mode.title().to_uppercase(),
+        difficulty.title().to_uppercase()
+    );
+    let mode_width = measure_text(&mode_text, None, 20, 1.0).width;
+    draw_text(&mode_text, (WINDOW_WIDTH as f32 - mode_width) / 2.0, 88.0, 20.0, YELLOW);
+
+    render_high_score_table(&world.viewed_high_scores, 120.0);
+
+    draw_text(
+        "LEFT/RIGHT mode, UP/DOWN difficulty, ESC, SPACE or ENTER to go back",
+        WINDOW_WIDTH as f32 / 2.0 - 200.0,
+        WINDOW_HEIGHT as f32 - 100.0,
+        14.0,
+        GRAY,
+    );
+
+    let back_clicked = widgets::Button::new("Back")
+        .position(vec2(
+            WINDOW_WIDTH as f32 / 2.0 - BUTTON_WIDTH / 2.0,
+            WINDOW_HEIGHT as f32 - 80.0,
+        ))
+        .size(vec2(BUTTON_WIDTH, BUTTON_HEIGHT))
+        .ui(&mut root_ui());
+
+    if back_pressed || back_clicked {
+        world.audio_queue.push(AudioEvent::ButtonClick);
+        world.menu_state.screen = MenuScreen::Main;
+    }
+}
+
+/// Handles the statistics screen opened from the main menu
+fn handle_stats_screen(world: &mut GameWorld) {
+    let back_pressed = is_key_pressed(KeyCode::Escape)
+        || is_key_pressed(KeyCode::Enter)
+        || is_key_pressed(KeyCode::Space);
+
+    draw_text("STATS", WINDOW_WIDTH as f32 / 2.0 - 45.0, 60.0, 36.0, WHITE);
+
+    let last_run = world.last_run.map(|_| world.run_stats.lines());
+    render_stats_column("LAST RUN", last_run.as_deref(), 30.0, 100.0);
+    render_stats_column("LIFETIME", Some(&world.lifetime_stats.lines()), 420.0, 100.0);
+
+    let back_clicked = widgets::Button::new("Back")
+        .position(vec2(
+            WINDOW_WIDTH as f32 / 2.0 - BUTTON_WIDTH / 2.0,
+            WINDOW_HEIGHT as f32 - 70.0,
+        ))
+        .size(vec2(BUTTON_WIDTH, BUTTON_HEIGHT))
+        .ui(&mut root_ui());
+
+    if back_pressed || back_clicked {
+        world.audio_queue.push(AudioEvent::ButtonClick);
+        world.menu_state.screen = MenuScreen::Main;
+    }
+}
+
+/// Handles the achievements screen opened from the main menu
+fn handle_achievements_screen(world: &mut GameWorld) {
+    let back_pressed = is_key_pressed(KeyCode::Escape)
+        || is_key_pressed(KeyCode::Enter)
+        || is_key_pressed(KeyCode::Space);
+
+    let tracker = &world.achievements;
+    let header = format!(
+        "ACHIEVEMENTS {}/{}",
+        tracker.unlocked_count(),
+        tracker.achievements().len()
+    );
+    draw_text(&header, WINDOW_WIDTH as f32 / 2.0 - 140.0, 60.0, 36.0, WHITE);
+
+    let row_height = 52.0;
+    for (index, achievement) in tracker.achievements().iter().enumerate() {
+        let y = 110.0 + index as f32 * row_height;
+        let unlocked = tracker.is_unlocked(achievement.id);
+        let (title_color, status) = if unlocked {
+            (YELLOW, "UNLOCKED")
+        } else {
+            (GRAY, "LOCKED")
+        };
+
+        draw_text(achievement.title, 80.0, y, 24.0, title_color);
+        draw_text(achievement.description, 80.0, y + 20.0, 16.0, LIGHTGRAY);
+        draw_text(status, WINDOW_WIDTH as f32 - 180.0, y, 18.0, title_color);
+    }
+
+    let back_clicked = widgets::Button::new("Back")
+        .position(vec2(
+            WINDOW_WIDTH as f32 / 2.0 - BUTTON_WIDTH / 2.0,
+            WINDOW_HEIGHT as f32 - 70.0,
+        ))
+        .size(vec2(BUTTON_WIDTH, BUTTON_HEIGHT))
+        .ui(&mut root_ui());
+
+    if back_pressed || back_clicked {
+        world.audio_queue.push(AudioEvent::ButtonClick);
+        world.menu_state.screen = MenuScreen::Main;
+    }
+}
+
+/// Renders a titled column of labelled statistics.
+fn render_stats_column(title: &str, lines: Option<&[(String, String)]>, left_x: f32, top_y: f32) {
+    let value_x = left_x + 180.0;
+    let line_height = 22.0;
+    let text_size = 16.0;
+
+    draw_text(title, left_x, top_y, 20.0, YELLOW);
+
+    let Some(lines) = lines else {
+        draw_text("No finished run yet", left_x, top_y + line_height * 1.5, text_size, GRAY);
+        return;
+    };
+
+    for (row, (label, value)) in lines.iter().enumerate() {
+        let y = top_y + (row as f32 + 1.5) * line_height;
+        draw_text(label, left_x, y, text_size, LIGHTGRAY);
+        draw_text(value, value_x, y, text_size, WHITE);
+    }
+}
+
+/// Renders the high-score table with one row per run.
+fn render_high_score_table(table: &HighScoreTable, top_y: f32) {
+    let columns = [15.0, 35.0, 140.0, 205.0, 285.0, 340.0, 390.0, 435.0, 500.0, 595.0];
+    let headers = ["#", "NAME", "SCORE", "DISTANCE", "TIME", "LEVEL", "SEED", "DIFF", "DATE", "CAUSE"];
+    let line_height = 24.0;
+    let text_size = 16.0;
+
+    if table.entries().is_empty() {
+        draw_text(
+            "No runs recorded yet",
+            WINDOW_WIDTH as f32 / 2.0 - 90.0,
+            top_y + line_height,
+            text_size,
+            GRAY,
+        );
+        return;
+    }
+
+    for (header, x) in headers.iter().zip(columns) {
+        draw_text(header, x, top_y, text_size, YELLOW);
+    }
+
+    for (rank, entry) in table.entries().iter().enumerate() {
+        let y = top_y + (rank + 1) as f32 * line_height;
+        let cells = [
+            format!("{}", rank + 1),
+            if entry.name.is_empty() { "-".to_string() } else { entry.name.clone() },
+            entry.score.to_string(),
+            format!("{}m", entry.distance),
+            format!("{:.1}s", entry.elapsed_time),
+            entry.level_number.to_string(),
+            entry.seed.to_string(),
+            entry.difficulty.to_string(),
+            entry.date_formatted(),
+            entry.outcome(),
+        ];
+        for (cell, x) in cells.iter().zip(columns) {
+            draw_text(cell, x, y, text_size, WHITE);
+        }
+    }
+}
+
+/// Handles the name-entry screen shown when a run makes the high-score table
+fn handle_name_entry(world: &mut GameWorld) {
+    let Some(entry) = world.name_entry.as_mut() else {
+        return;
+    };
+
+    // Keyboard text entry; in arcade entry SPACE confirms, so a pad mapped
+    // to keys can finish the name without typing
+    let mut confirmed = is_key_pressed(KeyCode::Enter) || is_key_pressed(KeyCode::Escape);
+    while let Some(c) = get_char_pressed() {
+        if c == ' ' && entry.mode() == NameEntryMode::Arcade {
+            confirmed = true;
+        } else if NameEntry::accepts(c) {
+            entry.type_char(c);
+        }
+    }
+    if is_key_pressed(KeyCode::Backspace) {
+        entry.backspace();
+    }
+
+    // Arcade-style letter cycling
+    if is_key_pressed(KeyCode::Up) {
+        entry.cycle_up();
+    }
+    if is_key_pressed(KeyCode::Down) {
+        entry.cycle_down();
+    }
+    if is_key_pressed(KeyCode::Left) {
+        entry.previous_slot();
+    }
+    if is_key_pressed(KeyCode::Right) {
+        entry.next_slot();
+    }
+
+    let center_y = WINDOW_HEIGHT as f32 / 2.0;
+    let title = "NEW HIGH SCORE!";
+    let title_width = measure_text(title, None, 36, 1.0).width;
+    draw_text(title, (WINDOW_WIDTH as f32 - title_width) / 2.0, center_y - 120.0, 36.0, YELLOW);
+
+    let score_text = format!("SCORE {}", world.score.total());
+    let score_width = measure_text(&score_text, None, 20, 1.0).width;
+    draw_text(&score_text, (WINDOW_WIDTH as f32 - score_width) / 2.0, center_y - 80.0, 20.0, WHITE);
+
+    render_name_entry(entry, center_y - 20.0);
+
+    draw_text(
+        "Type your name, or pick letters with UP/DOWN and LEFT/RIGHT",
+        WINDOW_WIDTH as f32 / 2.0 - 210.0,
+        center_y + 60.0,
+        14.0,
+        GRAY,
+    );
+    draw_text(
+        "Press ENTER to confirm (or SPACE when picking letters)",
+        WINDOW_WIDTH as f32 / 2.0 - 185.0,
+        center_y + 80.0,
+        14.0,
+        GRAY,
+    );
+
+    if confirmed {
+        world.audio_queue.push(AudioEvent::ButtonClick);
+        confirm_name_entry(world);
+    }
+}
+
+/// Renders the name being entered: free text with a caret, or arcade slots.
+fn render_name_entry(entry: &NameEntry, y: f32) {
+    let text_size = 40.0;
+
+    match entry.mode() {
+        NameEntryMode::Keyboard => {
+            let caret = if (get_time() * 2.0) as i64 % 2 == 0 { "_" } else { " " };
+            let name_text = format!("{}{}", entry.text(), caret);
+            let name_width = measure_text(&name_text, None, text_size as u16, 1.0).width;
+            draw_text(&name_text, (WINDOW_WIDTH as f32 - name_width) / 2.0, y, text_size, WHITE);
+        }
+        NameEntryMode::Arcade => {
+            let slot_width = 40.0;
+            let left = (WINDOW_WIDTH as f32 - slot_width * HighScoreConstants::ARCADE_NAME_LENGTH as f32) / 2.0;
+            for (slot, c) in entry.text().chars().enumerate() {
+                let x = left + slot as f32 * slot_width;
+                let color = if slot == entry.cursor() { YELLOW } else { WHITE };
+                draw_text(&c.to_string(), x + 8.0, y, text_size, color);
+                draw_line(x + 4.0, y + 6.0, x + slot_width - 4.0, y + 6.0, 2.0, color);
+            }
+        }
     }
 }
 
@@ -352,7 +1195,7 @@ fn handle_pause_menu(world: &mut GameWorld) {
             }
             1 => {
                 world.audio_queue.push(AudioEvent::ButtonClick);
-                world.state_machine.handle_event(GameEvent::BackToMenu);
+                leave_paused_run(world);
             }
             _ => {}
         }
@@ -418,18 +1261,29 @@ fn handle_pause_menu(world: &mut GameWorld) {
         back_color,
     );
 
-    // Back to Menu button
-    if widgets::Button::new("Back to Menu")
+    // Back to Menu button; a run without death ends here instead
+    let back_label = if world.mode.is_lethal() { "Back to Menu" } else { "End Run" };
+    if widgets::Button::new(back_label)
         .position(vec2(center_x, center_y + BUTTON_HEIGHT + MENU_SPACING))
         .size(vec2(BUTTON_WIDTH, BUTTON_HEIGHT))
         .ui(&mut root_ui())
     {
         world.audio_queue.push(AudioEvent::ButtonClick);
+        leave_paused_run(world);
+    }
+}
+
+/// Leaves a paused run: back to the menu, or to the results if the mode has no death
+fn leave_paused_run(world: &mut GameWorld) {
+    if world.mode.is_lethal() {
+        save_death_log(world);
         world.state_machine.handle_event(GameEvent::BackToMenu);
+    } else {
+        end_run(world, None);
     }
 }
 
-/// Handles game over menu
+/// Handles the game over and victory menus
 fn handle_game_over_menu(world: &mut GameWorld) {
     let center_x = WINDOW_WIDTH as f32 / 2.0 - BUTTON_WIDTH / 2.0;
     let center_y = WINDOW_HEIGHT as f32 / 2.0;
@@ -456,36 +1310,66 @@ fn handle_game_over_menu(world: &mut GameWorld) {
         }
     }
 
-    let death_message = if world.fuel.is_empty() {
-        "OUT OF FUEL!"
-    } else {
-        "CRASHED!"
+    let (headline, headline_color, message) = match world.last_run {
+        Some(run) if run.is_completed() => {
+            let headline = if world.mode.is_lethal() { "VICTORY!" } else { "RUN OVER" };
+            (headline, GREEN, world.mode.completion_message())
+        }
+        run => (
+            "GAME OVER",
+            RED,
+            run.and_then(|run| run.death_cause)
+                .map_or("CRASHED!", |cause| cause.message()),
+        ),
     };
 
-    // Game Over title
+    // Game Over or Victory title
+    let headline_width = measure_text(headline, None, 30, 1.0).width;
     draw_text(
-        "GAME OVER",
-        WINDOW_WIDTH as f32 / 2.0 - 70.0,
+        headline,
+        (WINDOW_WIDTH as f32 - headline_width) / 2.0,
         center_y - 80.0,
         30.0,
-        RED,
+        headline_color,
     );
 
-    // Death message
+    // Death message, centered since cause messages differ in length
+    let message_width = measure_text(message, None, 18, 1.0).width;
     draw_text(
-        death_message,
-        WINDOW_WIDTH as f32 / 2.0 - 65.0,
+        message,
+        (WINDOW_WIDTH as f32 - message_width) / 2.0,
         center_y - 50.0,
         18.0,
         WHITE,
     );
 
-    // Show final distance
+    if let Some(run) = world.last_run {
+        render_score_breakdown(&run.score, center_y - 256.0);
+    }
+
+    if let Some(rank) = world.high_score_rank {
+        let rank_text = format!("NEW HIGH SCORE! RANK #{}", rank + 1);
+        let rank_width = measure_text(&rank_text, None, 20, 1.0).width;
+        draw_text(
+            &rank_text,
+            (WINDOW_WIDTH as f32 - rank_width) / 2.0,
+            center_y - 276.0,
+            20.0,
+            YELLOW,
+        );
+    }
+
+    // Show final distance, and the time where the clock is what counts
     let final_distance = world.distance_tracker.distance_formatted();
-    let distance_text = format!("Distance: {}", final_distance);
+    let distance_text = if world.mode == GameMode::TimeAttack {
+        format!("Time: {:.1}s | Distance: {}", world.distance_tracker.elapsed_time(), final_distance)
+    } else {
+        format!("Distance: {}", final_distance)
+    };
+    let distance_width = measure_text(&distance_text, None, 16, 1.0).width;
     draw_text(
         &distance_text,
-        WINDOW_WIDTH as f32 / 2.0 - 80.0,
+        (WINDOW_WIDTH as f32 - distance_width) / 2.0,
         center_y - 25.0,
         16.0,
         YELLOW,
@@ -561,6 +1445,15 @@ fn handle_game_over_menu(world: &mut GameWorld) {
 fn handle_keyboard_input(world: &mut GameWorld) {
     let current_state = world.state_machine.current();
 
+    // Toggle the death heat strip while flying or paused
+    let in_flight = matches!(
+        current_state,
+        core::game_state::GameState::Playing | core::game_state::GameState::Paused
+    );
+    if in_flight && is_key_pressed(KeyCode::H) {
+        world.show_death_overlay = !world.show_death_overlay;
+    }
+
     match current_state {
         core::game_state::GameState::Playing => {
             if is_key_pressed(KeyCode::Escape) {
@@ -569,10 +1462,10 @@ fn handle_keyboard_input(world: &mut GameWorld) {
         }
         core::game_state::GameState::Paused => {
             if is_key_pressed(KeyCode::Escape) {
-                world.state_machine.handle_event(GameEvent::BackToMenu);
+                leave_paused_run(world);
             }
         }
-        core::game_state::GameState::GameOver => {
+        core::game_state::GameState::GameOver | core::game_state::GameState::Victory => {
             if is_key_pressed(KeyCode::Escape) {
                 world.state_machine.handle_event(GameEvent::BackToMenu);
             }
@@ -581,8 +1474,13 @@ fn handle_keyboard_input(world: &mut GameWorld) {
     }
 }
 
-/// Collects player input for physics simulation and tractor beam control
-fn collect_player_input() -> PlayerInput {
+/// Collects input for the current frame.
+///
+/// The mouse aims the tractor beam relative to the ship's screen position.
+fn collect_player_input(aim_origin: (f32, f32)) -> PlayerInput {
+    let (mouse_x, mouse_y) = mouse_position();
+    let aiming = is_mouse_button_down(MouseButton::Left);
+
     PlayerInput {
         up: is_key_down(KeyCode::Up),
         down: is_key_down(KeyCode::Down),
@@ -590,17 +1488,22 @@ fn collect_player_input() -> PlayerInput {
         right: is_key_down(KeyCode::Right),
         tractor_up: is_key_pressed(KeyCode::W),
         tractor_down: is_key_pressed(KeyCode::S),
+        tractor_polarity: is_key_pressed(KeyCode::E),
+        tractor_aim: aiming.then(|| (mouse_y - aim_origin.1).atan2(mouse_x - aim_origin.0)),
+        tractor_fire: is_mouse_button_pressed(MouseButton::Left),
+        throttle: None,
     }
 }
 
-/// Checks if player is currently consuming fuel.
-/// Right movement (acceleration) consumes fuel, left movement (braking) does not.
-fn is_consuming_fuel(input: PlayerInput) -> bool {
-    input.up || input.down || input.right
-}
-
-/// Checks for collision between player and cave walls.
-fn check_player_collision(player: &Player, cave: &mut Cave, camera_offset_x: f32) -> bool {
+/// Finds the deepest contact between player and cave walls.
+///
+/// Returns the contact together with the wall it belongs to as a death cause
+/// and the segment it was found in.
+fn find_wall_contact(
+    player: &Player,
+    cave: &mut Cave,
+    camera_offset_x: f32,
+) -> Option<(Contact, DeathCause, CaveSegment)> {
     let player_pos = (
         player.pos.x - PLAYER_SIZE.0 / 2.0,
         player.pos.y - PLAYER_SIZE.1 / 2.0,
@@ -611,25 +1514,88 @@ fn check_player_collision(player: &Player, cave: &mut Cave, camera_offset_x: f32
     // Default fuel spawn distance for collision detection
     let segments = cave.segments_in_view(view_start, view_end, PickupConstants::DEFAULT_FUEL_SPAWN_DISTANCE);
 
+    let mut deepest: Option<(Contact, DeathCause, CaveSegment)> = None;
     for segment in segments {
         // Check collision with ceiling
         let ceiling_pos = (segment.x_start, 0.0);
         let ceiling_size = (segment.width, segment.ceiling);
 
-        if aabb_overlap(player_pos, PLAYER_SIZE, ceiling_pos, ceiling_size) {
-            return true;
-        }
-
         // Check collision with floor
         let floor_pos = (segment.x_start, segment.floor);
         let floor_size = (segment.width, WINDOW_HEIGHT as f32 - segment.floor);
 
-        if aabb_overlap(player_pos, PLAYER_SIZE, floor_pos, floor_size) {
-            return true;
+        let contacts = [
+            aabb_contact(player_pos, PLAYER_SIZE, ceiling_pos, ceiling_size)
+                .map(|contact| (contact, DeathCause::CeilingCrash)),
+            aabb_contact(player_pos, PLAYER_SIZE, floor_pos, floor_size)
+                .map(|contact| (contact, DeathCause::FloorCrash)),
+        ];
+
+        for (contact, cause) in contacts.into_iter().flatten() {
+            if deepest.is_none_or(|(d, _, _)| contact.depth > d.depth) {
+                deepest = Some((contact, cause, segment));
+            }
         }
     }
 
-    false
+    deepest
+}
+
+/// Applies a wall contact according to the active damage model.
+///
+/// Returns true if the contact destroyed the ship.
+fn handle_wall_contact(world: &mut GameWorld, contact: Contact) -> bool {
+    // Without death, walls only push the ship back
+    if !world.mode.is_lethal() {
+        world.player.bounce(
+            contact.normal,
+            contact.depth,
+            HullConstants::BOUNCE_RESTITUTION,
+            HullConstants::MIN_BOUNCE_SPEED,
+        );
+        return false;
+    }
+    if world.damage_model == DamageModel::InstantDeath && !world.hull.is_invulnerable() {
+        return true;
+    }
+
+    let impact_speed = world.player.bounce(
+        contact.normal,
+        contact.depth,
+        HullConstants::BOUNCE_RESTITUTION,
+        HullConstants::MIN_BOUNCE_SPEED,
+    );
+
+    apply_hull_impact(world, impact_speed)
+}
+
+/// Applies a hazard hit according to the active damage model.
+///
+/// Returns true if the hit destroyed the ship.
+fn handle_hazard_hit(world: &mut GameWorld) -> bool {
+    if !world.mode.is_lethal() {
+        return false;
+    }
+    if world.damage_model == DamageModel::InstantDeath {
+        return !world.hull.is_invulnerable();
+    }
+
+    apply_hull_impact(world, HazardConstants::IMPACT_SPEED)
+}
+
+/// Damages the hull and plays hit feedback.
+///
+/// Returns true if the hull was destroyed.
+fn apply_hull_impact(world: &mut GameWorld, impact_speed: f32) -> bool {
+    match world.hull.take_impact(impact_speed) {
+        ImpactOutcome::Destroyed => true,
+        ImpactOutcome::Ignored => false,
+        ImpactOutcome::Absorbed | ImpactOutcome::Damaged(_) => {
+            world.collision_flash_timer = COLLISION_FLASH_DURATION;
+            world.audio_queue.push(AudioEvent::HullHit);
+            false
+        }
+    }
 }
 
 /// Updates collision flash timer.
@@ -644,102 +1610,638 @@ fn update_collision_flash(world: &mut GameWorld, dt: f32) {
 
 /// Updates camera position and distance tracking
 fn update_camera_and_distance(world: &mut GameWorld, dt: f32) {
-    world.camera_offset_x += SCROLL_SPEED * dt;
-    world.distance_tracker.update(SCROLL_SPEED, dt);
+    world.camera_offset_x += world.scroll_speed * dt;
+    world.distance_tracker.update(world.scroll_speed, dt);
 }
 
 /// Handles player input, physics, and tractor beam
 fn handle_player_input_and_physics(world: &mut GameWorld, audio_system: &mut AudioSystem, dt: f32) {
-    let input = collect_player_input();
+    let input = collect_player_input((world.player.pos.x - world.camera_offset_x, world.player.pos.y));
+    world.scroll_speed = world.mode.scroll_speed(input.throttle_levels().right > 0.0) * world.difficulty.settings().scroll_speed;
     
-    // Handle tractor beam activation
-    if input.tractor_up {
+    // Handle tractor beam activation (ignored while low on energy or overheated)
+    let beam_ready = world.tractor_beam.can_activate() && !world.tractor_beam.is_active();
+    if input.tractor_up && beam_ready {
         world.tractor_beam.activate(BeamDir::Up);
         world.audio_queue.push(AudioEvent::BeamActivation);
+        world.run_events.push(RunEvent::BeamFired);
     }
-    if input.tractor_down {
+    if input.tractor_down && beam_ready {
         world.tractor_beam.activate(BeamDir::Down);
         world.audio_queue.push(AudioEvent::BeamActivation);
+        world.run_events.push(RunEvent::BeamFired);
+    }
+    if let Some(angle) = input.tractor_aim {
+        if input.tractor_fire && beam_ready {
+            world.tractor_beam.activate_at(angle);
+            world.audio_queue.push(AudioEvent::BeamActivation);
+            world.run_events.push(RunEvent::BeamFired);
+        } else if world.tractor_beam.is_active() && !world.tractor_beam.is_grappling() {
+            world.tractor_beam.aim(angle);
+        }
+    }
+    if input.tractor_polarity {
+        world.tractor_beam.toggle_polarity();
+        world.audio_queue.push(AudioEvent::BeamPolarityToggle);
+    }
+
+    // Update tractor beam and hull timers (the beam may draw on fuel)
+    let fuel_before = world.fuel.current;
+    let was_overheated = world.tractor_beam.is_overheated();
+    world.tractor_beam.tick_with_fuel(dt, &mut world.fuel);
+    if world.tractor_beam.is_overheated() && !was_overheated {
+        world.audio_queue.push(AudioEvent::BeamOverheat);
+    }
+    world.hull.tick(dt);
+
+    // A beam running on fallback fuel has already paid for this frame
+    let beam_fuelled = world.tractor_beam.is_active() && !world.tractor_beam.is_running_on_fuel();
+    let demand = FuelDemand::from_input(input, beam_fuelled);
+    world.thrusting = demand.is_thrusting();
+
+    // Update thruster audio
+    if let Some(thruster_event) = audio_system.update_thruster(demand.is_thrusting()) {
+        world.audio_queue.push(thruster_event);
+    }
+
+    // Update fuel and check for empty state
+    let multiplier = if world.mode.burns_fuel() {
+        world.fuel_consumption.multiplier(demand)
+    } else {
+        0.0
+    };
+    let fuel_became_empty = world.fuel.burn(dt, multiplier);
+    world.run_events.push(RunEvent::FuelBurned {
+        amount: fuel_before - world.fuel.current,
+    });
+    if fuel_became_empty {
+        trigger_death(world, audio_system, DeathCause::FuelExhausted);
+        return;
+    }
+
+    // Net burn: thrust plus any beam fallback draw, less pad refuelling
+    let burned = (fuel_before - world.fuel.current) / dt;
+    let pad_refuel = if world.refuelling { world.current_refuel_rate() } else { 0.0 };
+    world.endurance.record(dt, burned - pad_refuel);
+    update_fuel_outlook(world);
+
+    // Update player physics only if fuel is available
+    if !world.fuel.is_empty() {
+        world.player.load_fuel(&world.fuel);
+        world.player.tick(dt, input, world.scroll_speed, world.camera_offset_x);
+    }
+
+    apply_beam_recoil(world, dt);
+    update_grapple(world, dt);
+    update_cargo(world, dt);
+}
+
+/// Swings the carried pod on its tether and delivers or loses it on contact
+fn update_cargo(world: &mut GameWorld, dt: f32) {
+    attach_cargo_in_beam(world);
+    world.cargo_hold.update(&mut world.player, dt);
+
+    let Some(tether) = world.cargo_hold.tether() else {
+        return;
+    };
+    let Some(segment) = world.cave.segment_at(tether.pod_position.0) else {
+        return;
+    };
+
+    match tether.check_contact(segment.ceiling, segment.floor, world.cave.drop_off_manager().drop_offs()) {
+        TetherStatus::Holding => {}
+        TetherStatus::Delivered => {
+            world.cargo_hold.drop_pod();
+            let goal_met = world.cargo_mission.deliver();
+            world.run_events.push(RunEvent::CargoDelivered { goal_met });
+            world.audio_queue.push(AudioEvent::CargoDelivered);
+        }
+        TetherStatus::Lost => {
+            world.cargo_hold.drop_pod();
+            world.cargo_mission.lose();
+            world.audio_queue.push(AudioEvent::CargoLost);
+        }
+    }
+}
+
+/// Tethers a cargo pod once a pulling beam has drawn it within tether reach
+fn attach_cargo_in_beam(world: &mut GameWorld) {
+    let pulling = world.tractor_beam.is_active() && world.tractor_beam.polarity == BeamPolarity::Pull;
+    if !pulling || world.cargo_hold.is_carrying() {
+        return;
+    }
+
+    let player_pos = (world.player.pos.x, world.player.pos.y);
+    let pickups = world.cave.pickup_manager();
+    let Some(index) = pickups.find_held_in_reach(PickupType::Cargo, player_pos, CargoConstants::TETHER_LENGTH) else {
+        return;
+    };
+    let Some(pod) = pickups.pickup_at(index) else {
+        return;
+    };
+
+    let half = PickupConstants::SIZE / 2.0;
+    world.cargo_hold.attach((pod.position.0 + half, pod.position.1 + half), pod.velocity);
+    if let Some(pickup_type) = world.cave.pickup_manager_mut().collect_pickup(index) {
+        handle_pickup_collection(world, pickup_type);
+        world.run_events.push(RunEvent::PickupCollected { pickup_type, beam_catch: true });
+    }
+}
+
+/// Estimates remaining endurance and sounds the fuel alarms
+fn update_fuel_outlook(world: &mut GameWorld) {
+    let ship_x = world.player.pos.x;
+    let next_depot_x = world.cave.pickup_manager().next_fuel_pickup_x(ship_x);
+    let outlook = world.endurance.outlook(&world.fuel, ship_x, next_depot_x, world.scroll_speed);
+
+    match world.endurance.update_warning(outlook.warning) {
+        Some(FuelWarning::Low) => world.audio_queue.push(AudioEvent::LowFuelBeep),
+        Some(FuelWarning::Critical) => world.audio_queue.push(AudioEvent::CriticalFuelAlarm),
+        _ => {}
+    }
+    world.fuel_outlook = Some(outlook);
+}
+
+/// Latches a pulling beam onto anchor crystals and reels the ship in
+fn update_grapple(world: &mut GameWorld, dt: f32) {
+    let player_pos = (world.player.pos.x, world.player.pos.y);
+
+    if world.tractor_beam.is_active() && !world.tractor_beam.is_grappling() {
+        if let Some(anchor) = world.tractor_beam.find_anchor(world.cave.anchor_manager().anchors(), player_pos) {
+            world.tractor_beam.latch(anchor.position, player_pos);
+            if world.tractor_beam.is_grappling() {
+                world.audio_queue.push(AudioEvent::GrappleLatch);
+            }
+        }
+    }
+
+    if world.tractor_beam.update_grapple(&mut world.player, dt) == Some(GrappleStatus::Broken) {
+        world.audio_queue.push(AudioEvent::GrappleBreak);
+    }
+}
+
+/// Nudges the ship away from the wall a pushing beam is aimed at
+fn apply_beam_recoil(world: &mut GameWorld, dt: f32) {
+    if !world.tractor_beam.is_active() || world.tractor_beam.polarity != BeamPolarity::Push {
+        return;
+    }
+
+    if let Some(wall_distance) = beam_wall_distance(&world.player, &world.tractor_beam, &world.cave) {
+        let (recoil_x, recoil_y) = world.tractor_beam.recoil(wall_distance);
+        world.player.vel.x += recoil_x * dt;
+        world.player.vel.y += recoil_y * dt;
+    }
+}
+
+/// Updates tractor beam pickup attraction effects
+fn update_tractor_beam_pickup_attraction(world: &mut GameWorld, dt: f32) {
+    let player_pos = (world.player.pos.x, world.player.pos.y);
+    
+    // Update pickup attraction based on tractor beam state
+    world.cave.pickup_manager_mut().update_tractor_beam_attraction(
+        &world.tractor_beam,
+        player_pos,
+        dt,
+    );
+
+    // Released pickups drift under gravity until they land
+    world.cave.update_pickups(dt);
+}
+
+/// Moves hazards and lets the tractor beam push mines away
+fn update_hazards(world: &mut GameWorld, dt: f32) {
+    let player_pos = (world.player.pos.x, world.player.pos.y);
+    let hazards = world.cave.hazard_manager_mut();
+
+    hazards.update(dt, player_pos);
+    hazards.update_tractor_beam(&world.tractor_beam, player_pos, dt);
+}
+
+/// Checks collisions and handles pickup collection
+fn check_collisions_and_pickups(world: &mut GameWorld, audio_system: &mut AudioSystem, dt: f32) {
+    // Update tractor beam pickup attraction and hazards first
+    update_tractor_beam_pickup_attraction(world, dt);
+    update_hazards(world, dt);
+    
+    // Check for collisions with walls
+    let wall_contact = find_wall_contact(&world.player, &mut world.cave, world.camera_offset_x);
+    let mut touching = wall_contact.is_some();
+    if let Some((contact, cause, segment)) = wall_contact {
+        let velocity = (world.player.vel.x, world.player.vel.y);
+        match Touchdown::classify(&contact, &segment, velocity) {
+            // Settle onto the pad without bouncing
+            Touchdown::Landed => {
+                world.player.bounce(contact.normal, contact.depth, 0.0, 0.0);
+            }
+            Touchdown::Crashed => {
+                if handle_wall_contact(world, contact) {
+                    trigger_death(world, audio_system, cause);
+                    return;
+                }
+            }
+        }
+    }
+    update_refuelling(world, dt);
+    
+    // Check for hazard hits
+    let player_box = (world.player.pos.x - PLAYER_SIZE.0 / 2.0, world.player.pos.y - PLAYER_SIZE.1 / 2.0);
+    if let Some(hit) = world.cave.hazard_manager_mut().check_collision(player_box, PLAYER_SIZE) {
+        touching = true;
+        if handle_hazard_hit(world) {
+            trigger_death(world, audio_system, hit.death_cause());
+            return;
+        }
+    }
+
+    // Resting on a pad is contact too, not a close pass
+    update_near_miss(world, touching || world.refuelling, dt);
+    
+    // Check for pickup collection
+    if let Some(pickup_index) = world.cave.pickup_manager().check_collision(
+        (world.player.pos.x - PLAYER_SIZE.0 / 2.0, world.player.pos.y - PLAYER_SIZE.1 / 2.0),
+        PLAYER_SIZE,
+    ) {
+        if !is_cargo_pod(world, pickup_index) {
+            let beam_catch = world
+                .cave
+                .pickup_manager()
+                .pickup_at(pickup_index)
+                .is_some_and(|pickup| pickup.being_attracted);
+            if let Some(pickup_type) = world.cave.pickup_manager_mut().collect_pickup(pickup_index) {
+                handle_pickup_collection(world, pickup_type);
+                world.run_events.push(RunEvent::PickupCollected { pickup_type, beam_catch });
+            }
+        }
+    }
+
+    // Chain pickups into combos
+    update_combo(world, dt);
+    
+    // Cleanup old pickups
+    world.cave.pickup_manager_mut().cleanup_old_pickups(world.camera_offset_x);
+    world.cave.hazard_manager_mut().cleanup_old_hazards(world.camera_offset_x);
+    world.cave.anchor_manager_mut().cleanup_old_anchors(world.camera_offset_x);
+    world.cave.drop_off_manager_mut().cleanup_old_drop_offs(world.camera_offset_x);
+}
+
+/// Gets the ship's bounding box in world coordinates
+fn ship_aabb(player: &Player) -> Aabb {
+    Aabb::new(
+        player.pos.x - PLAYER_SIZE.0 / 2.0,
+        player.pos.y - PLAYER_SIZE.1 / 2.0,
+        PLAYER_SIZE.0,
+        PLAYER_SIZE.1,
+    )
+}
+
+/// Measures the ship's clearance and rewards close passes
+fn update_near_miss(world: &mut GameWorld, touching: bool, dt: f32) {
+    // Fade out the previous cue
+    if let Some((_, timer)) = world.near_miss_cue.as_mut() {
+        *timer -= dt;
+    }
+    world.near_miss_cue = world.near_miss_cue.filter(|(_, timer)| *timer > 0.0);
+
+    let ship = ship_aabb(&world.player);
+    let Some((ceiling, floor)) = world.cave.wall_clearance(&ship) else {
+        return;
+    };
+    let clearance = Clearance {
+        ceiling,
+        floor,
+        obstacle: world.cave.hazard_manager().nearest_clearance(&ship),
+    };
+
+    if let Some(event) = world.near_miss.update(clearance, touching, dt) {
+        world.near_miss_cue = Some((world.near_miss.streak(), NearMissConstants::CUE_DURATION));
+        world.audio_queue.push(AudioEvent::NearMiss);
+        world.run_events.push(event);
+    }
+}
+
+/// Feeds pickup collections and misses into the combo tracker
+fn update_combo(world: &mut GameWorld, dt: f32) {
+    world.combo.update(dt);
+    // Pickups that scroll off the left edge are missed
+    world.cave.pickup_manager_mut().check_missed_pickups(world.camera_offset_x);
+
+    for pickup_event in world.cave.pickup_manager_mut().drain_events() {
+        if let PickupEvent::Missed(pickup_type) = pickup_event {
+            world.run_events.push(RunEvent::PickupMissed { pickup_type });
+        }
+        let Some(event) = world.combo.record(pickup_event) else {
+            continue;
+        };
+        if let RunEvent::Combo { reward: Some(reward), .. } = event {
+            if let ComboReward::Fuel(amount) = reward {
+                refuel(world, amount);
+            }
+            world.audio_queue.push(AudioEvent::ComboMilestone);
+        }
+        world.run_events.push(event);
+    }
+}
+
+/// Refuels the ship gradually while it rests on a landing pad
+fn update_refuelling(world: &mut GameWorld, dt: f32) {
+    let ship_bottom = world.player.pos.y + PLAYER_SIZE.1 / 2.0;
+    let velocity = (world.player.vel.x, world.player.vel.y);
+    let resting = world
+        .cave
+        .segment_at(world.player.pos.x)
+        .is_some_and(|segment| is_resting_on_pad(segment, ship_bottom, velocity));
+
+    if resting && !world.refuelling {
+        world.audio_queue.push(AudioEvent::PadTouchdown);
+    }
+    world.refuelling = resting;
+
+    if resting {
+        let refuel_amount = world.current_refuel_rate() * dt;
+        refuel(world, refuel_amount);
+    }
+}
+
+/// Adds fuel to the tank and records how much actually fit
+fn refuel(world: &mut GameWorld, amount: f32) {
+    let fuel_before = world.fuel.current;
+    world.fuel.refill(amount);
+    world.run_events.push(RunEvent::Refuelled {
+        amount: world.fuel.current - fuel_before,
+    });
+}
+
+/// Checks if a pickup is a cargo pod.
+///
+/// Pods are only taken by the beam, so flying into one does not collect it.
+fn is_cargo_pod(world: &GameWorld, pickup_index: usize) -> bool {
+    world
+        .cave
+        .pickup_manager()
+        .pickup_at(pickup_index)
+        .is_some_and(|pickup| pickup.pickup_type == PickupType::Cargo)
+}
+
+/// Handles pickup collection effects
+fn handle_pickup_collection(world: &mut GameWorld, pickup_type: PickupType) {
+    match pickup_type {
+        PickupType::Fuel => {
+            // Refill fuel based on configured percentage
+            let refill_amount = world.fuel.max
+                * FuelConstants::REFILL_PERCENTAGE
+                * world.difficulty.settings().refill_percentage;
+            refuel(world, refill_amount);
+            world.audio_queue.push(AudioEvent::FuelPickup);
+        }
+        PickupType::Shield => {
+            world.hull.add_shield();
+            world.audio_queue.push(AudioEvent::ShieldPickup);
+        }
+        PickupType::Cargo => {
+            // The beam tethered the pod before it was collected
+            world.audio_queue.push(AudioEvent::CargoAttach);
+        }
+    }
+}
+
+/// Triggers death effects and respawns or ends the run
+fn trigger_death(world: &mut GameWorld, audio_system: &mut AudioSystem, cause: DeathCause) {
+    world.audio_queue.push(AudioEvent::Death);
+    world.run_events.push(RunEvent::ShipLost { cause });
+    record_death(world, cause);
+    world.collision_flash_timer = COLLISION_FLASH_DURATION;
+    audio_system.stop_all();
+
+    let checkpoint = world.checkpoints.last().copied();
+    match checkpoint {
+        Some(checkpoint) if world.lives.lose_life() => begin_respawn(world, checkpoint),
+        _ => end_run(world, Some(cause)),
+    }
+}
+
+/// Ends the run after the last ship is lost, or reaching the goal when `cause` is `None`
+fn end_run(world: &mut GameWorld, cause: Option<DeathCause>) {
+    apply_run_events(world);
+    let level_number = world.level_manager.current_level_number();
+    let run = match cause {
+        Some(cause) => RunResult::new(cause, &world.distance_tracker, level_number),
+        None => {
+            world.score.finish(world.distance_tracker.elapsed_time());
+            RunResult::completed(&world.distance_tracker, level_number)
+        }
+    };
+    world.last_run = Some(run.with_score(world.score.breakdown()));
+    save_death_log(world);
+    if world.mode.tracks_progress() {
+        record_lifetime_stats(world);
+    }
+
+    if world.mode.ranks_run(run.is_completed()) && world.high_scores.qualifies(world.score.total()) {
+        begin_name_entry(world);
+    } else if let Some(cause) = cause {
+        world.state_machine.handle_event(GameEvent::Dead(cause));
+    } else {
+        world.state_machine.handle_event(GameEvent::RunComplete);
+    }
+}
+
+/// Ends a run that reached its goal with the victory fanfare
+fn complete_run(world: &mut GameWorld, audio_system: &mut AudioSystem) {
+    world.audio_queue.push(AudioEvent::RunComplete);
+    audio_system.stop_all();
+    end_run(world, None);
+}
+
+/// Logs where the ship was lost; the log is saved when the run ends
+fn record_death(world: &mut GameWorld, cause: DeathCause) {
+    let record = DeathRecord::new(
+        world.player.pos.x,
+        world.level_manager.current_level_number(),
+        world.seed,
+        cause,
+    )
+    .with_run(world.mode, world.difficulty);
+    world.death_log.record(record);
+    world.death_heat_strip.add(record.world_x);
+}
+
+/// Saves the death log once per run rather than after every death
+fn save_death_log(world: &mut GameWorld) {
+    if let Err(e) = world.death_log.save(&mut world.storage) {
+        eprintln!("Failed to save death log: {}", e);
+    }
+}
+
+/// Adds the finished run to the lifetime statistics and saves them
+fn record_lifetime_stats(world: &mut GameWorld) {
+    world.lifetime_stats.add_run(&world.run_stats);
+    if let Err(e) = world.lifetime_stats.save(&mut world.storage) {
+        eprintln!("Failed to save lifetime stats: {}", e);
+    }
+}
+
+/// Switches to name entry for a run that made the high-score table
+fn begin_name_entry(world: &mut GameWorld) {
+    // Drop keys typed during play so they don't end up in the name
+    while get_char_pressed().is_some() {}
+
+    world.name_entry = Some(NameEntry::new(&world.last_name));
+    world.state_machine.handle_event(GameEvent::NewHighScore);
+}
+
+/// Records the run under the entered name and moves on to game over or victory
+fn confirm_name_entry(world: &mut GameWorld) {
+    let Some(entry) = world.name_entry.take() else {
+        return;
+    };
+
+    let name = entry.name();
+    record_high_score(world, &name);
+    if let Err(e) = world.storage.save(HighScoreConstants::NAME_STORAGE_KEY, &name) {
+        eprintln!("Failed to save player name: {}", e);
+    }
+    world.last_name = name;
+    if world.last_run.is_some_and(|run| run.is_completed()) {
+        world.state_machine.handle_event(GameEvent::RunComplete);
+    } else {
+        world.state_machine.handle_event(GameEvent::NameConfirmed);
+    }
+}
+
+/// Enters the finished run into the high-score table and saves the table
+fn record_high_score(world: &mut GameWorld, name: &str) {
+    let Some(run) = world.last_run else {
+        return;
+    };
+
+    let date = macroquad::miniquad::date::now() as u64;
+    let entry = HighScoreEntry::from_run(&run, world.seed, date)
+        .with_difficulty(world.difficulty)
+        .with_name(name);
+    world.high_score_rank = world.high_scores.insert(entry);
+    if world.high_score_rank.is_some() {
+        let key = world.mode.high_score_key(world.difficulty);
+        if let Err(e) = world.high_scores.save_to(&mut world.storage, &key) {
+            eprintln!("Failed to save high scores: {}", e);
+        }
     }
+}
 
-    // Update tractor beam timer
-    world.tractor_beam.tick(dt);
+/// Records a checkpoint at the current position
+fn record_checkpoint(world: &mut GameWorld) {
+    world.checkpoints.record(Checkpoint {
+        camera_offset_x: world.camera_offset_x,
+        player_pos: (world.player.pos.x, world.player.pos.y),
+        fuel: world.fuel.current,
+        level_number: world.level_manager.current_level_number(),
+        level_start_time: world.level_manager.level_start_time(),
+        distance: world.distance_tracker,
+    });
+}
 
-    let consuming = is_consuming_fuel(input);
+/// Rewinds the world to a checkpoint and starts the fly-in
+fn begin_respawn(world: &mut GameWorld, checkpoint: Checkpoint) {
+    world.state_machine.handle_event(GameEvent::LifeLost);
 
-    // Update thruster audio
-    if let Some(thruster_event) = audio_system.update_thruster(consuming) {
-        world.audio_queue.push(thruster_event);
+    // Cargo goes down with the ship
+    if world.cargo_hold.drop_pod().is_some() {
+        world.cargo_mission.lose();
     }
 
-    // Update fuel and check for empty state
-    let fuel_became_empty = world.fuel.burn(dt, consuming);
-    if fuel_became_empty {
-        trigger_death(world, audio_system);
-        return;
-    }
+    world.camera_offset_x = checkpoint.camera_offset_x;
+    world.distance_tracker = checkpoint.distance;
 
-    // Update player physics only if fuel is available
-    if !world.fuel.is_empty() {
-        world.player.tick(dt, input, SCROLL_SPEED, world.camera_offset_x);
+    // Back to the checkpoint's level if the ship was lost after leaving it
+    match world.level_manager.rewind_to(checkpoint.level_number, checkpoint.level_start_time) {
+        Ok(true) => {
+            world.cave.configure_for_level(checkpoint.level_number);
+            world.apply_level_hazards();
+            let cargo_goal = world.current_cargo_goal();
+            world.cargo_mission.start_level(cargo_goal);
+        }
+        Ok(false) => {}
+        Err(e) => eprintln!("Failed to restore checkpoint level: {}", e),
     }
-}
-
-/// Updates tractor beam pickup attraction effects
-fn update_tractor_beam_pickup_attraction(world: &mut GameWorld, dt: f32) {
-    let player_pos = (world.player.pos.x, world.player.pos.y);
-    
-    // Update pickup attraction based on tractor beam state
-    world.cave.pickup_manager_mut().update_tractor_beam_attraction(
-        &world.tractor_beam,
-        player_pos,
-        dt,
+    world.fuel.current = checkpoint.fuel;
+    world.endurance.reset();
+    world.fuel_outlook = None;
+    world.near_miss.reset();
+    world.near_miss_cue = None;
+    world.combo.reset();
+    world.cave.pickup_manager_mut().drain_events();
+    world.tractor_beam = world.fresh_tractor_beam();
+    world.hull = Hull::default();
+    world.hull.grant_invulnerability(
+        RespawnConstants::FLY_IN_DURATION + RespawnConstants::INVULNERABILITY_DURATION,
     );
+
+    // Fly in from just off the left edge of the screen
+    let start = (checkpoint.camera_offset_x - PLAYER_SIZE.0, checkpoint.player_pos.1);
+    world.player = Player::new(Vec2::new(start.0, start.1));
+    world.fly_in = Some(FlyIn::new(
+        start,
+        checkpoint.player_pos,
+        RespawnConstants::FLY_IN_DURATION,
+    ));
 }
 
-/// Checks collisions and handles pickup collection
-fn check_collisions_and_pickups(world: &mut GameWorld, audio_system: &mut AudioSystem, dt: f32) {
-    // Update tractor beam pickup attraction first
-    update_tractor_beam_pickup_attraction(world, dt);
-    
-    // Check for collisions with walls
-    if check_player_collision(&world.player, &mut world.cave, world.camera_offset_x) {
-        trigger_death(world, audio_system);
-        return;
-    }
-    
-    // Check for pickup collection
-    if let Some(pickup_index) = world.cave.pickup_manager().check_collision(
-        (world.player.pos.x - PLAYER_SIZE.0 / 2.0, world.player.pos.y - PLAYER_SIZE.1 / 2.0),
-        PLAYER_SIZE,
-    ) {
-        if let Some(pickup_type) = world.cave.pickup_manager_mut().collect_pickup(pickup_index) {
-            handle_pickup_collection(world, pickup_type);
+/// Moves the ship along the respawn fly-in
+fn update_respawn(world: &mut GameWorld, dt: f32) {
+    world.hull.tick(dt);
+
+    let finished = match world.fly_in.as_mut() {
+        Some(fly_in) => {
+            let finished = fly_in.tick(dt);
+            let (x, y) = fly_in.position();
+            world.player.pos = Vec2::new(x, y);
+            finished
         }
+        None => true,
+    };
+
+    if finished {
+        world.fly_in = None;
+        world.state_machine.handle_event(GameEvent::RespawnComplete);
     }
-    
-    // Cleanup old pickups
-    world.cave.pickup_manager_mut().cleanup_old_pickups(world.camera_offset_x);
 }
 
-/// Handles pickup collection effects
-fn handle_pickup_collection(world: &mut GameWorld, pickup_type: PickupType) {
-    match pickup_type {
-        PickupType::Fuel => {
-            // Refill fuel based on configured percentage
-            let refill_amount = world.fuel.max * FuelConstants::REFILL_PERCENTAGE;
-            world.fuel.refill(refill_amount);
-            world.audio_queue.push(AudioEvent::FuelPickup);
+/// Feeds this frame's run events and distance into the score
+fn apply_run_events(world: &mut GameWorld) {
+    for event in world.run_events.drain() {
+        world.score.apply(&event);
+        world.run_stats.apply(&event);
+        if world.mode.tracks_progress() {
+            world.achievements.apply(&event);
         }
     }
+    world.score.record_distance(world.distance_tracker.distance);
+    announce_achievements(world);
 }
 
-/// Triggers death state and effects
-fn trigger_death(world: &mut GameWorld, audio_system: &mut AudioSystem) {
-    world.audio_queue.push(AudioEvent::Death);
-    world.state_machine.handle_event(GameEvent::Dead);
-    world.collision_flash_timer = COLLISION_FLASH_DURATION;
-    audio_system.stop_all();
+/// Shows a toast for each newly unlocked achievement and saves the unlocks
+fn announce_achievements(world: &mut GameWorld) {
+    let unlocked = world.achievements.drain_unlocked();
+    if unlocked.is_empty() {
+        return;
+    }
+
+    for achievement in unlocked {
+        world.audio_queue.push(AudioEvent::AchievementUnlocked);
+        world
+            .achievement_toasts
+            .push((achievement, AchievementConstants::TOAST_DURATION));
+    }
+    if let Err(e) = world.achievements.save(&mut world.storage) {
+        eprintln!("Failed to save achievements: {}", e);
+    }
+}
+
+/// Counts down the achievement toasts and drops expired ones
+fn update_achievement_toasts(world: &mut GameWorld, dt: f32) {
+    for (_, timer) in world.achievement_toasts.iter_mut() {
+        *timer -= dt;
+    }
+    world.achievement_toasts.retain(|(_, timer)| *timer > 0.0);
 }
 
 /// Updates game world physics, tractor beam, and collision detection
@@ -750,22 +2252,75 @@ fn update_game_world(world: &mut GameWorld, audio_system: &mut AudioSystem, dt:
             
             // Check for level progression
             let current_time = world.distance_tracker.elapsed_time();
+            let previous_level_number = world.level_manager.current_level_number();
+            let clear_completes_run = world.mode.completes_on_clear(&world.level_manager);
             if let Ok(level_changed) = world.level_manager.update(current_time) {
                 if level_changed {
-                    // Configure cave for new level
-                    let new_level_number = world.level_manager.current_level_number();
-                    world.cave.configure_for_level(new_level_number);
-                    // TODO: Add level up sound
-                    world.audio_queue.push(AudioEvent::ButtonClick);
+                    world.run_events.push(RunEvent::LevelCleared {
+                        level_number: previous_level_number,
+                        fuel_ratio: world.fuel.ratio(),
+                    });
+                    if clear_completes_run {
+                        complete_run(world, audio_system);
+                    } else {
+                        // Configure cave for new level
+                        let new_level_number = world.level_manager.current_level_number();
+                        world.cave.configure_for_level(new_level_number);
+                        world.apply_level_hazards();
+                        let cargo_goal = world.current_cargo_goal();
+                        world.cargo_mission.start_level(cargo_goal);
+                        // Anchors from the old layout are gone
+                        world.tractor_beam.release_grapple();
+                        // Keep new hazards out of the current view
+                        world.cave.hazard_manager_mut()
+                            .hold_spawns_until(world.camera_offset_x + WINDOW_WIDTH as f32);
+                        // TODO: Add level up sound
+                        world.audio_queue.push(AudioEvent::ButtonClick);
+                        // Level boundaries always get a checkpoint
+                        record_checkpoint(world);
+                    }
                 }
             }
             
-            handle_player_input_and_physics(world, audio_system, dt);
+            if world.mode.reached_target(world.distance_tracker.distance) {
+                complete_run(world, audio_system);
+            }
+
+            // Only fly on if the run didn't just end
+            if world.state_machine.current() == core::game_state::GameState::Playing {
+                handle_player_input_and_physics(world, audio_system, dt);
+            }
             
             // Only check collisions if still playing (fuel didn't run out)
             if world.state_machine.current() == core::game_state::GameState::Playing {
                 check_collisions_and_pickups(world, audio_system, dt);
             }
+
+            // Record a checkpoint once the interval has been covered
+            if world.state_machine.current() == core::game_state::GameState::Playing
+                && world.checkpoints.is_due(world.distance_tracker.distance)
+            {
+                record_checkpoint(world);
+            }
+
+            if world.state_machine.current() == core::game_state::GameState::Playing {
+                let ship = ship_aabb(&world.player);
+                world.run_events.push(RunEvent::Flight {
+                    dt,
+                    distance: world.scroll_speed * dt,
+                    thrusting: world.thrusting,
+                    speed: world.player.vel.x.hypot(world.player.vel.y),
+                    wall_clearance: world
+                        .cave
+                        .wall_clearance(&ship)
+                        .map(|(ceiling, floor)| ceiling.min(floor)),
+                });
+            }
+
+            apply_run_events(world);
+        }
+        core::game_state::GameState::Respawning => {
+            update_respawn(world, dt);
         }
         _ => {
             // Stop all sounds when not playing
@@ -777,6 +2332,25 @@ fn update_game_world(world: &mut GameWorld, audio_system: &mut AudioSystem, dt:
     update_collision_flash(world, dt);
 }
 
+/// Renders the score breakdown as a right-aligned column of points.
+fn render_score_breakdown(score: &ScoreBreakdown, top_y: f32) {
+    let label_x = WINDOW_WIDTH as f32 / 2.0 - 110.0;
+    let points_right = WINDOW_WIDTH as f32 / 2.0 + 110.0;
+    let line_height = 16.0;
+    let text_size = 16.0;
+
+    let total = ("SCORE", score.total());
+    for (row, (label, points)) in score.lines().into_iter().chain(std::iter::once(total)).enumerate() {
+        let y = top_y + row as f32 * line_height;
+        let color = if label == total.0 { YELLOW } else { LIGHTGRAY };
+        let points_text = points.to_string();
+        let points_width = measure_text(&points_text, None, text_size as u16, 1.0).width;
+
+        draw_text(label, label_x, y, text_size, color);
+        draw_text(&points_text, points_right - points_width, y, text_size, color);
+    }
+}
+
 /// Renders the cave segments
 fn render_cave(cave: &mut Cave, fuel_spawn_distance: f32, camera_offset_x: f32) {
     let view_start = camera_offset_x;
@@ -797,6 +2371,32 @@ fn render_cave(cave: &mut Cave, fuel_spawn_distance: f32, camera_offset_x: f32)
             WINDOW_HEIGHT as f32 - segment.floor,
             BLACK,
         );
+
+        // Mark landing pads with a yellow strip on the floor
+        if segment.landing_pad {
+            draw_rectangle(screen_x, segment.floor, segment.width, LANDING_PAD_MARK_HEIGHT, YELLOW);
+        }
+    }
+}
+
+/// Tints the cave where ships were lost before, redder for more deaths
+fn render_death_overlay(heat_strip: &DeathHeatStrip, cave: &Cave, camera_offset_x: f32) {
+    let view_end = camera_offset_x + WINDOW_WIDTH as f32;
+    let bucket_width = heat_strip.bucket_width();
+
+    for (x_start, count) in heat_strip.buckets_in_range(camera_offset_x, view_end) {
+        let intensity = count as f32 / heat_strip.max_count() as f32;
+        let screen_x = x_start - camera_offset_x;
+        let (top, bottom) = cave
+            .segment_at(x_start + bucket_width / 2.0)
+            .map(|segment| (segment.ceiling, segment.floor))
+            .unwrap_or((0.0, WINDOW_HEIGHT as f32));
+
+        let color = Color::new(1.0, 0.1, 0.0, DEATH_OVERLAY_MAX_ALPHA * intensity);
+        draw_rectangle(screen_x, top, bucket_width, bottom - top, color);
+
+        let label = count.to_string();
+        draw_text(&label, screen_x + 4.0, bottom - 6.0, 16.0, WHITE);
     }
 }
 
@@ -810,24 +2410,167 @@ fn render_pickups(cave: &Cave, camera_offset_x: f32) {
         let screen_x = pickup.position.0 - camera_offset_x;
         let screen_y = pickup.position.1;
         
-        // Draw fuel depot as a yellow/orange rectangle
+        // Fuel depots are orange "F", shield cells sky blue "S", cargo pods brown "C"
+        let (color, label) = match pickup.pickup_type {
+            PickupType::Fuel => (ORANGE, "F"),
+            PickupType::Shield => (SKYBLUE, "S"),
+            PickupType::Cargo => (BROWN, "C"),
+        };
+
         draw_rectangle(
             screen_x,
             screen_y,
             PickupConstants::SIZE,
             PickupConstants::SIZE,
-            ORANGE,
+            color,
         );
         
-        // Draw a small "F" for fuel
+        // Draw a small letter for the pickup type
         let text_offset_x = PickupConstants::SIZE * 0.3; // 30% of size
         let text_offset_y = PickupConstants::SIZE * 0.75; // 75% of size
-        draw_text("F", screen_x + text_offset_x, screen_y + text_offset_y, 16.0, WHITE);
+        draw_text(label, screen_x + text_offset_x, screen_y + text_offset_y, 16.0, WHITE);
+    }
+}
+
+/// Renders anchor crystals as violet diamonds
+fn render_anchors(cave: &Cave, camera_offset_x: f32) {
+    let view_start = camera_offset_x - GrappleConstants::ANCHOR_SIZE;
+    let view_end = camera_offset_x + WINDOW_WIDTH as f32 + GrappleConstants::ANCHOR_SIZE;
+
+    for anchor in cave.anchor_manager().get_anchors_in_range(view_start, view_end) {
+        draw_poly(
+            anchor.position.0 - camera_offset_x,
+            anchor.position.1,
+            4,
+            GrappleConstants::ANCHOR_SIZE / 2.0,
+            0.0,
+            VIOLET,
+        );
+    }
+}
+
+/// Renders the near-miss cue above the ship, fading out
+fn render_near_miss_cue(player: &Player, cue: Option<(u32, f32)>, camera_offset_x: f32) {
+    let Some((multiplier, timer)) = cue else {
+        return;
+    };
+
+    let cue_text = if multiplier > 1 {
+        format!("NEAR MISS x{}", multiplier)
+    } else {
+        "NEAR MISS".to_string()
+    };
+    let text_size = 14.0;
+    let text_width = measure_text(&cue_text, None, text_size as u16, 1.0).width;
+    let alpha = (timer / NearMissConstants::CUE_DURATION).clamp(0.0, 1.0);
+
+    draw_text(
+        &cue_text,
+        player.pos.x - camera_offset_x - text_width / 2.0,
+        player.pos.y - PLAYER_SIZE.1 - 6.0,
+        text_size,
+        Color::new(1.0, 0.9, 0.3, alpha),
+    );
+}
+
+/// Renders unlock toasts stacked up from the bottom of the screen, fading out at the end
+fn render_achievement_toasts(toasts: &[(Achievement, f32)]) {
+    let width = 320.0;
+    let height = 44.0;
+    let x = WINDOW_WIDTH as f32 / 2.0 - width / 2.0;
+
+    for (index, (achievement, timer)) in toasts.iter().enumerate() {
+        let y = WINDOW_HEIGHT as f32 - 70.0 - index as f32 * (height + 8.0);
+        let alpha = (*timer / 0.5).clamp(0.0, 1.0);
+
+        draw_rectangle(x, y, width, height, Color::new(0.0, 0.0, 0.0, 0.75 * alpha));
+        draw_rectangle_lines(x, y, width, height, 2.0, Color::new(1.0, 0.85, 0.2, alpha));
+        draw_text("ACHIEVEMENT UNLOCKED", x + 10.0, y + 16.0, 14.0, Color::new(1.0, 0.85, 0.2, alpha));
+        draw_text(achievement.title, x + 10.0, y + 36.0, 20.0, Color::new(1.0, 1.0, 1.0, alpha));
+    }
+}
+
+/// Renders drop-off platforms as green pads on the cave floor
+fn render_drop_offs(cave: &Cave, camera_offset_x: f32) {
+    let view_start = camera_offset_x;
+    let view_end = camera_offset_x + WINDOW_WIDTH as f32;
+
+    for drop_off in cave.drop_off_manager().get_drop_offs_in_range(view_start, view_end) {
+        let screen_x = drop_off.x - camera_offset_x;
+        draw_rectangle(
+            screen_x,
+            drop_off.y,
+            drop_off.width,
+            CargoConstants::PLATFORM_HEIGHT,
+            GREEN,
+        );
+        draw_text("DROP", screen_x + drop_off.width * 0.25, drop_off.y - 4.0, 16.0, GREEN);
+    }
+}
+
+/// Renders the carried cargo pod and its tether
+fn render_cargo(player: &Player, cargo_hold: &CargoHold, camera_offset_x: f32) {
+    let Some(tether) = cargo_hold.tether() else {
+        return;
+    };
+
+    let pod_x = tether.pod_position.0 - camera_offset_x;
+    let pod_y = tether.pod_position.1;
+    draw_line(player.pos.x - camera_offset_x, player.pos.y, pod_x, pod_y, 1.5, LIGHTGRAY);
+
+    let half = CargoConstants::POD_SIZE / 2.0;
+    draw_rectangle(pod_x - half, pod_y - half, CargoConstants::POD_SIZE, CargoConstants::POD_SIZE, BROWN);
+    draw_text("C", pod_x - half * 0.4, pod_y + half * 0.5, 16.0, WHITE);
+}
+
+/// Renders hazards and turret projectiles
+fn render_hazards(cave: &Cave, camera_offset_x: f32) {
+    let view_start = camera_offset_x - HazardConstants::ROCK_SIZE;
+    let view_end = camera_offset_x + WINDOW_WIDTH as f32 + HazardConstants::ROCK_SIZE;
+    let hazards = cave.hazard_manager();
+
+    for hazard in hazards.get_hazards_in_range(view_start, view_end) {
+        let screen_x = hazard.position.0 - camera_offset_x;
+        let screen_y = hazard.position.1;
+        let half = hazard.kind.size() / 2.0;
+
+        match hazard.kind {
+            HazardKind::Mine => {
+                // Mines glow brighter once they lock on to the ship
+                let color = if hazard.triggered { RED } else { MAROON };
+                draw_circle(screen_x, screen_y, half, color);
+                draw_circle_lines(screen_x, screen_y, half + 3.0, 1.5, color);
+            }
+            HazardKind::Rock => {
+                draw_rectangle(screen_x - half, screen_y - half, half * 2.0, half * 2.0, BROWN);
+            }
+            HazardKind::Turret => {
+                draw_rectangle(screen_x - half, screen_y - half, half * 2.0, half * 2.0, DARKGRAY);
+                let barrel_y = if hazard.on_ceiling { screen_y + half } else { screen_y - half };
+                draw_line(screen_x, screen_y, screen_x, barrel_y, 4.0, GRAY);
+            }
+        }
+    }
+
+    for projectile in hazards.projectiles() {
+        let screen_x = projectile.position.0 - camera_offset_x;
+        draw_circle(
+            screen_x,
+            projectile.position.1,
+            HazardConstants::PROJECTILE_SIZE / 2.0,
+            YELLOW,
+        );
     }
 }
 
-/// Renders the player
-fn render_player(player: &Player, camera_offset_x: f32) {
+/// Renders the player, blinking while the hull is invulnerable
+fn render_player(player: &Player, hull: &Hull, camera_offset_x: f32) {
+    const BLINK_RATE: f32 = 10.0; // blinks per second
+
+    if hull.is_invulnerable() && (hull.invulnerability_remaining() * BLINK_RATE) as i32 % 2 == 1 {
+        return;
+    }
+
     let screen_x = player.pos.x - camera_offset_x;
     let screen_y = player.pos.y;
 
@@ -857,7 +2600,7 @@ fn get_fuel_color(ratio: f32) -> Color {
 }
 
 /// Renders the fuel bar spanning the top of the screen.
-fn render_fuel_bar(fuel: &Fuel) {
+fn render_fuel_bar(fuel: &Fuel, refuelling: bool) {
     let ratio = fuel.ratio();
     let bar_width = WINDOW_WIDTH as f32 - 2.0 * FUEL_BAR_MARGIN;
 
@@ -893,7 +2636,11 @@ fn render_fuel_bar(fuel: &Fuel) {
     );
 
     // Fuel percentage text
-    let fuel_text = format!("{}%", (ratio * 100.0) as u32);
+    let fuel_text = if refuelling {
+        format!("{}% REFUELING", (ratio * 100.0) as u32)
+    } else {
+        format!("{}%", (ratio * 100.0) as u32)
+    };
     draw_text(
         &fuel_text,
         FUEL_BAR_MARGIN + 5.0,
@@ -903,6 +2650,36 @@ fn render_fuel_bar(fuel: &Fuel) {
     );
 }
 
+/// Renders the endurance estimate right-aligned inside the fuel bar.
+fn render_fuel_outlook(outlook: Option<&FuelOutlook>) {
+    let Some(outlook) = outlook else {
+        return;
+    };
+
+    let mut outlook_text = match outlook.seconds_left {
+        Some(seconds) => format!("~{:.0}s", seconds),
+        None => "~--s".to_string(),
+    };
+    if let Some(distance) = outlook.depot_distance {
+        outlook_text.push_str(&format!(" | DEPOT {:.0}px", distance));
+    }
+    if !outlook.can_reach_depot {
+        outlook_text.push_str(" | CAN'T MAKE IT");
+    }
+
+    let color = match outlook.warning {
+        _ if !outlook.can_reach_depot => RED,
+        FuelWarning::Critical => RED,
+        FuelWarning::Low => ORANGE,
+        FuelWarning::Normal => WHITE,
+    };
+
+    let text_size = 14.0;
+    let text_width = measure_text(&outlook_text, None, text_size as u16, 1.0).width;
+    let text_x = WINDOW_WIDTH as f32 - FUEL_BAR_MARGIN - text_width - 5.0;
+    draw_text(&outlook_text, text_x, FUEL_BAR_Y + FUEL_BAR_HEIGHT - 5.0, text_size, color);
+}
+
 /// Renders the distance display in the top-right corner.
 fn render_distance_display(distance_tracker: &DistanceTracker) {
     let distance_text = distance_tracker.distance_formatted();
@@ -917,16 +2694,97 @@ fn render_distance_display(distance_tracker: &DistanceTracker) {
     draw_text(&distance_text, text_x, text_y, text_size, WHITE);
 }
 
+/// Renders mode progress at the top centre: the time attack clock or the campaign level.
+fn render_mode_display(mode: GameMode, distance_tracker: &DistanceTracker, level_manager: &LevelManager) {
+    let text = match mode {
+        GameMode::TimeAttack => {
+            let target = mode.target_distance().unwrap_or_default();
+            format!(
+                "TIME {:.1}s | {:.0}/{:.0}m",
+                distance_tracker.elapsed_time(),
+                distance_tracker.distance.min(target),
+                target
+            )
+        }
+        GameMode::Campaign => format!(
+            "LEVEL {}/{}",
+            level_manager.current_level_number(),
+            level_manager.levels().len()
+        ),
+        GameMode::Zen => "ZEN".to_string(),
+        GameMode::Endless => return,
+    };
+    let text_size = 18.0;
+    let text_width = measure_text(&text, None, text_size as u16, 1.0).width;
+    draw_text(
+        &text,
+        (WINDOW_WIDTH as f32 - text_width) / 2.0,
+        FUEL_BAR_Y + FUEL_BAR_HEIGHT + 20.0,
+        text_size,
+        WHITE,
+    );
+}
+
+/// Renders the remaining lives below the distance display.
+fn render_lives_display(lives: &Lives) {
+    let lives_text = format!("LIVES x{}", lives.remaining());
+    let text_size = 14.0;
+    let margin = 15.0;
+
+    let text_width = measure_text(&lives_text, None, text_size as u16, 1.0).width;
+    let text_x = WINDOW_WIDTH as f32 - text_width - margin;
+    let text_y = margin + 20.0 + text_size + 5.0;
+
+    let color = if lives.remaining() > 1 { WHITE } else { RED };
+    draw_text(&lives_text, text_x, text_y, text_size, color);
+}
+
+/// Renders hull points and shield charges below the lives display.
+fn render_hull_display(hull: &Hull) {
+    let hull_text = format!(
+        "HULL {}% | SHIELD x{}",
+        (hull.ratio() * 100.0) as u32,
+        hull.shield_charges
+    );
+    let text_size = 14.0;
+    let margin = 15.0;
+
+    let text_width = measure_text(&hull_text, None, text_size as u16, 1.0).width;
+    let text_x = WINDOW_WIDTH as f32 - text_width - margin;
+    let text_y = margin + 20.0 + 2.0 * (text_size + 5.0);
+
+    let color = if hull.ratio() > HullConstants::LOW_HULL_THRESHOLD { WHITE } else { RED };
+    draw_text(&hull_text, text_x, text_y, text_size, color);
+}
+
+/// Renders cargo deliveries below the hull display.
+fn render_cargo_display(mission: &CargoMission) {
+    let cargo_text = format!(
+        "CARGO {}/{} | +{}",
+        mission.delivered, mission.goal, mission.points
+    );
+    let text_size = 14.0;
+    let margin = 15.0;
+
+    let text_width = measure_text(&cargo_text, None, text_size as u16, 1.0).width;
+    let text_x = WINDOW_WIDTH as f32 - text_width - margin;
+    let text_y = margin + 20.0 + 3.0 * (text_size + 5.0);
+
+    let color = if mission.is_complete() { GREEN } else { WHITE };
+    draw_text(&cargo_text, text_x, text_y, text_size, color);
+}
+
 /// Renders the beam ready indicator icon.
 fn render_beam_indicator(tractor_beam: &TractorBeam) {
     let icon_x = FUEL_BAR_MARGIN + 5.0;
     let icon_y = FUEL_BAR_Y + FUEL_BAR_HEIGHT + 10.0;
 
-    // Choose color based on beam state
-    let icon_color = if tractor_beam.is_active() {
-        GRAY // Grayed out when active
-    } else {
-        Color::new(0.5, 0.8, 1.0, 1.0) // Light blue when ready
+    // Choose color based on beam state and polarity
+    let icon_color = match (tractor_beam.is_active(), tractor_beam.polarity) {
+        _ if tractor_beam.is_overheated() => RED, // Red while locked out
+        (true, _) => GRAY, // Grayed out when active
+        (false, BeamPolarity::Pull) => Color::new(0.5, 0.8, 1.0, 1.0), // Light blue when ready to pull
+        (false, BeamPolarity::Push) => Color::new(1.0, 0.6, 0.3, 1.0), // Orange when ready to push
     };
 
     // Draw simple beam icon (triangle pointing up)
@@ -959,19 +2817,50 @@ fn render_beam_indicator(tractor_beam: &TractorBeam) {
     }
 
     // Beam status text
-    let status_text = if tractor_beam.is_active() {
-        "BEAM ACTIVE"
+    let polarity_text = match tractor_beam.polarity {
+        BeamPolarity::Pull => "PULL",
+        BeamPolarity::Push => "PUSH",
+    };
+    let status_text = if tractor_beam.is_overheated() {
+        format!("OVERHEAT {:.1}s", tractor_beam.overheat_remaining())
+    } else if tractor_beam.is_active() {
+        format!("BEAM ACTIVE ({})", polarity_text)
     } else {
-        "BEAM READY"
+        format!("BEAM READY ({})", polarity_text)
     };
 
     draw_text(
-        status_text,
+        &status_text,
         icon_x + BEAM_ICON_SIZE + 5.0,
         icon_y + BEAM_ICON_SIZE / 2.0 + 4.0,
         12.0,
         icon_color,
     );
+
+    // Energy bar under the icon
+    let bar_y = icon_y + BEAM_ICON_SIZE + 4.0;
+    let bar_width = BEAM_ENERGY_BAR_WIDTH * tractor_beam.energy_ratio();
+    draw_rectangle(icon_x, bar_y, BEAM_ENERGY_BAR_WIDTH, BEAM_ENERGY_BAR_HEIGHT, DARKGRAY);
+    draw_rectangle(icon_x, bar_y, bar_width, BEAM_ENERGY_BAR_HEIGHT, icon_color);
+}
+
+/// Renders the combo multiplier and its draining timer below the beam indicator.
+fn render_combo_meter(combo: &ComboTracker) {
+    if !combo.is_active() {
+        return;
+    }
+
+    let meter_x = FUEL_BAR_MARGIN + 5.0;
+    let meter_y = FUEL_BAR_Y + FUEL_BAR_HEIGHT + 10.0 + BEAM_ICON_SIZE + BEAM_ENERGY_BAR_HEIGHT + 20.0;
+
+    let combo_text = format!("COMBO x{} ({})", combo.multiplier(), combo.count());
+    draw_text(&combo_text, meter_x, meter_y, 14.0, YELLOW);
+
+    // Timer bar drains until the combo ends
+    let bar_y = meter_y + 4.0;
+    let bar_width = COMBO_METER_WIDTH * combo.time_ratio();
+    draw_rectangle(meter_x, bar_y, COMBO_METER_WIDTH, COMBO_METER_HEIGHT, DARKGRAY);
+    draw_rectangle(meter_x, bar_y, bar_width, COMBO_METER_HEIGHT, YELLOW);
 }
 
 /// Renders collision flash effect.
@@ -990,82 +2879,66 @@ fn render_collision_flash(collision_flash_timer: f32) {
     }
 }
 
-/// Renders the tractor beam as a blue rectangle ending at cave walls
+/// Renders the tractor beam as an oriented rectangle ending at cave walls
 fn render_tractor_beam(
     player: &Player,
     tractor_beam: &TractorBeam,
-    cave: &mut Cave,
+    cave: &Cave,
     camera_offset_x: f32,
 ) {
     if !tractor_beam.is_active() {
         return;
     }
 
-    let screen_x = player.pos.x - camera_offset_x;
-    let beam_width = 32.0; // 16px on each side of player center
-    let beam_x = screen_x - beam_width / 2.0;
-
-    // Get cave segments at player position to find wall heights
-    let wall_height = get_cave_wall_height_at_position(player.pos.x, tractor_beam.dir, cave);
-
-    match tractor_beam.dir {
-        BeamDir::Up => {
-            // Beam from player to ceiling
-            let beam_start_y = wall_height; // Ceiling height
-            let beam_height = player.pos.y - wall_height;
-
-            // Only draw if there's space between player and ceiling
-            if beam_height > 0.0 {
-                draw_rectangle(
-                    beam_x,
-                    beam_start_y,
-                    beam_width,
-                    beam_height,
-                    Color::new(0.0, 0.5, 1.0, 0.6), // Semi-transparent blue
-                );
-            }
-        }
-        BeamDir::Down => {
-            // Beam from player to floor
-            let beam_start_y = player.pos.y;
-            let beam_height = wall_height - player.pos.y; // Floor height - player position
-
-            // Only draw if there's space between player and floor
-            if beam_height > 0.0 {
-                draw_rectangle(
-                    beam_x,
-                    beam_start_y,
-                    beam_width,
-                    beam_height,
-                    Color::new(0.0, 0.5, 1.0, 0.6), // Semi-transparent blue
-                );
-            }
-        }
-    }
-}
+    let beam_color = match tractor_beam.polarity {
+        BeamPolarity::Pull => Color::new(0.0, 0.5, 1.0, 0.6), // Semi-transparent blue
+        BeamPolarity::Push => Color::new(1.0, 0.5, 0.0, 0.6), // Semi-transparent orange
+    };
 
-/// Gets the cave wall height (ceiling or floor) at the specified x position.
-fn get_cave_wall_height_at_position(x_pos: f32, beam_dir: BeamDir, cave: &mut Cave) -> f32 {
-    // Get cave segments around player position
-    let view_start = x_pos - 50.0; // Small buffer around player
-    let view_end = x_pos + 50.0;
-    // Default fuel spawn distance for wall detection
-    let segments = cave.segments_in_view(view_start, view_end, PickupConstants::DEFAULT_FUEL_SPAWN_DISTANCE);
+    // Clip the beam at the first wall along its axis
+    let length = beam_wall_distance(player, tractor_beam, cave).unwrap_or(TractorBeam::MAX_RANGE);
+    let (dir_x, dir_y) = tractor_beam.direction();
+    let start_x = player.pos.x - camera_offset_x;
+    let start_y = player.pos.y;
+
+    draw_line(
+        start_x,
+        start_y,
+        start_x + dir_x * length,
+        start_y + dir_y * length,
+        TractorBeamConstants::BEAM_WIDTH,
+        beam_color,
+    );
 
-    // Find the segment that contains the player's x position
-    for segment in segments {
-        if x_pos >= segment.x_start && x_pos < segment.x_end() {
-            return match beam_dir {
-                BeamDir::Up => segment.ceiling,
-                BeamDir::Down => segment.floor,
-            };
-        }
+    // Rope to the anchor while grappling
+    if let Some(grapple) = tractor_beam.grapple() {
+        draw_line(
+            start_x,
+            start_y,
+            grapple.anchor.0 - camera_offset_x,
+            grapple.anchor.1,
+            2.0,
+            WHITE,
+        );
     }
+}
+
+/// Gets the distance from the ship to the cave wall along the beam, if within range.
+fn beam_wall_distance(player: &Player, tractor_beam: &TractorBeam, cave: &Cave) -> Option<f32> {
+    cave.raycast(
+        (player.pos.x, player.pos.y),
+        tractor_beam.direction(),
+        TractorBeam::MAX_RANGE,
+    )
+}
 
-    // Fallback if no segment found (shouldn't happen in normal gameplay)
-    match beam_dir {
-        BeamDir::Up => 0.0,                    // Top of window
-        BeamDir::Down => WINDOW_HEIGHT as f32, // Bottom of window
+/// Reads the seed count following a tool flag, defaulting to 10 when it's left out
+fn seed_count_arg(args: &[String], index: usize) -> Result<u32, String> {
+    match args.get(index + 1).filter(|value| !value.starts_with("--")) {
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("invalid seed count '{}'", value)),
+        None => Ok(10),
     }
 }
 
@@ -1082,13 +2955,28 @@ async fn main() {
         println!();
         println!("OPTIONS:");
         println!("  --start, -s          Start the game directly (skip main menu)");
+        println!("  --hull               Use hull points and shields instead of instant death");
+        println!("  --beam-fuel          Let the tractor beam draw on fuel when its energy runs out");
+        println!("  --cargo              Carry cargo pods to drop-off platforms for points");
         println!("  --headless-test      Run headless test for CI");
+        println!("  --check-seeds <N>    Verify seeds 0..N generate passable caves");
+        println!("  --death-report <N>   Fly a bot through seeds 0..N and print where it died as CSV");
+        println!("  --json               Print the death report as JSON instead");
+        println!("  --death-overlay      Show the heat strip of past deaths (toggle with H)");
+        println!("  --mode <MODE>        Game mode: campaign, endless, time_attack or zen");
+        println!("  --difficulty <LEVEL> Difficulty preset: easy, normal, hard or insane");
         println!("  --help, -h           Show this help message");
         println!();
         println!("CONTROLS:");
-        println!("  Arrow Keys           Move spaceship");
+        println!("  Arrow Keys           Move spaceship (RIGHT boosts in time attack)");
         println!("  W/S                  Activate tractor beam");
+        println!("  E                    Toggle tractor beam pull/push");
+        println!("  Left Mouse           Fire and aim tractor beam toward the cursor");
+        println!("                       (a pulling beam latches onto violet anchor crystals)");
+        println!("  H                    Toggle death heat strip");
         println!("  ESC                  Pause game");
+        println!("  LEFT/RIGHT           Change game mode in the main menu");
+        println!("  D                    Change difficulty in the main menu");
         println!("  SPACE/ENTER          Select menu option");
         return;
     }
@@ -1103,12 +2991,86 @@ async fn main() {
         return;
     }
 
+    // Check for seed passability check
+    if let Some(index) = args.iter().position(|arg| arg == "--check-seeds") {
+        let seed_count = match seed_count_arg(&args, index) {
+            Ok(seed_count) => seed_count,
+            Err(e) => {
+                eprintln!("Seed check failed: {}", e);
+                std::process::exit(1);
+            }
+        };
+        if let Err(e) = seed_check::run_seed_check(seed_count) {
+            eprintln!("Seed check failed: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    // Check for the bot death report
+    if let Some(index) = args.iter().position(|arg| arg == "--death-report") {
+        let seed_count = match seed_count_arg(&args, index) {
+            Ok(seed_count) => seed_count,
+            Err(e) => {
+                eprintln!("Death report failed: {}", e);
+                std::process::exit(1);
+            }
+        };
+        let format = if args.contains(&"--json".to_string()) {
+            death_report::ReportFormat::Json
+        } else {
+            death_report::ReportFormat::Csv
+        };
+        if let Err(e) = death_report::run_death_report(seed_count, format) {
+            eprintln!("Death report failed: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
     // Check for direct start flag
     let direct_start = args.contains(&"--start".to_string()) || args.contains(&"-s".to_string());
 
     // Initialize audio system (stub implementation)
     let mut audio_system = AudioSystem::new();
     let mut world = GameWorld::new();
+
+    // Enable the hull damage model if requested
+    if args.contains(&"--hull".to_string()) {
+        world.damage_model = DamageModel::Hull;
+    }
+
+    // Let the tractor beam fall back on fuel if requested
+    if args.contains(&"--beam-fuel".to_string()) {
+        world.beam_fuel_fallback = true;
+        world.tractor_beam.fuel_fallback = true;
+    }
+
+    // Enable cargo delivery missions if requested
+    if args.contains(&"--cargo".to_string()) {
+        world.cargo_mode = true;
+    }
+
+    // Show the death heat strip from the start if requested
+    if args.contains(&"--death-overlay".to_string()) {
+        world.show_death_overlay = true;
+    }
+
+    // Pick the game mode if requested
+    if let Some(index) = args.iter().position(|arg| arg == "--mode") {
+        match args.get(index + 1).and_then(|name| GameMode::from_name(name)) {
+            Some(mode) => world.select_mode(mode),
+            None => eprintln!("Unknown game mode, using {}", world.mode),
+        }
+    }
+
+    // Pick the difficulty preset if requested
+    if let Some(index) = args.iter().position(|arg| arg == "--difficulty") {
+        match args.get(index + 1).and_then(|name| Difficulty::from_name(name)) {
+            Some(difficulty) => world.select_difficulty(difficulty),
+            None => eprintln!("Unknown difficulty, using {}", world.difficulty),
+        }
+    }
     
     // Start game directly if requested
     if direct_start {
@@ -1142,17 +3104,35 @@ async fn main() {
                 clear_background(DARKBLUE);
                 let fuel_spawn_distance = world.current_fuel_spawn_distance();
                 render_cave(&mut world.cave, fuel_spawn_distance, world.camera_offset_x);
+                if world.show_death_overlay {
+                    render_death_overlay(&world.death_heat_strip, &world.cave, world.camera_offset_x);
+                }
                 render_pickups(&world.cave, world.camera_offset_x);
-                render_player(&world.player, world.camera_offset_x);
+                render_hazards(&world.cave, world.camera_offset_x);
+                render_anchors(&world.cave, world.camera_offset_x);
+                render_drop_offs(&world.cave, world.camera_offset_x);
+                render_cargo(&world.player, &world.cargo_hold, world.camera_offset_x);
+                render_player(&world.player, &world.hull, world.camera_offset_x);
                 render_tractor_beam(
                     &world.player,
                     &world.tractor_beam,
-                    &mut world.cave,
+                    &world.cave,
                     world.camera_offset_x,
                 );
-                render_fuel_bar(&world.fuel);
+                render_fuel_bar(&world.fuel, world.refuelling);
+                render_fuel_outlook(world.fuel_outlook.as_ref());
                 render_distance_display(&world.distance_tracker);
+                render_mode_display(world.mode, &world.distance_tracker, &world.level_manager);
+                render_lives_display(&world.lives);
+                if world.damage_model == DamageModel::Hull {
+                    render_hull_display(&world.hull);
+                }
+                if world.cargo_mode {
+                    render_cargo_display(&world.cargo_mission);
+                }
                 render_beam_indicator(&world.tractor_beam);
+                render_combo_meter(&world.combo);
+                render_near_miss_cue(&world.player, world.near_miss_cue, world.camera_offset_x);
                 render_collision_flash(world.collision_flash_timer);
             }
             core::game_state::GameState::Paused => {
@@ -1160,27 +3140,80 @@ async fn main() {
                 clear_background(DARKBLUE);
                 let fuel_spawn_distance = world.current_fuel_spawn_distance();
                 render_cave(&mut world.cave, fuel_spawn_distance, world.camera_offset_x);
+                if world.show_death_overlay {
+                    render_death_overlay(&world.death_heat_strip, &world.cave, world.camera_offset_x);
+                }
                 render_pickups(&world.cave, world.camera_offset_x);
-                render_player(&world.player, world.camera_offset_x);
+                render_hazards(&world.cave, world.camera_offset_x);
+                render_anchors(&world.cave, world.camera_offset_x);
+                render_drop_offs(&world.cave, world.camera_offset_x);
+                render_cargo(&world.player, &world.cargo_hold, world.camera_offset_x);
+                render_player(&world.player, &world.hull, world.camera_offset_x);
                 render_tractor_beam(
                     &world.player,
                     &world.tractor_beam,
-                    &mut world.cave,
+                    &world.cave,
                     world.camera_offset_x,
                 );
-                render_fuel_bar(&world.fuel);
+                render_fuel_bar(&world.fuel, world.refuelling);
+                render_fuel_outlook(world.fuel_outlook.as_ref());
                 render_distance_display(&world.distance_tracker);
+                render_mode_display(world.mode, &world.distance_tracker, &world.level_manager);
+                render_lives_display(&world.lives);
+                if world.damage_model == DamageModel::Hull {
+                    render_hull_display(&world.hull);
+                }
+                if world.cargo_mode {
+                    render_cargo_display(&world.cargo_mission);
+                }
                 render_beam_indicator(&world.tractor_beam);
+                render_combo_meter(&world.combo);
 
                 handle_pause_menu(&mut world);
             }
-            core::game_state::GameState::GameOver => {
+            core::game_state::GameState::Respawning => {
+                update_game_world(&mut world, &mut audio_system, dt);
+
+                clear_background(DARKBLUE);
+                let fuel_spawn_distance = world.current_fuel_spawn_distance();
+                render_cave(&mut world.cave, fuel_spawn_distance, world.camera_offset_x);
+                if world.show_death_overlay {
+                    render_death_overlay(&world.death_heat_strip, &world.cave, world.camera_offset_x);
+                }
+                render_pickups(&world.cave, world.camera_offset_x);
+                render_hazards(&world.cave, world.camera_offset_x);
+                render_anchors(&world.cave, world.camera_offset_x);
+                render_drop_offs(&world.cave, world.camera_offset_x);
+                render_cargo(&world.player, &world.cargo_hold, world.camera_offset_x);
+                render_player(&world.player, &world.hull, world.camera_offset_x);
+                render_fuel_bar(&world.fuel, world.refuelling);
+                render_fuel_outlook(world.fuel_outlook.as_ref());
+                render_distance_display(&world.distance_tracker);
+                render_mode_display(world.mode, &world.distance_tracker, &world.level_manager);
+                render_lives_display(&world.lives);
+                if world.damage_model == DamageModel::Hull {
+                    render_hull_display(&world.hull);
+                }
+                if world.cargo_mode {
+                    render_cargo_display(&world.cargo_mission);
+                }
+                render_collision_flash(world.collision_flash_timer);
+            }
+            core::game_state::GameState::NameEntry => {
+                clear_background(DARKBLUE);
+                render_collision_flash(world.collision_flash_timer);
+                handle_name_entry(&mut world);
+            }
+            core::game_state::GameState::GameOver | core::game_state::GameState::Victory => {
                 clear_background(DARKBLUE);
                 render_collision_flash(world.collision_flash_timer);
                 handle_game_over_menu(&mut world);
             }
         }
 
+        update_achievement_toasts(&mut world, dt);
+        render_achievement_toasts(&world.achievement_toasts);
+
         next_frame().await;
     }
 }
@@ -90,7 +90,7 @@ impl MainMenuController {
 
         // Instructions
         draw_centered_text(
-            "Arrow keys: Move | W/S: Tractor Beam | Watch your fuel!",
+            "Arrows: Move | W/S: Beam | E: Push/Pull | Mouse: Aim & Fire | Watch your fuel!",
             center_y - 50.0,
             INSTRUCTIONS_OFFSET_X,
             INSTRUCTIONS_FONT_SIZE,
@@ -0,0 +1,59 @@
+//! Seed fairness check for level designers.
+//!
+//! Generates each level of the default level pack for a range of seeds
+//! and proves a collision-free path exists through every stretch.
+use core::cave::Cave;
+use core::level::LevelManager;
+use core::passability::PassabilityValidator;
+
+/// Number of segments generated and validated per level.
+const STRETCH_SEGMENTS: usize = 90;
+
+/// Checks seeds `0..seed_count` and prints a per-seed report.
+///
+/// Returns an error if any generated stretch is impassable.
+pub fn run_seed_check(seed_count: u32) -> Result<(), Box<dyn std::error::Error>> {
+    println!("Checking {} seeds for passability...", seed_count);
+
+    let validator = PassabilityValidator::default();
+    let level_manager = LevelManager::new();
+    let mut failures = 0;
+
+    for seed in 0..seed_count {
+        let mut repairs = 0;
+        let mut seed_ok = true;
+
+        for level in level_manager.levels() {
+            let mut cave = Cave::new(seed);
+            cave.configure_for_level(level.number);
+            for _ in 0..STRETCH_SEGMENTS {
+                cave.generate_next(level.fuel_spawn_distance);
+            }
+            repairs += cave.repair_count();
+
+            let segments: Vec<_> = cave.segments().iter().copied().collect();
+            if let Err(e) = validator.find_path(&segments, &[]) {
+                println!("Seed {} level {}: FAILED ({})", seed, level.number, e);
+                seed_ok = false;
+            }
+        }
+
+        if seed_ok {
+            println!("Seed {}: OK ({} repaired segments)", seed, repairs);
+        } else {
+            failures += 1;
+        }
+    }
+
+    println!(
+        "Seed check completed: {} passed, {} failed",
+        seed_count - failures,
+        failures
+    );
+
+    if failures > 0 {
+        return Err(format!("{} of {} seeds are impassable", failures, seed_count).into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+//! Persistent storage backends for the native and browser builds.
+//!
+//! Native builds keep one file per record in a data directory under the
+//! user's home; the WASM build keeps records in the browser's local storage.
+
+use core::storage::Storage;
+
+/// Opens the persistent storage for this platform.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn open_storage() -> Box<dyn Storage> {
+    Box::new(core::storage::FileStorage::new(data_dir()))
+}
+
+/// Opens the persistent storage for this platform.
+///
+/// Falls back to memory-only storage when local storage is unavailable
+/// (for example when the browser blocks it).
+#[cfg(target_arch = "wasm32")]
+pub fn open_storage() -> Box<dyn Storage> {
+    match BrowserStorage::open() {
+        Some(storage) => Box::new(storage),
+        None => Box::new(core::storage::MemoryStorage::new()),
+    }
+}
+
+/// Gets the directory holding saved records on native builds.
+#[cfg(not(target_arch = "wasm32"))]
+fn data_dir() -> std::path::PathBuf {
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("APPDATA"))
+        .map(|home| std::path::PathBuf::from(home).join(".fuel-drift"))
+        .unwrap_or_else(|| std::path::PathBuf::from(".fuel-drift"))
+}
+
+/// Storage backed by the browser's `localStorage`.
+#[cfg(target_arch = "wasm32")]
+pub struct BrowserStorage {
+    storage: web_sys::Storage,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl BrowserStorage {
+    /// Opens the window's local storage, if the browser provides one.
+    pub fn open() -> Option<Self> {
+        let storage = web_sys::window()?.local_storage().ok()??;
+        Some(Self { storage })
+    }
+
+    /// Namespaces a key so it does not clash with other pages on the origin.
+    fn item_key(key: &str) -> String {
+        format!("fuel-drift.{}", key)
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Storage for BrowserStorage {
+    fn load(&self, key: &str) -> core::storage::StorageResult<Option<String>> {
+        self.storage
+            .get_item(&Self::item_key(key))
+            .map_err(|e| core::storage::StorageError::Io(format!("{:?}", e)))
+    }
+
+    fn save(&mut self, key: &str, data: &str) -> core::storage::StorageResult<()> {
+        self.storage
+            .set_item(&Self::item_key(key), data)
+            .map_err(|e| core::storage::StorageError::Io(format!("{:?}", e)))
+    }
+}
@@ -15,7 +15,7 @@ pub mod constants {
     
     /// Text positioning offsets
     pub const TITLE_OFFSET_X: f32 = 80.0;
-    pub const INSTRUCTIONS_OFFSET_X: f32 = 180.0;
+    pub const INSTRUCTIONS_OFFSET_X: f32 = 255.0;
     pub const GAME_OVER_TITLE_OFFSET_X: f32 = 70.0;
     pub const DISTANCE_OFFSET_X: f32 = 80.0;
     pub const KEYBOARD_INSTRUCTIONS_OFFSET_X: f32 = 120.0;